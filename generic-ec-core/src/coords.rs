@@ -24,6 +24,14 @@ pub trait HasAffineXAndParity: Curve + HasAffineX {
     ///
     /// Returns `None` if it's point at infinity
     fn x_and_parity(point: &Self::Point) -> Option<(Self::CoordinateArray, Parity)>;
+    /// Returns parity of point y coordinate
+    ///
+    /// Returns `None` if it's point at infinity. Default implementation calls
+    /// [`x_and_parity`](Self::x_and_parity) and discards x coordinate, implementations
+    /// may override it if parity can be obtained cheaper.
+    fn y_parity(point: &Self::Point) -> Option<Parity> {
+        Self::x_and_parity(point).map(|(_, parity)| parity)
+    }
     /// Construct a point from x coordinate and parity of y coordinate
     ///
     /// Returns `None` if input does not correspond to a valid point (but you
@@ -72,6 +80,15 @@ pub trait AlwaysHasAffineYAndSign: Curve + AlwaysHasAffineY {
     fn from_y_and_sign(x_sign: Sign, y: &Self::CoordinateArray) -> Option<Self::Point>;
 }
 
+/// Curve that exposes modulus of the field its points coordinates belong to
+///
+/// Note that field modulus $p$ is generally different from the curve order $q$, so
+/// coordinates may not fit into a scalar without reduction.
+pub trait HasFieldModulus: Curve {
+    /// Returns field modulus $p$ (big-endian)
+    fn field_modulus() -> Self::CoordinateArray;
+}
+
 /// Sign of coordinate
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -0,0 +1,131 @@
+//! `expand_message` functions defined in [RFC 9380](https://www.rfc-editor.org/rfc/rfc9380.html#section-5.3)
+//!
+//! Shared by curve implementations of hashing to the curve and by `generic-ec` hashing to
+//! scalar, so there's a single implementation of the spec.
+
+use digest::{crypto_common::BlockSizeUser, generic_array::GenericArray, Digest};
+
+/// Prefix prepended to DST longer than 255 bytes before hashing it
+const OVERSIZE_DST_PREFIX: &[u8] = b"H2C-OVERSIZE-DST-";
+
+/// `expand_message_xmd` as defined in [RFC 9380, Section 5.3.1](https://www.rfc-editor.org/rfc/rfc9380.html#section-5.3.1)
+///
+/// Derives `len_in_bytes` uniform bytes from message and DST, and writes them into `out`.
+/// Message and DST are given as lists of parts which are concatenated, i.e.
+/// `msgs = [b"a", b"bc"]` is the same as `msgs = [b"abc"]`. If `out` is shorter than
+/// `len_in_bytes`, the rest of output is discarded. DST longer than 255 bytes is hashed as
+/// described in [Section 5.3.3](https://www.rfc-editor.org/rfc/rfc9380.html#section-5.3.3).
+///
+/// RFC requires DST to be non-empty. This function doesn't enforce it, callers that need to
+/// follow RFC strictly must check it themselves.
+///
+/// ## Panics
+/// Panics if `len_in_bytes` is greater than $2^{16} - 1$ or requires more than 255 digest blocks.
+pub fn expand_message_xmd<'o, D>(
+    msgs: &[&[u8]],
+    dsts: &[&[u8]],
+    len_in_bytes: usize,
+    out: impl IntoIterator<Item = &'o mut u8>,
+) where
+    D: Digest + BlockSizeUser,
+{
+    let ell = len_in_bytes.div_ceil(<D as Digest>::output_size());
+    let (Ok(ell), Ok(len_in_bytes)) = (u8::try_from(ell), u16::try_from(len_in_bytes)) else {
+        panic!("expand_message_xmd: requested output is too long")
+    };
+
+    let dst_len: usize = dsts.iter().map(|dst| dst.len()).sum();
+    let hashed_dst;
+    let hashed_dst_parts;
+    let dst: &[&[u8]] = if dst_len > 255 {
+        let mut hash = D::new().chain_update(OVERSIZE_DST_PREFIX);
+        dsts.iter().for_each(|dst| hash.update(dst));
+        hashed_dst = hash.finalize();
+        hashed_dst_parts = [hashed_dst.as_slice()];
+        &hashed_dst_parts
+    } else {
+        dsts
+    };
+    // DST_prime = DST || I2OSP(len(DST), 1)
+    let dst_len = [dst.iter().map(|dst| dst.len()).sum::<usize>() as u8];
+    let update_with_dst_prime = |hash: D| {
+        dst.iter()
+            .fold(hash, |hash, dst| hash.chain_update(dst))
+            .chain_update(dst_len)
+    };
+
+    let mut b_0 = D::new().chain_update(GenericArray::<u8, D::BlockSize>::default());
+    msgs.iter().for_each(|msg| b_0.update(msg));
+    let b_0 = update_with_dst_prime(
+        b_0.chain_update(len_in_bytes.to_be_bytes())
+            .chain_update([0u8]),
+    )
+    .finalize();
+    let mut b_i = update_with_dst_prime(D::new().chain_update(&b_0).chain_update([1u8])).finalize();
+
+    let mut out = out.into_iter();
+    for i in 2..=ell {
+        // `b_i` goes first in `zip`, so `out` is not advanced past the written bytes
+        b_i.iter().zip(out.by_ref()).for_each(|(b, o)| *o = *b);
+
+        // b_i = H(strxor(b_0, b_(i - 1)) || I2OSP(i, 1) || DST_prime)
+        b_i.iter_mut().zip(&b_0).for_each(|(b_i, b_0)| *b_i ^= b_0);
+        b_i = update_with_dst_prime(D::new().chain_update(&b_i).chain_update([i])).finalize();
+    }
+    b_i.iter().zip(out).for_each(|(b, o)| *o = *b);
+}
+
+#[cfg(test)]
+mod tests {
+    use sha2::{Sha256, Sha512};
+
+    /// Test vectors from RFC 9380, Appendix K.1
+    #[test]
+    fn expand_message_xmd_sha256() {
+        let dst = b"QUUX-V01-CS02-with-expander-SHA256-128";
+        let test_vectors: &[(&[u8], &str)] = &[
+            (
+                b"",
+                "68a985b87eb6b46952128911f2a4412bbc302a9d759667f87f7a21d803f07235",
+            ),
+            (
+                b"abc",
+                "d8ccab23b5985ccea865c6c97b6e5b8350e794e603b4b97902f53a8a0d605615",
+            ),
+        ];
+
+        for (msg, expected) in test_vectors {
+            let mut out = [0u8; 32];
+            super::expand_message_xmd::<Sha256>(&[msg], &[dst], out.len(), &mut out);
+            assert_eq!(hex::encode(out), *expected);
+        }
+    }
+
+    /// Test vector from RFC 9380, Appendix K.3
+    #[test]
+    fn expand_message_xmd_sha512() {
+        let dst = b"QUUX-V01-CS02-with-expander-SHA512-256";
+        let mut out = [0u8; 32];
+        super::expand_message_xmd::<Sha512>(&[b""], &[dst], out.len(), &mut out);
+        assert_eq!(
+            hex::encode(out),
+            "6b9a7312411d92f921c6f68ca0b6380730a1a4d982c507211a90964c394179ba"
+        );
+    }
+
+    #[test]
+    fn parts_are_concatenated() {
+        let long_dst = [b'd'; 300];
+        let (long_dst_hi, long_dst_lo) = long_dst.split_at(100);
+        for (dst, dst_parts) in [
+            (&b"QUUX-V01"[..], [&b"QUUX"[..], &b"-V01"[..]]),
+            (&long_dst[..], [long_dst_hi, long_dst_lo]),
+        ] {
+            let mut out = [0u8; 96];
+            let mut out2 = [0u8; 96];
+            super::expand_message_xmd::<Sha512>(&[b"abc"], &[dst], out.len(), &mut out);
+            super::expand_message_xmd::<Sha512>(&[b"a", b"bc"], &dst_parts, out2.len(), &mut out2);
+            assert_eq!(out, out2);
+        }
+    }
+}
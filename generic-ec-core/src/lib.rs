@@ -16,6 +16,8 @@ use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 use zeroize::Zeroize;
 
 pub mod coords;
+#[cfg(feature = "digest")]
+pub mod expand_message;
 
 /// Elliptic curve
 ///
@@ -25,6 +27,29 @@ pub trait Curve: Debug + Copy + Eq + Ord + Hash + Default + Sync + Send + 'stati
     /// Curve name
     const CURVE_NAME: &'static str;
 
+    /// Hash to curve suite ID defined in [RFC 9380] for this curve
+    ///
+    /// Suite ID identifies a curve, a hash function, and a mapping used for hashing to the curve,
+    /// e.g. `secp256k1_XMD:SHA-256_SSWU_RO_`. Protocols may use it to build a domain separation
+    /// tag `DST = app_tag || suite_id` instead of hardcoding the suite string.
+    ///
+    /// `None` if RFC doesn't define a hash to curve suite for this curve.
+    ///
+    /// [RFC 9380]: https://www.rfc-editor.org/rfc/rfc9380.html
+    const HASH_TO_CURVE_SUITE: Option<&'static str> = None;
+
+    /// Cofactor $h$ of the curve, encoded in big-endian without leading zeroes
+    ///
+    /// Order of the curve group is $h \cdot q$ where $q$ is the order of the prime subgroup.
+    /// Prime-order curves have $h = 1$. Cofactor is represented as bytes as some curves
+    /// (e.g. BLS12-381) have cofactors that don't fit into any primitive integer.
+    const COFACTOR: &'static [u8];
+
+    /// Indicates that the curve provides its own [multiscalar multiplication](Self::vartime_multiscalar_mul)
+    ///
+    /// When it's `true`, `generic-ec` prefers it over generic multiscalar algorithms.
+    const HAS_VARTIME_MULTISCALAR_MUL: bool = false;
+
     /// Type that represents a curve point
     type Point: Additive
         + From<CurveGenerator>
@@ -73,6 +98,51 @@ pub trait Curve: Debug + Copy + Eq + Ord + Hash + Default + Sync + Send + 'stati
     ///
     /// If a curve doesn't expose point coordinates, it may be `[u8; 0]`
     type CoordinateArray: ByteArray;
+
+    /// Returns big-endian bytes representation of curve group order $q$
+    ///
+    /// Default implementation derives it from $-1 = q - 1 \pmod{q}$. Curve implementation
+    /// may override it with a constant.
+    fn group_order_be_bytes() -> Self::ScalarArray {
+        let minus_one = Self::Scalar::negate(&Self::Scalar::one());
+        let mut order = minus_one.to_be_bytes();
+        // $q$ is an odd prime, so $q - 1$ is even, and adding 1 to it never produces a carry
+        if let Some(least_significant_byte) = order.as_mut().last_mut() {
+            debug_assert_eq!(*least_significant_byte & 1, 0);
+            *least_significant_byte |= 1;
+        }
+        order
+    }
+
+    /// Returns cofactor $h$ of the curve, encoded in big-endian without leading zeroes
+    ///
+    /// Same as [`Self::COFACTOR`]
+    fn cofactor() -> &'static [u8] {
+        Self::COFACTOR
+    }
+
+    /// Indicates whether the curve has prime order, i.e. its [cofactor](Self::COFACTOR) is $1$
+    fn is_prime_order() -> bool {
+        Self::COFACTOR == [1]
+    }
+
+    /// Computes $\sum_i s_i P_i$ where $s_i$ are `scalars` and $P_i$ are `points`
+    ///
+    /// Curve implementation may override it with multiscalar algorithm optimized specifically
+    /// for this curve, in which case it must also set [`HAS_VARTIME_MULTISCALAR_MUL`](Self::HAS_VARTIME_MULTISCALAR_MUL)
+    /// to `true`. Default implementation computes the sum naively. Implementation may be
+    /// variable time.
+    ///
+    /// `scalars` and `points` must have the same length.
+    fn vartime_multiscalar_mul(scalars: &[Self::Scalar], points: &[Self::Point]) -> Self::Point {
+        debug_assert_eq!(scalars.len(), points.len());
+        scalars
+            .iter()
+            .zip(points)
+            .fold(Self::Point::zero(), |acc, (s, p)| {
+                Self::Point::add(&acc, &<Self::Scalar as Multiplicative<_>>::mul(s, p))
+            })
+    }
 }
 
 /// Type for which addition is defined
@@ -112,6 +182,18 @@ where
     fn invert(x: &Self) -> CtOption<Self>;
 }
 
+/// Type for which square root is defined
+pub trait Sqrt
+where
+    Self: Sized,
+{
+    /// Computes square root of $x$, i.e. returns $y$ such that $y^2 = x$
+    ///
+    /// Returns `None` if $x$ is not a quadratic residue. If square root exists, it's not specified
+    /// which of two roots $y$ or $-y$ is returned.
+    fn sqrt(x: &Self) -> CtOption<Self>;
+}
+
 /// Type that has zero value (additive identity)
 pub trait Zero {
     /// Constructs zero value of `Self`
@@ -146,6 +228,16 @@ pub trait SmallFactor {
     fn is_torsion_free(&self) -> Choice;
 }
 
+/// Multiplication at curve cofactor
+///
+/// Cofactor $h$ itself is exposed via [`Curve::COFACTOR`].
+pub trait Cofactor {
+    /// Multiplies the point at cofactor, returns $h \cdot P$
+    ///
+    /// Output is always free of torsion component.
+    fn mul_by_cofactor(&self) -> Self;
+}
+
 /// Curve generator
 ///
 /// Represents a curve generator. The curve point must implement `From<CurveGenerator>`.
@@ -181,6 +273,26 @@ where
     fn to_bytes_uncompressed(&self) -> Self::Bytes;
 }
 
+/// Encoding of many points at once
+///
+/// Encoding a point usually requires converting it into affine coordinates, which involves
+/// an expensive field inversion. Implementation may share a single inversion across the whole
+/// batch (using Montgomery's trick). Backends that can't do that may simply encode points one
+/// by one.
+pub trait BatchEncoding: CompressedEncoding + UncompressedEncoding {
+    /// Encodes `points` in compressed form, writes the results into `out`
+    ///
+    /// `out` must have the same length as `points`
+    fn to_bytes_compressed_batch(points: &[Self], out: &mut [<Self as CompressedEncoding>::Bytes]);
+    /// Encodes `points` in uncompressed form, writes the results into `out`
+    ///
+    /// `out` must have the same length as `points`
+    fn to_bytes_uncompressed_batch(
+        points: &[Self],
+        out: &mut [<Self as UncompressedEncoding>::Bytes],
+    );
+}
+
 /// Encodes an integer as bytes
 pub trait IntegerEncoding
 where
@@ -209,17 +321,59 @@ where
     fn from_le_bytes_mod_order(bytes: &[u8]) -> Self;
 }
 
+/// Conversion between a scalar and a big integer
+///
+/// Allows converting scalars to/from big integers without encoding them as bytes
+pub trait UintConversion: Sized {
+    /// Big integer that fits any scalar
+    type Uint;
+
+    /// Converts scalar into big integer
+    ///
+    /// Returned integer is always less than curve group order
+    fn to_uint(&self) -> Self::Uint;
+    /// Converts big integer into scalar
+    ///
+    /// Returns `None` if integer is not less than curve group order
+    fn from_uint(uint: &Self::Uint) -> CtOption<Self>;
+}
+
 /// Decodes a point from its compressed or uncompressed representation
 pub trait Decode: Sized {
     /// Decodes a point from its compressed or uncompressed representation
     fn decode(bytes: &[u8]) -> Option<Self>;
 }
 
+/// Curve supporting hashing to the curve as defined in [RFC 9380]
+///
+/// Hashing is done according to suite [`Curve::HASH_TO_CURVE_SUITE`].
+///
+/// [RFC 9380]: https://www.rfc-editor.org/rfc/rfc9380.html
+pub trait HashToCurve: Curve {
+    /// Hashes a message to the curve point
+    ///
+    /// `msgs` and `dsts` are concatenated into a single message and a single domain separation
+    /// tag respectively. Output point must be torsion-free. Returns error if DST is empty.
+    fn hash_to_curve(msgs: &[&[u8]], dsts: &[&[u8]]) -> Result<Self::Point, Error>;
+}
+
 /// Error type
+#[derive(Debug, Clone, Copy)]
 pub struct Error;
 
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("generic error")
+    }
+}
+
 /// Byte array
 pub trait ByteArray: AsRef<[u8]> + AsMut<[u8]> + Clone + Send + Sync + 'static {
+    /// Length of the array
+    ///
+    /// Must be equal to `Self::zeroes().as_ref().len()`
+    const LEN: usize;
+
     /// New byte array of zeroes
     ///
     /// Alternative to [`Default`] that is not implemented for generic `[T; N]`
@@ -228,12 +382,16 @@ pub trait ByteArray: AsRef<[u8]> + AsMut<[u8]> + Clone + Send + Sync + 'static {
 }
 
 impl<const N: usize> ByteArray for [u8; N] {
+    const LEN: usize = N;
+
     fn zeroes() -> Self {
         [0; N]
     }
 }
 
 impl<N: ArrayLength<u8>> ByteArray for GenericArray<u8, N> {
+    const LEN: usize = N::USIZE;
+
     fn zeroes() -> Self {
         GenericArray::default()
     }
@@ -10,9 +10,19 @@ fn bench_curves(c: &mut criterion::Criterion) {
     bench_curve::<generic_ec_curves::Secp256k1>(c, &mut rng, "secp256k1");
     bench_bytes_reduction::<generic_ec_curves::Secp256k1, 32>(c, &mut rng, "secp256k1");
     bench_bytes_reduction::<generic_ec_curves::Secp256k1, 64>(c, &mut rng, "secp256k1");
+    bench_batch_encoding::<generic_ec_curves::Secp256k1>(c, &mut rng, "secp256k1");
 
     bench_curve::<generic_ec_curves::Secp256r1>(c, &mut rng, "secp256r1");
     bench_bytes_reduction::<generic_ec_curves::Secp256r1, 32>(c, &mut rng, "secp256r1");
+    bench_batch_encoding::<generic_ec_curves::Secp256r1>(c, &mut rng, "secp256r1");
+
+    bench_curve::<generic_ec_curves::Secp384r1>(c, &mut rng, "secp384r1");
+    bench_bytes_reduction::<generic_ec_curves::Secp384r1, 48>(c, &mut rng, "secp384r1");
+    bench_batch_encoding::<generic_ec_curves::Secp384r1>(c, &mut rng, "secp384r1");
+
+    bench_curve::<generic_ec_curves::Sm2>(c, &mut rng, "sm2");
+    bench_bytes_reduction::<generic_ec_curves::Sm2, 32>(c, &mut rng, "sm2");
+    bench_batch_encoding::<generic_ec_curves::Sm2>(c, &mut rng, "sm2");
 
     bench_curve::<generic_ec_curves::Stark>(c, &mut rng, "stark");
     bench_bytes_reduction::<generic_ec_curves::Stark, 32>(c, &mut rng, "stark");
@@ -20,6 +30,17 @@ fn bench_curves(c: &mut criterion::Criterion) {
     bench_curve::<generic_ec_curves::Ed25519>(c, &mut rng, "ed25519");
     bench_bytes_reduction::<generic_ec_curves::Ed25519, 32>(c, &mut rng, "ed25519");
     bench_bytes_reduction::<generic_ec_curves::Ed25519, 64>(c, &mut rng, "ed25519");
+    bench_batch_encoding::<generic_ec_curves::Ed25519>(c, &mut rng, "ed25519");
+
+    bench_curve::<generic_ec_curves::Ristretto255>(c, &mut rng, "ristretto255");
+
+    bench_curve::<generic_ec_curves::Bls12_381G1>(c, &mut rng, "bls12_381_g1");
+    bench_bytes_reduction::<generic_ec_curves::Bls12_381G1, 32>(c, &mut rng, "bls12_381_g1");
+    bench_bytes_reduction::<generic_ec_curves::Bls12_381G1, 64>(c, &mut rng, "bls12_381_g1");
+    bench_batch_encoding::<generic_ec_curves::Bls12_381G1>(c, &mut rng, "bls12_381_g1");
+
+    bench_curve::<generic_ec_curves::Bls12_381G2>(c, &mut rng, "bls12_381_g2");
+    bench_batch_encoding::<generic_ec_curves::Bls12_381G2>(c, &mut rng, "bls12_381_g2");
 }
 
 fn bench_curve<E: Curve>(
@@ -167,6 +188,58 @@ fn bench_curve<E: Curve>(
     }
 }
 
+/// Compares encoding (and decoding) 256 points one by one vs in a batch
+fn bench_batch_encoding<E: Curve>(
+    c: &mut criterion::Criterion,
+    rng: &mut rand_dev::DevRng,
+    curve: &str,
+) where
+    E::Point: BatchEncoding,
+{
+    const N: usize = 256;
+
+    let mut g = c.benchmark_group(format!("{curve}/{N}Points"));
+    g.bench_function("EncodeCompressed", |b| {
+        b.iter_batched(
+            || random_points::<E>(rng, N),
+            |points| {
+                points
+                    .iter()
+                    .map(|p| p.to_bytes_compressed())
+                    .collect::<Vec<_>>()
+            },
+            criterion::BatchSize::SmallInput,
+        );
+    });
+    g.bench_function("BatchEncodeCompressed", |b| {
+        b.iter_batched(
+            || random_points::<E>(rng, N),
+            |points| {
+                let mut out = vec![E::CompressedPointArray::zeroes(); N];
+                E::Point::to_bytes_compressed_batch(&points, &mut out);
+                out
+            },
+            criterion::BatchSize::SmallInput,
+        );
+    });
+    g.bench_function("DecodeCompressed", |b| {
+        b.iter_batched(
+            || {
+                let mut out = vec![E::CompressedPointArray::zeroes(); N];
+                E::Point::to_bytes_compressed_batch(&random_points::<E>(rng, N), &mut out);
+                out
+            },
+            |encoded| {
+                encoded
+                    .iter()
+                    .map(|bytes| E::Point::decode(bytes.as_ref()))
+                    .collect::<Vec<_>>()
+            },
+            criterion::BatchSize::SmallInput,
+        );
+    });
+}
+
 fn bench_bytes_reduction<E: Curve, const N: usize>(
     c: &mut criterion::Criterion,
     rng: &mut rand_dev::DevRng,
@@ -196,9 +269,37 @@ fn bench_bytes_reduction<E: Curve, const N: usize>(
             criterion::BatchSize::SmallInput,
         );
     });
+
+    // Baseline: byte-by-byte reduction via Horner's method
+    let bytes_scalars = (0..=u8::MAX)
+        .map(|b| E::Scalar::from_be_bytes_mod_order(&[b]))
+        .collect::<Vec<_>>();
+    let scalar_0x100 = E::Scalar::from_be_bytes_mod_order(&[1, 0]);
+    c.bench_function(&format!("{curve}/HornerBe/{N}"), |b| {
+        b.iter_batched(
+            || {
+                let mut bytes = [0u8; N];
+                rng.fill_bytes(&mut bytes);
+                bytes
+            },
+            |bytes| {
+                bytes.iter().fold(E::Scalar::zero(), |acc, byte| {
+                    E::Scalar::add(
+                        &E::Scalar::mul(&acc, &scalar_0x100),
+                        &bytes_scalars[usize::from(*byte)],
+                    )
+                })
+            },
+            criterion::BatchSize::SmallInput,
+        );
+    });
 }
 
 fn random_point<E: Curve>(rng: &mut rand_dev::DevRng) -> E::Point {
     let scalar = E::Scalar::random(rng);
     E::Scalar::mul(&scalar, &CurveGenerator)
 }
+
+fn random_points<E: Curve>(rng: &mut rand_dev::DevRng, n: usize) -> Vec<E::Point> {
+    (0..n).map(|_| random_point::<E>(rng)).collect()
+}
@@ -0,0 +1,521 @@
+//! BLS12-381 pairing-friendly curve
+//!
+//! BLS12-381 defines two groups $\mathbb{G}_1$ and $\mathbb{G}_2$ of the same prime order $q$.
+//! Each of them is exposed as a separate curve: [`Bls12_381G1`] and [`Bls12_381G2`]. Group
+//! $\mathbb{G}_1$ is defined over the base field, points are encoded in 48 bytes (compressed)
+//! or 96 bytes (uncompressed). Group $\mathbb{G}_2$ is defined over the quadratic extension of
+//! the base field, points are encoded in 96 bytes (compressed) or 192 bytes (uncompressed).
+//! Encoding follows the [ZCash serialization format].
+//!
+//! Scalars of both groups are integers modulo $q$. [`g1::Scalar`] and [`g2::Scalar`] can be
+//! converted into each other for free.
+//!
+//! Pairing is not a part of [`Curve`](generic_ec_core::Curve) trait, it's not exposed by this
+//! module. Points can be converted into [`bls12_381`](::bls12_381) types to compute the pairing.
+//!
+//! Hashing to the curve is available via `bls12-381-hash-to-curve` feature. It's built on top of
+//! `experimental` API of [`bls12_381`](::bls12_381) crate, which is not covered by semver
+//! guarantees, so the feature is opt-in.
+//!
+//! [ZCash serialization format]: https://github.com/zkcrypto/pairing/tree/master/src/bls12_381#serialization
+
+pub use self::{g1::Bls12_381G1, g2::Bls12_381G2};
+
+/// Implements a curve, its point and scalar for one of BLS12-381 groups
+macro_rules! bls12_381_group {
+    (
+        curve: $curve:ident,
+        curve_name: $curve_name:literal,
+        group: $group:literal,
+        hash_to_curve_suite: $hash_to_curve_suite:literal,
+        field_element_len: $field_element_len:literal,
+        cofactor: $cofactor:expr,
+        projective: $projective:ident,
+        affine: $affine:ident,
+        compressed_len: $compressed_len:literal,
+        uncompressed_len: $uncompressed_len:literal,
+        other_scalar: $other_scalar:path,
+    ) => {
+        #[doc = concat!("BLS12-381 ", $group, " group")]
+        #[derive(
+            Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash, Default, zeroize::Zeroize,
+        )]
+        pub struct $curve {
+            _private: (),
+        }
+
+        impl generic_ec_core::Curve for $curve {
+            const CURVE_NAME: &'static str = $curve_name;
+            const HASH_TO_CURVE_SUITE: Option<&'static str> =
+                if cfg!(feature = "bls12-381-hash-to-curve") {
+                    Some($hash_to_curve_suite)
+                } else {
+                    None
+                };
+            const COFACTOR: &'static [u8] = &$cofactor;
+
+            type Point = Point;
+            type Scalar = Scalar;
+
+            type CompressedPointArray = <Point as generic_ec_core::CompressedEncoding>::Bytes;
+            type UncompressedPointArray = <Point as generic_ec_core::UncompressedEncoding>::Bytes;
+
+            type ScalarArray = <Scalar as generic_ec_core::IntegerEncoding>::Bytes;
+
+            // We don't expose affine coordinates for BLS12-381 groups
+            type CoordinateArray = [u8; 0];
+        }
+
+        #[cfg(feature = "bls12-381-hash-to-curve")]
+        impl $curve {
+            /// Hashes a message to the group point
+            ///
+            #[doc = concat!("Implements `", $hash_to_curve_suite, "` suite from RFC 9380: message is")]
+            /// expanded via `expand_message_xmd` with SHA-256, hashed to two field elements, each
+            /// of which is mapped to the curve via simplified SWU map. Cofactor of the sum is
+            /// cleared, so output point is always torsion-free.
+            ///
+            /// `msgs` and `dsts` are concatenated into a single message and a single domain
+            /// separation tag respectively. Returns error if DST is empty.
+            ///
+            /// Requires `bls12-381-hash-to-curve` feature.
+            pub fn hash_to_curve(
+                msgs: &[&[u8]],
+                dsts: &[&[u8]],
+            ) -> Result<Point, generic_ec_core::Error> {
+                use ::bls12_381::hash_to_curve::{HashToField, MapToCurve};
+                type Field = <::bls12_381::$projective as MapToCurve>::Field;
+
+                if dsts.iter().all(|dst| dst.is_empty()) {
+                    return Err(generic_ec_core::Error);
+                }
+                let mut uniform_bytes = [0u8; 2 * $field_element_len];
+                generic_ec_core::expand_message::expand_message_xmd::<sha2::Sha256>(
+                    msgs,
+                    dsts,
+                    uniform_bytes.len(),
+                    &mut uniform_bytes,
+                );
+
+                let (u0, u1) = uniform_bytes.split_at($field_element_len);
+                let u0 = Field::from_okm(generic_array::GenericArray::from_slice(u0));
+                let u1 = Field::from_okm(generic_array::GenericArray::from_slice(u1));
+                let q0 = ::bls12_381::$projective::map_to_curve(&u0);
+                let q1 = ::bls12_381::$projective::map_to_curve(&u1);
+                Ok(Point((q0 + q1).clear_h()))
+            }
+        }
+
+        #[cfg(feature = "bls12-381-hash-to-curve")]
+        impl generic_ec_core::HashToCurve for $curve {
+            fn hash_to_curve(
+                msgs: &[&[u8]],
+                dsts: &[&[u8]],
+            ) -> Result<Point, generic_ec_core::Error> {
+                Self::hash_to_curve(msgs, dsts)
+            }
+        }
+
+        #[doc = concat!("BLS12-381 ", $group, " point")]
+        #[derive(Clone, Copy, PartialEq, Eq, zeroize::Zeroize)]
+        #[repr(transparent)]
+        pub struct Point(pub ::bls12_381::$projective);
+
+        impl generic_ec_core::Additive for Point {
+            #[inline]
+            fn add(a: &Self, b: &Self) -> Self {
+                Self(a.0 + b.0)
+            }
+
+            #[inline]
+            fn sub(a: &Self, b: &Self) -> Self {
+                Self(a.0 - b.0)
+            }
+
+            #[inline]
+            fn negate(x: &Self) -> Self {
+                Self(-x.0)
+            }
+        }
+
+        impl From<generic_ec_core::CurveGenerator> for Point {
+            #[inline]
+            fn from(_: generic_ec_core::CurveGenerator) -> Self {
+                Self(::bls12_381::$projective::generator())
+            }
+        }
+
+        impl generic_ec_core::Zero for Point {
+            fn zero() -> Self {
+                Self(::bls12_381::$projective::identity())
+            }
+
+            fn is_zero(x: &Self) -> subtle::Choice {
+                x.0.is_identity()
+            }
+        }
+
+        impl generic_ec_core::OnCurve for Point {
+            #[inline]
+            fn is_on_curve(&self) -> subtle::Choice {
+                self.0.is_on_curve()
+            }
+        }
+
+        impl generic_ec_core::SmallFactor for Point {
+            #[inline]
+            fn is_torsion_free(&self) -> subtle::Choice {
+                ::bls12_381::$affine::from(self.0).is_torsion_free()
+            }
+        }
+
+        impl subtle::ConstantTimeEq for Point {
+            #[inline]
+            fn ct_eq(&self, other: &Self) -> subtle::Choice {
+                self.0.ct_eq(&other.0)
+            }
+        }
+
+        impl subtle::ConditionallySelectable for Point {
+            #[inline]
+            fn conditional_select(a: &Self, b: &Self, choice: subtle::Choice) -> Self {
+                Self(subtle::ConditionallySelectable::conditional_select(
+                    &a.0, &b.0, choice,
+                ))
+            }
+        }
+
+        impl generic_ec_core::CompressedEncoding for Point {
+            type Bytes = [u8; $compressed_len];
+
+            fn to_bytes_compressed(&self) -> Self::Bytes {
+                ::bls12_381::$affine::from(self.0).to_compressed()
+            }
+        }
+        impl generic_ec_core::UncompressedEncoding for Point {
+            type Bytes = [u8; $uncompressed_len];
+
+            fn to_bytes_uncompressed(&self) -> Self::Bytes {
+                ::bls12_381::$affine::from(self.0).to_uncompressed()
+            }
+        }
+
+        /// Points are converted into affine form in batches sharing a single field inversion
+        impl generic_ec_core::BatchEncoding for Point {
+            fn to_bytes_compressed_batch(points: &[Self], out: &mut [[u8; $compressed_len]]) {
+                debug_assert_eq!(points.len(), out.len());
+                to_affine_batch(points, |i, affine| out[i] = affine.to_compressed())
+            }
+
+            fn to_bytes_uncompressed_batch(points: &[Self], out: &mut [[u8; $uncompressed_len]]) {
+                debug_assert_eq!(points.len(), out.len());
+                to_affine_batch(points, |i, affine| out[i] = affine.to_uncompressed())
+            }
+        }
+
+        /// Converts `points` into affine form, calls `f(i, affine_i)` for each of them
+        fn to_affine_batch(points: &[Point], mut f: impl FnMut(usize, &::bls12_381::$affine)) {
+            const CHUNK_SIZE: usize = 32;
+            let mut projective = [::bls12_381::$projective::identity(); CHUNK_SIZE];
+            let mut affine = [::bls12_381::$affine::identity(); CHUNK_SIZE];
+
+            for (chunk_i, chunk) in points.chunks(CHUNK_SIZE).enumerate() {
+                let projective = &mut projective[..chunk.len()];
+                let affine = &mut affine[..chunk.len()];
+                for (projective, point) in projective.iter_mut().zip(chunk) {
+                    *projective = point.0;
+                }
+                ::bls12_381::$projective::batch_normalize(projective, affine);
+                for (i, affine) in affine.iter().enumerate() {
+                    f(chunk_i * CHUNK_SIZE + i, affine)
+                }
+            }
+        }
+
+        impl generic_ec_core::Decode for Point {
+            fn decode(bytes: &[u8]) -> Option<Self> {
+                let affine = if let Ok(bytes) = <&[u8; $compressed_len]>::try_from(bytes) {
+                    ::bls12_381::$affine::from_compressed(bytes)
+                } else if let Ok(bytes) = <&[u8; $uncompressed_len]>::try_from(bytes) {
+                    ::bls12_381::$affine::from_uncompressed(bytes)
+                } else {
+                    return None;
+                };
+                Option::from(affine).map(|p: ::bls12_381::$affine| Self(p.into()))
+            }
+        }
+
+        impl core::cmp::PartialOrd for Point {
+            fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl core::cmp::Ord for Point {
+            fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+                let lhs = generic_ec_core::CompressedEncoding::to_bytes_compressed(self);
+                let rhs = generic_ec_core::CompressedEncoding::to_bytes_compressed(other);
+                lhs.cmp(&rhs)
+            }
+        }
+
+        impl core::hash::Hash for Point {
+            fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+                generic_ec_core::CompressedEncoding::to_bytes_compressed(self).hash(state)
+            }
+        }
+
+        impl Default for Point {
+            fn default() -> Self {
+                Self(::bls12_381::$projective::identity())
+            }
+        }
+
+        #[doc = concat!("BLS12-381 ", $group, " scalar")]
+        ///
+        /// Scalars of both BLS12-381 groups are the same, they can be converted into each other
+        /// via `From` trait.
+        #[derive(Default, Clone, Copy, PartialEq, Eq, zeroize::Zeroize)]
+        pub struct Scalar(pub ::bls12_381::Scalar);
+
+        impl Scalar {
+            /// Scalar equal to 1
+            pub const ONE: Self = Self(::bls12_381::Scalar::one());
+            /// Scalar equal to 0
+            pub const ZERO: Self = Self(::bls12_381::Scalar::zero());
+        }
+
+        impl From<$other_scalar> for Scalar {
+            fn from(s: $other_scalar) -> Self {
+                Self(s.0)
+            }
+        }
+
+        impl generic_ec_core::Additive for Scalar {
+            #[inline]
+            fn add(a: &Self, b: &Self) -> Self {
+                Self(a.0 + b.0)
+            }
+
+            #[inline]
+            fn sub(a: &Self, b: &Self) -> Self {
+                Self(a.0 - b.0)
+            }
+
+            #[inline]
+            fn negate(x: &Self) -> Self {
+                Self(-x.0)
+            }
+        }
+
+        impl generic_ec_core::Multiplicative<Scalar> for Scalar {
+            type Output = Scalar;
+
+            #[inline]
+            fn mul(a: &Self, b: &Scalar) -> Self::Output {
+                Self(a.0 * b.0)
+            }
+        }
+
+        impl generic_ec_core::Multiplicative<Point> for Scalar {
+            type Output = Point;
+
+            #[inline]
+            fn mul(a: &Self, b: &Point) -> Self::Output {
+                Point(b.0 * a.0)
+            }
+        }
+
+        impl generic_ec_core::Multiplicative<generic_ec_core::CurveGenerator> for Scalar {
+            type Output = Point;
+
+            #[inline]
+            fn mul(a: &Self, _: &generic_ec_core::CurveGenerator) -> Self::Output {
+                Point(::bls12_381::$projective::generator() * a.0)
+            }
+        }
+
+        impl generic_ec_core::Invertible for Scalar {
+            fn invert(x: &Self) -> subtle::CtOption<Self> {
+                x.0.invert().map(Self)
+            }
+        }
+
+        impl generic_ec_core::Sqrt for Scalar {
+            fn sqrt(x: &Self) -> subtle::CtOption<Self> {
+                group::ff::Field::sqrt(&x.0).map(Self)
+            }
+        }
+
+        impl generic_ec_core::Zero for Scalar {
+            fn zero() -> Self {
+                Self::ZERO
+            }
+
+            fn is_zero(x: &Self) -> subtle::Choice {
+                subtle::ConstantTimeEq::ct_eq(&x.0, &::bls12_381::Scalar::zero())
+            }
+        }
+
+        impl generic_ec_core::One for Scalar {
+            fn one() -> Self {
+                Self::ONE
+            }
+
+            fn is_one(x: &Self) -> subtle::Choice {
+                subtle::ConstantTimeEq::ct_eq(&x.0, &::bls12_381::Scalar::one())
+            }
+        }
+
+        impl generic_ec_core::Samplable for Scalar {
+            fn random<R: rand_core::RngCore>(rng: &mut R) -> Self {
+                Self(group::ff::Field::random(rng))
+            }
+        }
+
+        impl subtle::ConstantTimeEq for Scalar {
+            fn ct_eq(&self, other: &Self) -> subtle::Choice {
+                self.0.ct_eq(&other.0)
+            }
+        }
+
+        impl subtle::ConditionallySelectable for Scalar {
+            fn conditional_select(a: &Self, b: &Self, choice: subtle::Choice) -> Self {
+                Self(subtle::ConditionallySelectable::conditional_select(
+                    &a.0, &b.0, choice,
+                ))
+            }
+        }
+
+        impl generic_ec_core::IntegerEncoding for Scalar {
+            type Bytes = [u8; 32];
+
+            fn to_be_bytes(&self) -> Self::Bytes {
+                let mut bytes = self.to_le_bytes();
+                bytes.reverse();
+                bytes
+            }
+
+            fn to_le_bytes(&self) -> Self::Bytes {
+                self.0.to_bytes()
+            }
+
+            fn from_be_bytes_exact(bytes: &Self::Bytes) -> Option<Self> {
+                let mut bytes = *bytes;
+                bytes.reverse();
+                Self::from_le_bytes_exact(&bytes)
+            }
+
+            fn from_le_bytes_exact(bytes: &Self::Bytes) -> Option<Self> {
+                Option::from(::bls12_381::Scalar::from_bytes(bytes)).map(Self)
+            }
+
+            fn from_be_bytes_mod_order(bytes: &[u8]) -> Self {
+                crate::utils::scalar_from_be_bytes_mod_order_reducing_32_64(bytes, &Self::ONE)
+            }
+
+            fn from_le_bytes_mod_order(bytes: &[u8]) -> Self {
+                crate::utils::scalar_from_le_bytes_mod_order_reducing_32_64(bytes, &Self::ONE)
+            }
+        }
+
+        #[cfg(feature = "crypto-bigint")]
+        impl generic_ec_core::UintConversion for Scalar {
+            type Uint = crypto_bigint::U256;
+
+            fn to_uint(&self) -> Self::Uint {
+                crypto_bigint::U256::from_le_slice(&self.0.to_bytes())
+            }
+
+            fn from_uint(uint: &Self::Uint) -> subtle::CtOption<Self> {
+                let bytes = crypto_bigint::Encoding::to_le_bytes(uint);
+                ::bls12_381::Scalar::from_bytes(&bytes).map(Self)
+            }
+        }
+
+        impl core::cmp::PartialOrd for Scalar {
+            fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl core::cmp::Ord for Scalar {
+            fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+                self.0.to_bytes().cmp(&other.0.to_bytes())
+            }
+        }
+
+        impl generic_ec_core::Reduce<32> for Scalar {
+            fn from_be_array_mod_order(bytes: &[u8; 32]) -> Self {
+                let mut bytes = *bytes;
+                bytes.reverse();
+                <Self as generic_ec_core::Reduce<32>>::from_le_array_mod_order(&bytes)
+            }
+            fn from_le_array_mod_order(bytes: &[u8; 32]) -> Self {
+                let mut wide = [0u8; 64];
+                wide[..32].copy_from_slice(bytes);
+                Self(::bls12_381::Scalar::from_bytes_wide(&wide))
+            }
+        }
+        impl generic_ec_core::Reduce<64> for Scalar {
+            fn from_be_array_mod_order(bytes: &[u8; 64]) -> Self {
+                let mut bytes = *bytes;
+                bytes.reverse();
+                Self(::bls12_381::Scalar::from_bytes_wide(&bytes))
+            }
+            fn from_le_array_mod_order(bytes: &[u8; 64]) -> Self {
+                Self(::bls12_381::Scalar::from_bytes_wide(bytes))
+            }
+        }
+    };
+}
+
+/// BLS12-381 $\mathbb{G}_1$ group
+pub mod g1 {
+    bls12_381_group! {
+        curve: Bls12_381G1,
+        curve_name: "bls12_381_g1",
+        group: "G1",
+        hash_to_curve_suite: "BLS12381G1_XMD:SHA-256_SSWU_RO_",
+        // Element of $\mathbb{F}_p$ is derived from $L = 64$ bytes
+        field_element_len: 64,
+        // $h_1 = (x - 1)^2 / 3$ where $x = -\texttt{0xd201000000010000}$
+        cofactor: [
+            0x39, 0x6c, 0x8c, 0x00, 0x55, 0x55, 0xe1, 0x56,
+            0x8c, 0x00, 0xaa, 0xab, 0x00, 0x00, 0xaa, 0xab,
+        ],
+        projective: G1Projective,
+        affine: G1Affine,
+        compressed_len: 48,
+        uncompressed_len: 96,
+        other_scalar: super::g2::Scalar,
+    }
+}
+
+/// BLS12-381 $\mathbb{G}_2$ group
+pub mod g2 {
+    bls12_381_group! {
+        curve: Bls12_381G2,
+        curve_name: "bls12_381_g2",
+        group: "G2",
+        hash_to_curve_suite: "BLS12381G2_XMD:SHA-256_SSWU_RO_",
+        // Element of $\mathbb{F}_{p^2}$ is derived from $2 \cdot L = 128$ bytes
+        field_element_len: 128,
+        // $h_2 = (x^8 - 4x^7 + 5x^6 - 4x^4 + 6x^3 - 4x^2 - 4x + 13) / 9$, $x$ is the same as for $h_1$
+        cofactor: [
+            0x05, 0xd5, 0x43, 0xa9, 0x54, 0x14, 0xe7, 0xf1,
+            0x09, 0x1d, 0x50, 0x79, 0x28, 0x76, 0xa2, 0x02,
+            0xcd, 0x91, 0xde, 0x45, 0x47, 0x08, 0x5a, 0xba,
+            0xa6, 0x8a, 0x20, 0x5b, 0x2e, 0x5a, 0x7d, 0xdf,
+            0xa6, 0x28, 0xf1, 0xcb, 0x4d, 0x9e, 0x82, 0xef,
+            0x21, 0x53, 0x7e, 0x29, 0x3a, 0x66, 0x91, 0xae,
+            0x16, 0x16, 0xec, 0x6e, 0x78, 0x6f, 0x0c, 0x70,
+            0xcf, 0x1c, 0x38, 0xe3, 0x1c, 0x72, 0x38, 0xe5,
+        ],
+        projective: G2Projective,
+        affine: G2Affine,
+        compressed_len: 96,
+        uncompressed_len: 192,
+        other_scalar: super::g1::Scalar,
+    }
+}
@@ -1,5 +1,7 @@
 //! Ed25519 curve
 
+mod hash_to_curve;
+
 /// Ed25519 curve
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash, Default, zeroize::Zeroize)]
 pub struct Ed25519 {
@@ -8,6 +10,8 @@ pub struct Ed25519 {
 
 impl generic_ec_core::Curve for Ed25519 {
     const CURVE_NAME: &'static str = "ed25519";
+    const HASH_TO_CURVE_SUITE: Option<&'static str> = Some("edwards25519_XMD:SHA-512_ELL2_RO_");
+    const COFACTOR: &'static [u8] = &[8];
 
     type Point = Point;
     type Scalar = Scalar;
@@ -19,6 +23,20 @@ impl generic_ec_core::Curve for Ed25519 {
 
     // We don't expose affine coordinates for ed25519 curve
     type CoordinateArray = [u8; 0];
+
+    #[cfg(feature = "alloc")]
+    const HAS_VARTIME_MULTISCALAR_MUL: bool = true;
+
+    /// Uses `curve25519-dalek` variable time multiscalar multiplication
+    #[cfg(feature = "alloc")]
+    fn vartime_multiscalar_mul(scalars: &[Scalar], points: &[Point]) -> Point {
+        use curve25519::traits::VartimeMultiscalarMul;
+        debug_assert_eq!(scalars.len(), points.len());
+        Point(curve25519::EdwardsPoint::vartime_multiscalar_mul(
+            scalars.iter().map(|s| &s.0),
+            points.iter().map(|p| &p.0),
+        ))
+    }
 }
 
 /// Ed25519 point
@@ -26,6 +44,18 @@ impl generic_ec_core::Curve for Ed25519 {
 #[repr(transparent)]
 pub struct Point(pub curve25519::EdwardsPoint);
 
+impl Point {
+    /// Converts a point into Montgomery form and returns its $u$ coordinate, as used in X25519
+    ///
+    /// Conversion is done via birational map $u = (1 + y) / (1 - y)$. Montgomery $u$ coordinate
+    /// doesn't carry sign of Edwards $x$ coordinate, so $P$ and $-P$ are mapped to the same
+    /// $u$, and the conversion can't be reversed unambiguously. Identity point is mapped
+    /// to $u = 0$.
+    pub fn to_montgomery(&self) -> [u8; 32] {
+        self.0.to_montgomery().to_bytes()
+    }
+}
+
 impl generic_ec_core::Additive for Point {
     #[inline]
     fn add(a: &Self, b: &Self) -> Self {
@@ -74,6 +104,13 @@ impl generic_ec_core::SmallFactor for Point {
     }
 }
 
+impl generic_ec_core::Cofactor for Point {
+    #[inline]
+    fn mul_by_cofactor(&self) -> Self {
+        Self(self.0.mul_by_cofactor())
+    }
+}
+
 impl subtle::ConstantTimeEq for Point {
     #[inline]
     fn ct_eq(&self, other: &Self) -> subtle::Choice {
@@ -105,6 +142,20 @@ impl generic_ec_core::UncompressedEncoding for Point {
     }
 }
 
+/// `curve25519-dalek` doesn't provide batch compression, so points are encoded one by one
+impl generic_ec_core::BatchEncoding for Point {
+    fn to_bytes_compressed_batch(points: &[Self], out: &mut [[u8; 32]]) {
+        debug_assert_eq!(points.len(), out.len());
+        for (point, out) in points.iter().zip(out) {
+            *out = point.0.compress().to_bytes();
+        }
+    }
+
+    fn to_bytes_uncompressed_batch(points: &[Self], out: &mut [[u8; 32]]) {
+        Self::to_bytes_compressed_batch(points, out)
+    }
+}
+
 impl generic_ec_core::Decode for Point {
     fn decode(bytes: &[u8]) -> Option<Self> {
         let compressed = curve25519::edwards::CompressedEdwardsY::from_slice(bytes).ok()?;
@@ -200,6 +251,12 @@ impl generic_ec_core::Invertible for Scalar {
     }
 }
 
+impl generic_ec_core::Sqrt for Scalar {
+    fn sqrt(x: &Self) -> subtle::CtOption<Self> {
+        group::ff::Field::sqrt(&x.0).map(Self)
+    }
+}
+
 impl generic_ec_core::Zero for Scalar {
     fn zero() -> Self {
         Self(curve25519::Scalar::ZERO)
@@ -222,27 +279,10 @@ impl generic_ec_core::One for Scalar {
 
 impl generic_ec_core::Samplable for Scalar {
     fn random<R: rand_core::RngCore>(rng: &mut R) -> Self {
-        // Having crypto rng for scalar generation is not a hard requirement,
-        // as in some cases it isn't needed. However, `curve25519` lib asks for
-        // it, so we'll trick it
-        struct FakeCryptoRng<R>(R);
-        impl<R: rand_core::RngCore> rand_core::RngCore for FakeCryptoRng<R> {
-            fn next_u32(&mut self) -> u32 {
-                self.0.next_u32()
-            }
-            fn next_u64(&mut self) -> u64 {
-                self.0.next_u64()
-            }
-            fn fill_bytes(&mut self, dest: &mut [u8]) {
-                self.0.fill_bytes(dest)
-            }
-            fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
-                self.0.try_fill_bytes(dest)
-            }
-        }
-        impl<R> rand_core::CryptoRng for FakeCryptoRng<R> {}
-
-        Self(curve25519::Scalar::random(&mut FakeCryptoRng(rng)))
+        // Same as `curve25519::Scalar::random`, which we can't call as it requires `CryptoRng`
+        let mut bytes = zeroize::Zeroizing::new([0u8; 64]);
+        rng.fill_bytes(&mut *bytes);
+        Self(curve25519::Scalar::from_bytes_mod_order_wide(&bytes))
     }
 }
 
@@ -292,6 +332,20 @@ impl generic_ec_core::IntegerEncoding for Scalar {
     }
 }
 
+#[cfg(feature = "crypto-bigint")]
+impl generic_ec_core::UintConversion for Scalar {
+    type Uint = crypto_bigint::U256;
+
+    fn to_uint(&self) -> Self::Uint {
+        crypto_bigint::U256::from_le_slice(self.0.as_bytes())
+    }
+
+    fn from_uint(uint: &Self::Uint) -> subtle::CtOption<Self> {
+        let bytes = crypto_bigint::Encoding::to_le_bytes(uint);
+        curve25519::Scalar::from_canonical_bytes(bytes).map(Self)
+    }
+}
+
 impl core::cmp::PartialOrd for Scalar {
     fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
         Some(self.cmp(other))
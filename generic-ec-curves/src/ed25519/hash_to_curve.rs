@@ -0,0 +1,155 @@
+//! Hash to curve defined in [RFC 9380](https://www.rfc-editor.org/rfc/rfc9380.html), suite
+//! `edwards25519_XMD:SHA-512_ELL2_RO_`
+
+use crypto_bigint::{impl_modulus, modular::constant_mod::Residue, Encoding, U256};
+use generic_ec_core::expand_message::expand_message_xmd;
+use sha2::Sha512;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+
+use super::{Ed25519, Point};
+
+impl_modulus!(
+    FieldModulus,
+    U256,
+    "7fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffed"
+);
+
+/// Element of $\mathbb{F}_p$, $p = 2^{255} - 19$
+type Fe = Residue<FieldModulus, { U256::LIMBS }>;
+
+/// Montgomery curve parameter $A$ of curve25519
+const A: Fe = Fe::new(&U256::from_u64(486662));
+/// Non-square $Z$ used by Elligator 2 map (RFC 9380, Section 4.1)
+const Z: Fe = Fe::new(&U256::from_u64(2));
+/// $2^{256} \bmod p$
+const TWO_POW_256: Fe = Fe::new(&U256::from_u64(38));
+/// $\sqrt{-486664}$ such that $\text{sgn0}(c) = 0$, used by the rational map to Edwards form
+const SQRT_MINUS_486664: Fe = Fe::new(&U256::from_be_hex(
+    "0f26edf460a006bbd27b08dc03fc4f7ec5a1d3d14b7d1a82cc6e04aaff457e06",
+));
+/// $\sqrt{-1}$
+const SQRT_MINUS_ONE: Fe = Fe::new(&U256::from_be_hex(
+    "2b8324804fc1df0b2b4d00993dfbd7a72f431806ad2fe478c4ee1b274a0ea0b0",
+));
+/// $(p - 1) / 2$
+const P_MINUS_ONE_HALF: U256 =
+    U256::from_be_hex("3ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff6");
+/// $(p + 3) / 8$
+const P_PLUS_THREE_EIGHTH: U256 =
+    U256::from_be_hex("0ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffe");
+
+/// Length of an element of hash-to-field output: $L = \lceil (\lceil \log_2 p \rceil + k) / 8 \rceil$
+/// with security parameter $k = 128$
+const FIELD_ELEMENT_LEN: usize = 48;
+
+impl Ed25519 {
+    /// Hashes a message to the curve point
+    ///
+    /// Implements `edwards25519_XMD:SHA-512_ELL2_RO_` suite from RFC 9380: message is expanded
+    /// via `expand_message_xmd` with SHA-512, hashed to two field elements, each of which is
+    /// mapped to the curve via Elligator 2. Cofactor of the sum is cleared, so output point
+    /// is always torsion-free.
+    ///
+    /// `msgs` and `dsts` are concatenated into a single message and a single domain separation
+    /// tag respectively. Returns error if DST is empty.
+    pub fn hash_to_curve(msgs: &[&[u8]], dsts: &[&[u8]]) -> Result<Point, generic_ec_core::Error> {
+        if dsts.iter().all(|dst| dst.is_empty()) {
+            return Err(generic_ec_core::Error);
+        }
+        let mut uniform_bytes = [0u8; 2 * FIELD_ELEMENT_LEN];
+        expand_message_xmd::<Sha512>(msgs, dsts, uniform_bytes.len(), &mut uniform_bytes);
+
+        let (u0, u1) = uniform_bytes.split_at(FIELD_ELEMENT_LEN);
+        let q0 = map_to_curve(&fe_from_be_bytes_mod_order(u0))?;
+        let q1 = map_to_curve(&fe_from_be_bytes_mod_order(u1))?;
+        Ok(Point((q0 + q1).mul_by_cofactor()))
+    }
+}
+
+impl generic_ec_core::HashToCurve for Ed25519 {
+    fn hash_to_curve(msgs: &[&[u8]], dsts: &[&[u8]]) -> Result<Point, generic_ec_core::Error> {
+        Self::hash_to_curve(msgs, dsts)
+    }
+}
+
+/// Interprets [`FIELD_ELEMENT_LEN`] bytes as big-endian integer, and reduces it modulo $p$
+fn fe_from_be_bytes_mod_order(bytes: &[u8]) -> Fe {
+    let (hi, lo) = bytes.split_at(FIELD_ELEMENT_LEN - 32);
+    let mut hi_padded = [0u8; 32];
+    hi_padded[32 - hi.len()..].copy_from_slice(hi);
+    // `Fe::new` reduces any 256-bit integer, not necessarily less than $p$
+    let hi = Fe::new(&U256::from_be_bytes(hi_padded));
+    let lo = Fe::new(&U256::from_be_slice(lo));
+    hi * TWO_POW_256 + lo
+}
+
+/// Elligator 2 map to curve25519 followed by the rational map to edwards25519
+/// (RFC 9380, Sections 6.7.1 and 6.8.2)
+fn map_to_curve(u: &Fe) -> Result<curve25519::EdwardsPoint, generic_ec_core::Error> {
+    let one = Fe::ONE;
+
+    // Map to Montgomery curve $t^2 = s^3 + A s^2 + s$
+    let mut tv1 = Z * u.square();
+    tv1.conditional_assign(&Fe::ZERO, tv1.ct_eq(&-one));
+    let (inv, _) = (tv1 + one).invert();
+    let x1 = -A * inv;
+    let gx1 = x1 * (x1 * (x1 + A) + one);
+    let x2 = -x1 - A;
+    let gx2 = x2 * (x2 * (x2 + A) + one);
+    let e2 = is_square(&gx1);
+    let s = Fe::conditional_select(&x2, &x1, e2);
+    let t2 = Fe::conditional_select(&gx2, &gx1, e2);
+    let t = sqrt(&t2);
+    let e3 = sgn0(&t);
+    let t = Fe::conditional_select(&t, &-t, e2 ^ e3);
+
+    // Rational map to Edwards curve: $(x, y) = (\sqrt{-486664} \cdot s / t, (s - 1) / (s + 1))$
+    let mut x_num = SQRT_MINUS_486664 * s;
+    let mut x_den = t;
+    let mut y_num = s - one;
+    let mut y_den = s + one;
+    let exceptional = x_den.ct_eq(&Fe::ZERO) | y_den.ct_eq(&Fe::ZERO);
+    x_num.conditional_assign(&Fe::ZERO, exceptional);
+    x_den.conditional_assign(&one, exceptional);
+    y_num.conditional_assign(&one, exceptional);
+    y_den.conditional_assign(&one, exceptional);
+    let x = x_num * x_den.invert().0;
+    let y = y_num * y_den.invert().0;
+
+    // Encode the point and decode it back. Note that the point is always on the curve, so
+    // decoding never fails.
+    let mut compressed = y.retrieve().to_le_bytes();
+    compressed[31] |= sgn0(&x).unwrap_u8() << 7;
+    curve25519::edwards::CompressedEdwardsY(compressed)
+        .decompress()
+        .ok_or(generic_ec_core::Error)
+}
+
+/// Checks whether $x$ is a square in $\mathbb{F}_p$, i.e. $x^{(p - 1) / 2} \in \\{0, 1\\}$
+fn is_square(x: &Fe) -> Choice {
+    let legendre = x.pow(&P_MINUS_ONE_HALF);
+    legendre.ct_eq(&Fe::ZERO) | legendre.ct_eq(&Fe::ONE)
+}
+
+/// Computes square root of $x$ assuming it's a square (RFC 9380, Appendix I.2)
+fn sqrt(x: &Fe) -> Fe {
+    let tv1 = x.pow(&P_PLUS_THREE_EIGHTH);
+    let tv2 = tv1 * SQRT_MINUS_ONE;
+    Fe::conditional_select(&tv2, &tv1, tv1.square().ct_eq(x))
+}
+
+/// Returns sign of $x$, i.e. $x \bmod 2$ (RFC 9380, Section 4.1)
+fn sgn0(x: &Fe) -> Choice {
+    Choice::from(x.retrieve().to_le_bytes()[0] & 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Ed25519;
+
+    #[test]
+    fn empty_dst_is_rejected() {
+        assert!(Ed25519::hash_to_curve(&[b"abc"], &[]).is_err());
+        assert!(Ed25519::hash_to_curve(&[b"abc"], &[b""]).is_err());
+    }
+}
@@ -13,11 +13,15 @@
 #[cfg(docsrs)]
 pub mod __docs;
 
-#[cfg(any(feature = "ed25519", feature = "rust-crypto"))]
+#[cfg(any(feature = "ed25519", feature = "rust-crypto", feature = "bls12-381"))]
 mod utils;
 
+#[cfg(feature = "bls12-381")]
+pub mod bls12_381;
 #[cfg(feature = "ed25519")]
 pub mod ed25519;
+#[cfg(feature = "ristretto255")]
+pub mod ristretto255;
 #[cfg(feature = "rust-crypto")]
 pub mod rust_crypto;
 
@@ -27,8 +31,20 @@ pub use rust_crypto::Secp256k1;
 #[cfg(feature = "secp256r1")]
 pub use rust_crypto::Secp256r1;
 
+#[cfg(feature = "secp384r1")]
+pub use rust_crypto::Secp384r1;
+
+#[cfg(feature = "sm2")]
+pub use rust_crypto::Sm2;
+
 #[cfg(feature = "stark")]
 pub use rust_crypto::Stark;
 
 #[cfg(feature = "ed25519")]
 pub use ed25519::Ed25519;
+
+#[cfg(feature = "ristretto255")]
+pub use ristretto255::Ristretto255;
+
+#[cfg(feature = "bls12-381")]
+pub use bls12_381::{Bls12_381G1, Bls12_381G2};
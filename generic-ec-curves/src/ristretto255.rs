@@ -0,0 +1,375 @@
+//! Ristretto255 group
+//!
+//! Ristretto255 is a prime-order group built on top of Curve25519. Unlike [Ed25519](crate::Ed25519),
+//! it has no cofactor: every point is torsion free, and every point has exactly one (canonical)
+//! encoding.
+//!
+//! Scalars are integers modulo the same prime $\ell$ as for Ed25519 curve. [`Scalar`] can be
+//! converted from/to [`ed25519::Scalar`](crate::ed25519::Scalar) for free.
+
+mod hash_to_curve;
+
+/// Ristretto255 group
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash, Default, zeroize::Zeroize)]
+pub struct Ristretto255 {
+    _private: (),
+}
+
+impl generic_ec_core::Curve for Ristretto255 {
+    const CURVE_NAME: &'static str = "ristretto255";
+    const HASH_TO_CURVE_SUITE: Option<&'static str> = Some("ristretto255_XMD:SHA-512_R255MAP_RO_");
+    // Ristretto255 is a prime-order group
+    const COFACTOR: &'static [u8] = &[1];
+
+    type Point = Point;
+    type Scalar = Scalar;
+
+    type CompressedPointArray = <Point as generic_ec_core::CompressedEncoding>::Bytes;
+    type UncompressedPointArray = <Point as generic_ec_core::UncompressedEncoding>::Bytes;
+
+    type ScalarArray = <Scalar as generic_ec_core::IntegerEncoding>::Bytes;
+
+    // Ristretto points don't have affine coordinates
+    type CoordinateArray = [u8; 0];
+}
+
+/// Ristretto255 point
+#[derive(Clone, Copy, PartialEq, Eq, zeroize::Zeroize)]
+#[repr(transparent)]
+pub struct Point(pub curve25519::RistrettoPoint);
+
+impl generic_ec_core::Additive for Point {
+    #[inline]
+    fn add(a: &Self, b: &Self) -> Self {
+        Self(a.0 + b.0)
+    }
+
+    #[inline]
+    fn sub(a: &Self, b: &Self) -> Self {
+        Self(a.0 - b.0)
+    }
+
+    #[inline]
+    fn negate(x: &Self) -> Self {
+        Self(-x.0)
+    }
+}
+
+impl From<generic_ec_core::CurveGenerator> for Point {
+    #[inline]
+    fn from(_: generic_ec_core::CurveGenerator) -> Self {
+        Self(group::Group::generator())
+    }
+}
+
+impl generic_ec_core::Zero for Point {
+    fn zero() -> Self {
+        Self(group::Group::identity())
+    }
+
+    fn is_zero(x: &Self) -> subtle::Choice {
+        subtle::ConstantTimeEq::ct_eq(x, &Self::zero())
+    }
+}
+
+impl generic_ec_core::OnCurve for Point {
+    #[inline]
+    fn is_on_curve(&self) -> subtle::Choice {
+        subtle::Choice::from(1)
+    }
+}
+
+impl generic_ec_core::SmallFactor for Point {
+    #[inline]
+    fn is_torsion_free(&self) -> subtle::Choice {
+        // Ristretto255 is a prime-order group
+        subtle::Choice::from(1)
+    }
+}
+
+impl generic_ec_core::Cofactor for Point {
+    // Ristretto255 is a prime-order group
+    #[inline]
+    fn mul_by_cofactor(&self) -> Self {
+        *self
+    }
+}
+
+impl subtle::ConstantTimeEq for Point {
+    #[inline]
+    fn ct_eq(&self, other: &Self) -> subtle::Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+impl subtle::ConditionallySelectable for Point {
+    #[inline]
+    fn conditional_select(a: &Self, b: &Self, choice: subtle::Choice) -> Self {
+        Self(subtle::ConditionallySelectable::conditional_select(
+            &a.0, &b.0, choice,
+        ))
+    }
+}
+
+impl generic_ec_core::CompressedEncoding for Point {
+    type Bytes = [u8; 32];
+
+    fn to_bytes_compressed(&self) -> Self::Bytes {
+        self.0.compress().to_bytes()
+    }
+}
+impl generic_ec_core::UncompressedEncoding for Point {
+    type Bytes = <Self as generic_ec_core::CompressedEncoding>::Bytes;
+
+    fn to_bytes_uncompressed(&self) -> Self::Bytes {
+        <Self as generic_ec_core::CompressedEncoding>::to_bytes_compressed(self)
+    }
+}
+
+/// `curve25519-dalek` doesn't provide batch compression, so points are encoded one by one
+impl generic_ec_core::BatchEncoding for Point {
+    fn to_bytes_compressed_batch(points: &[Self], out: &mut [[u8; 32]]) {
+        debug_assert_eq!(points.len(), out.len());
+        for (point, out) in points.iter().zip(out) {
+            *out = point.0.compress().to_bytes();
+        }
+    }
+
+    fn to_bytes_uncompressed_batch(points: &[Self], out: &mut [[u8; 32]]) {
+        Self::to_bytes_compressed_batch(points, out)
+    }
+}
+
+impl generic_ec_core::Decode for Point {
+    fn decode(bytes: &[u8]) -> Option<Self> {
+        let compressed = curve25519::ristretto::CompressedRistretto::from_slice(bytes).ok()?;
+        compressed.decompress().map(Self)
+    }
+}
+
+impl core::cmp::PartialOrd for Point {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl core::cmp::Ord for Point {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.0
+            .compress()
+            .as_bytes()
+            .cmp(other.0.compress().as_bytes())
+    }
+}
+
+impl core::hash::Hash for Point {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.0.compress().as_bytes().hash(state)
+    }
+}
+
+impl Default for Point {
+    fn default() -> Self {
+        Self(group::Group::identity())
+    }
+}
+
+/// Ristretto255 scalar
+///
+/// Scalar is the same as [Ed25519 scalar](crate::ed25519::Scalar), they can be converted
+/// into each other via `From` trait.
+#[derive(Default, Clone, Copy, PartialEq, Eq, zeroize::Zeroize)]
+pub struct Scalar(pub curve25519::Scalar);
+
+impl Scalar {
+    /// Scalar equal to 1
+    pub const ONE: Self = Self(curve25519::Scalar::ONE);
+    /// Scalar equal to 0
+    pub const ZERO: Self = Self(curve25519::Scalar::ZERO);
+}
+
+impl From<crate::ed25519::Scalar> for Scalar {
+    fn from(s: crate::ed25519::Scalar) -> Self {
+        Self(s.0)
+    }
+}
+
+impl From<Scalar> for crate::ed25519::Scalar {
+    fn from(s: Scalar) -> Self {
+        Self(s.0)
+    }
+}
+
+impl generic_ec_core::Additive for Scalar {
+    #[inline]
+    fn add(a: &Self, b: &Self) -> Self {
+        Self(a.0 + b.0)
+    }
+
+    #[inline]
+    fn sub(a: &Self, b: &Self) -> Self {
+        Self(a.0 - b.0)
+    }
+
+    #[inline]
+    fn negate(x: &Self) -> Self {
+        Self(-x.0)
+    }
+}
+
+impl generic_ec_core::Multiplicative<Scalar> for Scalar {
+    type Output = Scalar;
+
+    #[inline]
+    fn mul(a: &Self, b: &Scalar) -> Self::Output {
+        Self(a.0 * b.0)
+    }
+}
+
+impl generic_ec_core::Multiplicative<Point> for Scalar {
+    type Output = Point;
+    #[inline]
+    fn mul(a: &Self, b: &Point) -> Self::Output {
+        Point(a.0 * b.0)
+    }
+}
+
+impl generic_ec_core::Multiplicative<generic_ec_core::CurveGenerator> for Scalar {
+    type Output = Point;
+
+    #[inline]
+    fn mul(a: &Self, _: &generic_ec_core::CurveGenerator) -> Self::Output {
+        Point(curve25519::RistrettoPoint::mul_base(&a.0))
+    }
+}
+
+impl generic_ec_core::Invertible for Scalar {
+    fn invert(x: &Self) -> subtle::CtOption<Self> {
+        subtle::CtOption::new(Self(x.0.invert()), !generic_ec_core::Zero::is_zero(x))
+    }
+}
+
+impl generic_ec_core::Sqrt for Scalar {
+    fn sqrt(x: &Self) -> subtle::CtOption<Self> {
+        group::ff::Field::sqrt(&x.0).map(Self)
+    }
+}
+
+impl generic_ec_core::Zero for Scalar {
+    fn zero() -> Self {
+        Self(curve25519::Scalar::ZERO)
+    }
+
+    fn is_zero(x: &Self) -> subtle::Choice {
+        subtle::ConstantTimeEq::ct_eq(&x.0, &curve25519::Scalar::ZERO)
+    }
+}
+
+impl generic_ec_core::One for Scalar {
+    fn one() -> Self {
+        Self(curve25519::Scalar::ONE)
+    }
+
+    fn is_one(x: &Self) -> subtle::Choice {
+        subtle::ConstantTimeEq::ct_eq(&x.0, &curve25519::Scalar::ONE)
+    }
+}
+
+impl generic_ec_core::Samplable for Scalar {
+    fn random<R: rand_core::RngCore>(rng: &mut R) -> Self {
+        <crate::ed25519::Scalar as generic_ec_core::Samplable>::random(rng).into()
+    }
+}
+
+impl subtle::ConstantTimeEq for Scalar {
+    fn ct_eq(&self, other: &Self) -> subtle::Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+impl subtle::ConditionallySelectable for Scalar {
+    fn conditional_select(a: &Self, b: &Self, choice: subtle::Choice) -> Self {
+        Self(subtle::ConditionallySelectable::conditional_select(
+            &a.0, &b.0, choice,
+        ))
+    }
+}
+
+impl generic_ec_core::IntegerEncoding for Scalar {
+    type Bytes = [u8; 32];
+
+    fn to_be_bytes(&self) -> Self::Bytes {
+        let mut bytes = self.to_le_bytes();
+        bytes.reverse();
+        bytes
+    }
+
+    fn to_le_bytes(&self) -> Self::Bytes {
+        self.0.to_bytes()
+    }
+
+    fn from_be_bytes_exact(bytes: &Self::Bytes) -> Option<Self> {
+        let mut bytes = *bytes;
+        bytes.reverse();
+        Self::from_le_bytes_exact(&bytes)
+    }
+
+    fn from_le_bytes_exact(bytes: &Self::Bytes) -> Option<Self> {
+        Option::from(curve25519::Scalar::from_canonical_bytes(*bytes)).map(Self)
+    }
+
+    fn from_be_bytes_mod_order(bytes: &[u8]) -> Self {
+        crate::utils::scalar_from_be_bytes_mod_order_reducing_32_64(bytes, &Self::ONE)
+    }
+
+    fn from_le_bytes_mod_order(bytes: &[u8]) -> Self {
+        crate::utils::scalar_from_le_bytes_mod_order_reducing_32_64(bytes, &Self::ONE)
+    }
+}
+
+#[cfg(feature = "crypto-bigint")]
+impl generic_ec_core::UintConversion for Scalar {
+    type Uint = crypto_bigint::U256;
+
+    fn to_uint(&self) -> Self::Uint {
+        crypto_bigint::U256::from_le_slice(self.0.as_bytes())
+    }
+
+    fn from_uint(uint: &Self::Uint) -> subtle::CtOption<Self> {
+        let bytes = crypto_bigint::Encoding::to_le_bytes(uint);
+        curve25519::Scalar::from_canonical_bytes(bytes).map(Self)
+    }
+}
+
+impl core::cmp::PartialOrd for Scalar {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl core::cmp::Ord for Scalar {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.0.as_bytes().cmp(other.0.as_bytes())
+    }
+}
+
+impl generic_ec_core::Reduce<32> for Scalar {
+    fn from_be_array_mod_order(bytes: &[u8; 32]) -> Self {
+        <crate::ed25519::Scalar as generic_ec_core::Reduce<32>>::from_be_array_mod_order(bytes)
+            .into()
+    }
+    fn from_le_array_mod_order(bytes: &[u8; 32]) -> Self {
+        <crate::ed25519::Scalar as generic_ec_core::Reduce<32>>::from_le_array_mod_order(bytes)
+            .into()
+    }
+}
+impl generic_ec_core::Reduce<64> for Scalar {
+    fn from_be_array_mod_order(bytes: &[u8; 64]) -> Self {
+        <crate::ed25519::Scalar as generic_ec_core::Reduce<64>>::from_be_array_mod_order(bytes)
+            .into()
+    }
+    fn from_le_array_mod_order(bytes: &[u8; 64]) -> Self {
+        <crate::ed25519::Scalar as generic_ec_core::Reduce<64>>::from_le_array_mod_order(bytes)
+            .into()
+    }
+}
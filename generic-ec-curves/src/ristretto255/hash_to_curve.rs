@@ -0,0 +1,78 @@
+//! Hash to group defined in [RFC 9380, Appendix B](https://www.rfc-editor.org/rfc/rfc9380.html#appendix-B),
+//! suite `ristretto255_XMD:SHA-512_R255MAP_RO_`
+
+use generic_ec_core::expand_message::expand_message_xmd;
+use sha2::Sha512;
+
+use super::{Point, Ristretto255};
+
+impl Ristretto255 {
+    /// Hashes a message to the group element
+    ///
+    /// Implements `ristretto255_XMD:SHA-512_R255MAP_RO_` suite from RFC 9380: message is
+    /// expanded via `expand_message_xmd` with SHA-512 into 64 bytes, which are mapped to the
+    /// group via one-way map defined in [RFC 9496, Section 4.3.4](https://www.rfc-editor.org/rfc/rfc9496.html#section-4.3.4).
+    ///
+    /// `msgs` and `dsts` are concatenated into a single message and a single domain separation
+    /// tag respectively. Returns error if DST is empty.
+    pub fn hash_to_curve(msgs: &[&[u8]], dsts: &[&[u8]]) -> Result<Point, generic_ec_core::Error> {
+        if dsts.iter().all(|dst| dst.is_empty()) {
+            return Err(generic_ec_core::Error);
+        }
+        let mut uniform_bytes = [0u8; 64];
+        expand_message_xmd::<Sha512>(msgs, dsts, uniform_bytes.len(), &mut uniform_bytes);
+        Ok(map_to_group(&uniform_bytes))
+    }
+}
+
+impl generic_ec_core::HashToCurve for Ristretto255 {
+    fn hash_to_curve(msgs: &[&[u8]], dsts: &[&[u8]]) -> Result<Point, generic_ec_core::Error> {
+        Self::hash_to_curve(msgs, dsts)
+    }
+}
+
+/// One-way map from 64 uniform bytes to the group (RFC 9496, Section 4.3.4)
+fn map_to_group(uniform_bytes: &[u8; 64]) -> Point {
+    Point(curve25519::RistrettoPoint::from_uniform_bytes(
+        uniform_bytes,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Ristretto255;
+
+    /// Test vectors from RFC 9496, Appendix A.3
+    #[test]
+    fn map_to_group() {
+        let test_vectors = [
+            (
+                "5d1be09e3d0c82fc538112490e35701979d99e06ca3e2b5b54bffe8b4dc772c1\
+                 4d98b696a1bbfb5ca32c436cc61c16563790306c79eaca7705668b47dffe5bb6",
+                "3066f82a1a747d45120d1740f14358531a8f04bbffe6a819f86dfe50f44a0a46",
+            ),
+            (
+                "f116b34b8f17ceb56e8732a60d913dd10cce47a6d53bee9204be8b44f6678b27\
+                 0102a56902e2488c46120e9276cfe54638286b9e4b3cdb470b542d46c2068d38",
+                "f26e5b6f7d362d2d2a94c5d0e7602cb4773c95a2e5c31a64f133189fa76ed61b",
+            ),
+            (
+                "8422e1bbdaab52938b81fd602effb6f89110e1e57208ad12d9ad767e2e25510c\
+                 27140775f9337088b982d83d7fcf0b2fa1edffe51952cbe7365e95c86eaf325c",
+                "006ccd2a9e6867e6a2c5cea83d3302cc9de128dd2a9a57dd8ee7b9d7ffe02826",
+            ),
+        ];
+
+        for (input, expected) in test_vectors {
+            let input: [u8; 64] = hex::decode(input).unwrap().try_into().unwrap();
+            let point = super::map_to_group(&input);
+            assert_eq!(hex::encode(point.0.compress().as_bytes()), expected);
+        }
+    }
+
+    #[test]
+    fn empty_dst_is_rejected() {
+        assert!(Ristretto255::hash_to_curve(&[b"abc"], &[]).is_err());
+        assert!(Ristretto255::hash_to_curve(&[b"abc"], &[b""]).is_err());
+    }
+}
@@ -1,11 +1,15 @@
 use elliptic_curve::generic_array::GenericArray;
+use elliptic_curve::group::Group;
+use elliptic_curve::point::AffineCoordinates;
 use elliptic_curve::sec1::{
     CompressedPointSize, Coordinates, EncodedPoint, FromEncodedPoint, Tag, ToEncodedPoint,
 };
 use elliptic_curve::{CurveArithmetic, FieldBytesSize};
-use generic_ec_core::coords::{HasAffineX, HasAffineXAndParity, HasAffineXY, HasAffineY, Parity};
+use generic_ec_core::coords::{
+    HasAffineX, HasAffineXAndParity, HasAffineXY, HasAffineY, HasFieldModulus, Parity,
+};
 
-use super::{RustCryptoCurve, RustCryptoPoint};
+use super::{FieldModulus, RustCryptoCurve, RustCryptoPoint};
 
 impl<C, X> HasAffineX for RustCryptoCurve<C, X>
 where
@@ -59,6 +63,18 @@ where
         }
     }
 
+    fn y_parity(point: &Self::Point) -> Option<Parity> {
+        if bool::from(point.0.is_identity()) {
+            return None;
+        }
+        let y_is_odd = C::AffinePoint::from(point.0).y_is_odd();
+        Some(if bool::from(y_is_odd) {
+            Parity::Odd
+        } else {
+            Parity::Even
+        })
+    }
+
     fn from_x_and_parity(x: &Self::CoordinateArray, y_parity: Parity) -> Option<Self::Point> {
         let mut encoding = GenericArray::<u8, CompressedPointSize<C>>::default();
         let tag = match y_parity {
@@ -130,3 +146,16 @@ where
             .map(|point: C::AffinePoint| RustCryptoPoint(point.into()))
     }
 }
+
+impl<C, X> HasFieldModulus for RustCryptoCurve<C, X>
+where
+    C: CurveArithmetic + FieldModulus,
+    RustCryptoCurve<C, X>: generic_ec_core::Curve<
+        Point = RustCryptoPoint<C>,
+        CoordinateArray = elliptic_curve::FieldBytes<C>,
+    >,
+{
+    fn field_modulus() -> Self::CoordinateArray {
+        elliptic_curve::FieldBytes::<C>::clone_from_slice(C::FIELD_MODULUS)
+    }
+}
@@ -2,19 +2,45 @@
 pub trait CurveName {
     /// Name of the curve
     const CURVE_NAME: &'static str;
+    /// Hash to curve suite ID defined in RFC 9380
+    ///
+    /// See [`Curve::HASH_TO_CURVE_SUITE`](generic_ec_core::Curve::HASH_TO_CURVE_SUITE)
+    const HASH_TO_CURVE_SUITE: Option<&'static str> = None;
+    /// Cofactor of the curve
+    ///
+    /// See [`Curve::COFACTOR`](generic_ec_core::Curve::COFACTOR)
+    const COFACTOR: &'static [u8];
 }
 
 #[cfg(feature = "secp256r1")]
 impl CurveName for p256::NistP256 {
     const CURVE_NAME: &'static str = "secp256r1";
+    const HASH_TO_CURVE_SUITE: Option<&'static str> = Some("P256_XMD:SHA-256_SSWU_RO_");
+    const COFACTOR: &'static [u8] = &[1];
+}
+
+#[cfg(feature = "secp384r1")]
+impl CurveName for p384::NistP384 {
+    const CURVE_NAME: &'static str = "secp384r1";
+    const HASH_TO_CURVE_SUITE: Option<&'static str> = Some("P384_XMD:SHA-384_SSWU_RO_");
+    const COFACTOR: &'static [u8] = &[1];
+}
+
+#[cfg(feature = "sm2")]
+impl CurveName for sm2::Sm2 {
+    const CURVE_NAME: &'static str = "sm2";
+    const COFACTOR: &'static [u8] = &[1];
 }
 
 #[cfg(feature = "secp256k1")]
 impl CurveName for k256::Secp256k1 {
     const CURVE_NAME: &'static str = "secp256k1";
+    const HASH_TO_CURVE_SUITE: Option<&'static str> = Some("secp256k1_XMD:SHA-256_SSWU_RO_");
+    const COFACTOR: &'static [u8] = &[1];
 }
 
 #[cfg(feature = "stark")]
 impl CurveName for stark_curve::StarkCurve {
     const CURVE_NAME: &'static str = "stark";
+    const COFACTOR: &'static [u8] = &[1];
 }
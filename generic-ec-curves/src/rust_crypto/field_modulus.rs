@@ -0,0 +1,53 @@
+/// Modulus of the field the curve is defined over
+pub trait FieldModulus {
+    /// Field modulus $p$ (big-endian)
+    ///
+    /// Must be exactly as long as the curve's field bytes.
+    const FIELD_MODULUS: &'static [u8];
+}
+
+#[cfg(feature = "secp256r1")]
+impl FieldModulus for p256::NistP256 {
+    const FIELD_MODULUS: &'static [u8] = &[
+        0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff,
+    ];
+}
+
+#[cfg(feature = "secp384r1")]
+impl FieldModulus for p384::NistP384 {
+    const FIELD_MODULUS: &'static [u8] = &[
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xfe, 0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff,
+        0xff, 0xff, 0xff,
+    ];
+}
+
+#[cfg(feature = "sm2")]
+impl FieldModulus for sm2::Sm2 {
+    const FIELD_MODULUS: &'static [u8] = &[
+        0xff, 0xff, 0xff, 0xfe, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff,
+    ];
+}
+
+#[cfg(feature = "secp256k1")]
+impl FieldModulus for k256::Secp256k1 {
+    const FIELD_MODULUS: &'static [u8] = &[
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfe, 0xff, 0xff,
+        0xfc, 0x2f,
+    ];
+}
+
+#[cfg(feature = "stark")]
+impl FieldModulus for stark_curve::StarkCurve {
+    const FIELD_MODULUS: &'static [u8] = &[
+        0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x01,
+    ];
+}
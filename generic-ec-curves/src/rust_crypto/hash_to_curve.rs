@@ -1 +1,184 @@
+//! Hash to curve defined in [RFC 9380](https://www.rfc-editor.org/rfc/rfc9380.html)
 
+use elliptic_curve::group::cofactor::CofactorGroup;
+use elliptic_curve::hash2curve::{ExpandMsg, GroupDigest};
+use elliptic_curve::ProjectivePoint;
+
+use super::{RustCryptoCurve, RustCryptoPoint};
+
+impl<C, X> RustCryptoCurve<C, X>
+where
+    C: GroupDigest,
+    ProjectivePoint<C>: CofactorGroup,
+    X: for<'a> ExpandMsg<'a>,
+{
+    /// Hashes a message to the curve point
+    ///
+    /// Message is expanded via expander `X` the curve is parametrized with, e.g.
+    /// [`ExpandMsgXmd<Sha256>`](elliptic_curve::hash2curve::ExpandMsgXmd) for [`Secp256r1`](super::Secp256r1).
+    /// Use [`hash_to_curve_with_expander`](Self::hash_to_curve_with_expander) to choose another
+    /// expander.
+    ///
+    /// `msgs` and `dsts` are concatenated into a single message and a single domain separation
+    /// tag respectively. Returns error if DST is empty.
+    pub fn hash_to_curve(
+        msgs: &[&[u8]],
+        dsts: &[&[u8]],
+    ) -> elliptic_curve::Result<RustCryptoPoint<C>> {
+        Self::hash_to_curve_with_expander::<X>(msgs, dsts)
+    }
+}
+
+impl<C, X> RustCryptoCurve<C, X>
+where
+    C: GroupDigest,
+    ProjectivePoint<C>: CofactorGroup,
+{
+    /// Hashes a message to the curve point using expander `Y`
+    ///
+    /// Expander can be either XMD-based (e.g. [`ExpandMsgXmd<Sha256>`](elliptic_curve::hash2curve::ExpandMsgXmd)),
+    /// or XOF-based (e.g. [`ExpandMsgXof<Shake128>`](elliptic_curve::hash2curve::ExpandMsgXof)).
+    /// The rest of the procedure (hashing to field and mapping to the curve) is defined by the curve.
+    ///
+    /// `msgs` and `dsts` are concatenated into a single message and a single domain separation
+    /// tag respectively. Returns error if DST is empty.
+    pub fn hash_to_curve_with_expander<Y>(
+        msgs: &[&[u8]],
+        dsts: &[&[u8]],
+    ) -> elliptic_curve::Result<RustCryptoPoint<C>>
+    where
+        Y: for<'a> ExpandMsg<'a>,
+    {
+        C::hash_from_bytes::<Y>(msgs, dsts).map(RustCryptoPoint)
+    }
+}
+
+impl<C, X> generic_ec_core::HashToCurve for RustCryptoCurve<C, X>
+where
+    Self: generic_ec_core::Curve<Point = RustCryptoPoint<C>>,
+    C: GroupDigest,
+    ProjectivePoint<C>: CofactorGroup,
+    X: for<'a> ExpandMsg<'a>,
+{
+    fn hash_to_curve(
+        msgs: &[&[u8]],
+        dsts: &[&[u8]],
+    ) -> Result<RustCryptoPoint<C>, generic_ec_core::Error> {
+        Self::hash_to_curve_with_expander::<X>(msgs, dsts).map_err(|_| generic_ec_core::Error)
+    }
+}
+
+#[cfg(all(test, feature = "secp256r1"))]
+mod tests {
+    use elliptic_curve::hash2curve::{ExpandMsg, ExpandMsgXmd, ExpandMsgXof, Expander};
+    use elliptic_curve::sec1::ToEncodedPoint;
+    use sha2::Sha256;
+    use sha3::{Shake128, Shake256};
+
+    use crate::Secp256r1;
+
+    fn from_hex(hex: &str) -> [u8; 32] {
+        hex::FromHex::from_hex(hex).unwrap()
+    }
+
+    fn expand<X: for<'a> ExpandMsg<'a>>(msg: &[u8], dst: &[u8], len: usize) -> [u8; 32] {
+        let mut out = [0u8; 32];
+        X::expand_message(&[msg], &[dst], len)
+            .unwrap()
+            .fill_bytes(&mut out[..len]);
+        out
+    }
+
+    /// Test vectors from RFC 9380, Appendix K.5 and K.6
+    #[test]
+    fn expand_message_xof() {
+        let dst = b"QUUX-V01-CS02-with-expander-SHAKE128";
+        let test_vectors: &[(&[u8], &str)] = &[
+            (
+                b"",
+                "86518c9cd86581486e9485aa74ab35ba150d1c75c88e26b7043e44e2acd735a2",
+            ),
+            (
+                b"abc",
+                "8696af52a4d862417c0763556073f47bc9b9ba43c99b505305cb1ec04a9ab468",
+            ),
+        ];
+        for (msg, expected) in test_vectors {
+            assert_eq!(
+                expand::<ExpandMsgXof<Shake128>>(msg, dst, 32),
+                from_hex(expected)
+            );
+        }
+
+        let dst = b"QUUX-V01-CS02-with-expander-SHAKE256";
+        let test_vectors: &[(&[u8], &str)] = &[
+            (
+                b"",
+                "2ffc05c48ed32b95d72e807f6eab9f7530dd1c2f013914c8fed38c5ccc15ad76",
+            ),
+            (
+                b"abc",
+                "b39e493867e2767216792abce1f2676c197c0692aed061560ead251821808e07",
+            ),
+        ];
+        for (msg, expected) in test_vectors {
+            assert_eq!(
+                expand::<ExpandMsgXof<Shake256>>(msg, dst, 32),
+                from_hex(expected)
+            );
+        }
+    }
+
+    /// Test vectors from RFC 9380, Appendix J.1.1
+    #[test]
+    fn hash_to_curve_p256() {
+        let dst = b"QUUX-V01-CS02-with-P256_XMD:SHA-256_SSWU_RO_";
+        let test_vectors: &[(&[u8], &str, &str)] = &[
+            (
+                b"",
+                "2c15230b26dbc6fc9a37051158c95b79656e17a1a920b11394ca91c44247d3e4",
+                "8a7a74985cc5c776cdfe4b1f19884970453912e9d31528c060be9ab5c43e8415",
+            ),
+            (
+                b"abc",
+                "0bb8b87485551aa43ed54f009230450b492fead5f1cc91658775dac4a3388a0f",
+                "5c41b3d0731a27a7b14bc0bf0ccded2d8751f83493404c84a88e71ffd424212e",
+            ),
+        ];
+
+        for (msg, x, y) in test_vectors {
+            let point = Secp256r1::hash_to_curve(&[msg], &[dst]).unwrap();
+            let with_expander =
+                Secp256r1::hash_to_curve_with_expander::<ExpandMsgXmd<Sha256>>(&[msg], &[dst])
+                    .unwrap();
+            assert_eq!(point.0, with_expander.0);
+
+            let point = point.0.to_affine().to_encoded_point(false);
+            assert_eq!(point.x().unwrap().as_slice(), from_hex(x));
+            assert_eq!(point.y().unwrap().as_slice(), from_hex(y));
+        }
+    }
+
+    #[test]
+    fn hash_to_curve_with_xof_expander() {
+        let dst = b"QUUX-V01-CS02-with-P256_XOF:SHAKE-128_SSWU_RO_";
+        let hash = |msg: &[u8]| {
+            Secp256r1::hash_to_curve_with_expander::<ExpandMsgXof<Shake128>>(&[msg], &[dst])
+                .unwrap()
+                .0
+        };
+
+        assert_eq!(hash(b"abc"), hash(b"abc"));
+        assert_ne!(hash(b"abc"), hash(b"abd"));
+        assert_ne!(
+            hash(b"abc"),
+            Secp256r1::hash_to_curve(&[b"abc"], &[dst]).unwrap().0
+        );
+
+        // Empty DST is rejected
+        assert!(
+            Secp256r1::hash_to_curve_with_expander::<ExpandMsgXof<Shake128>>(&[b"abc"], &[])
+                .is_err()
+        );
+    }
+}
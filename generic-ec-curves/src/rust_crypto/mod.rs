@@ -19,11 +19,22 @@ use zeroize::{DefaultIsZeroes, Zeroize};
 
 #[cfg(any(feature = "secp256k1", feature = "secp256r1", feature = "stark"))]
 use sha2::Sha256;
-
-pub use self::{curve_name::CurveName, point::RustCryptoPoint, scalar::RustCryptoScalar};
+#[cfg(feature = "secp384r1")]
+use sha2::Sha384;
+
+pub use self::{
+    curve_name::CurveName, field_modulus::FieldModulus, point::RustCryptoPoint,
+    scalar::RustCryptoScalar,
+};
+pub use elliptic_curve;
+#[cfg(feature = "secp256k1")]
+pub use k256;
+#[cfg(feature = "secp256r1")]
+pub use p256;
 
 mod affine_coords;
 mod curve_name;
+mod field_modulus;
 mod hash_to_curve;
 mod point;
 mod scalar;
@@ -63,6 +74,18 @@ pub type Secp256k1 = RustCryptoCurve<k256::Secp256k1, ExpandMsgXmd<Sha256>>;
 /// Based on [p256] crate
 #[cfg(feature = "secp256r1")]
 pub type Secp256r1 = RustCryptoCurve<p256::NistP256, ExpandMsgXmd<Sha256>>;
+/// secp384r1 curve
+///
+/// Based on [p384] crate
+#[cfg(feature = "secp384r1")]
+pub type Secp384r1 = RustCryptoCurve<p384::NistP384, ExpandMsgXmd<Sha384>>;
+
+/// SM2 curve defined in Chinese national standard GB/T 32918
+///
+/// Based on [sm2] crate. Hash to curve is not standardized for SM2, so the curve is not
+/// parametrized with an expander.
+#[cfg(feature = "sm2")]
+pub type Sm2 = RustCryptoCurve<sm2::Sm2, ()>;
 
 /// Stark curve
 ///
@@ -92,6 +115,8 @@ where
     X: 'static,
 {
     const CURVE_NAME: &'static str = C::CURVE_NAME;
+    const HASH_TO_CURVE_SUITE: Option<&'static str> = C::HASH_TO_CURVE_SUITE;
+    const COFACTOR: &'static [u8] = C::COFACTOR;
 
     type Point = RustCryptoPoint<C>;
     type Scalar = RustCryptoScalar<C>;
@@ -158,23 +183,30 @@ impl<C, X> Default for RustCryptoCurve<C, X> {
 #[cfg(test)]
 mod tests {
     use generic_ec_core::{
-        coords::{HasAffineX, HasAffineXAndParity, HasAffineXY},
+        coords::{HasAffineX, HasAffineXAndParity, HasAffineXY, HasAffineY, HasFieldModulus},
         Curve,
     };
 
-    use super::{Secp256k1, Secp256r1, Stark};
+    use super::{Secp256k1, Secp256r1, Secp384r1, Sm2, Stark};
 
     /// Asserts that `E` implements `Curve`
     fn _impls_curve<E: Curve>() {}
-    fn _exposes_affine_coords<E: HasAffineX + HasAffineXAndParity + HasAffineXY>() {}
+    fn _exposes_affine_coords<
+        E: HasAffineX + HasAffineY + HasAffineXAndParity + HasAffineXY + HasFieldModulus,
+    >() {
+    }
 
     fn _curves_impl_trait() {
         _impls_curve::<Secp256k1>();
         _impls_curve::<Secp256r1>();
+        _impls_curve::<Secp384r1>();
+        _impls_curve::<Sm2>();
         _impls_curve::<Stark>();
 
         _exposes_affine_coords::<Secp256k1>();
         _exposes_affine_coords::<Secp256r1>();
+        _exposes_affine_coords::<Secp384r1>();
+        _exposes_affine_coords::<Sm2>();
         _exposes_affine_coords::<Stark>();
     }
 }
@@ -1,6 +1,7 @@
 use core::cmp;
 use core::hash::{self, Hash};
 
+use elliptic_curve::generic_array::typenum::Unsigned;
 use elliptic_curve::group::cofactor::CofactorGroup;
 use elliptic_curve::{
     sec1::{EncodedPoint, FromEncodedPoint, ModulusSize, ToEncodedPoint},
@@ -72,6 +73,27 @@ where
     }
 }
 
+/// Implements [`Cofactor`] for prime-order curves
+macro_rules! impl_cofactor_for_prime_order_curves {
+    ($($feature:literal => $curve:ty),+ $(,)?) => {$(
+        #[cfg(feature = $feature)]
+        impl Cofactor for RustCryptoPoint<$curve> {
+            #[inline]
+            fn mul_by_cofactor(&self) -> Self {
+                *self
+            }
+        }
+    )+};
+}
+
+impl_cofactor_for_prime_order_curves! {
+    "secp256k1" => k256::Secp256k1,
+    "secp256r1" => p256::NistP256,
+    "secp384r1" => p384::NistP384,
+    "sm2" => sm2::Sm2,
+    "stark" => stark_curve::StarkCurve,
+}
+
 impl<E> ConstantTimeEq for RustCryptoPoint<E>
 where
     E: CurveArithmetic,
@@ -112,14 +134,7 @@ where
 {
     type Bytes = elliptic_curve::sec1::CompressedPoint<E>;
     fn to_bytes_compressed(&self) -> Self::Bytes {
-        let point_encoded = E::AffinePoint::from(self.0).to_encoded_point(true);
-
-        let mut bytes = Self::Bytes::default();
-        if !bool::from(Self::is_zero(self)) {
-            bytes.copy_from_slice(point_encoded.as_bytes());
-        }
-
-        bytes
+        self.encode_affine(&E::AffinePoint::from(self.0), true)
     }
 }
 
@@ -131,15 +146,114 @@ where
 {
     type Bytes = elliptic_curve::sec1::UncompressedPoint<E>;
     fn to_bytes_uncompressed(&self) -> Self::Bytes {
-        let point_encoded = E::AffinePoint::from(self.0).to_encoded_point(false);
+        self.encode_affine(&E::AffinePoint::from(self.0), false)
+    }
+}
 
-        let mut bytes = Self::Bytes::default();
+/// Number of points normalized with a single field inversion in [`BatchEncoding`]
+#[cfg(any(feature = "secp256k1", feature = "sm2"))]
+const NORMALIZATION_BATCH: usize = 32;
+
+impl<E> RustCryptoPoint<E>
+where
+    E: CurveArithmetic,
+    E::AffinePoint: ToEncodedPoint<E> + From<E::ProjectivePoint>,
+    FieldBytesSize<E>: ModulusSize,
+{
+    /// Encodes the point given its affine representation
+    fn encode_affine<B: Default + AsMut<[u8]>>(
+        &self,
+        affine: &E::AffinePoint,
+        compress: bool,
+    ) -> B {
+        let point_encoded = affine.to_encoded_point(compress);
+
+        let mut bytes = B::default();
         if !bool::from(Self::is_zero(self)) {
-            bytes.copy_from_slice(point_encoded.as_bytes());
+            bytes.as_mut().copy_from_slice(point_encoded.as_bytes());
         }
 
         bytes
     }
+
+    /// Encodes points one by one, each conversion to affine coordinates takes a field inversion
+    #[cfg(any(feature = "secp256r1", feature = "secp384r1", feature = "stark"))]
+    fn encode_one_by_one<B: Default + AsMut<[u8]>>(points: &[Self], out: &mut [B], compress: bool) {
+        debug_assert_eq!(points.len(), out.len());
+        for (point, out) in points.iter().zip(out) {
+            *out = point.encode_affine(&E::AffinePoint::from(point.0), compress);
+        }
+    }
+
+    /// Encodes points converting them into affine coordinates in batches
+    ///
+    /// Points are processed in chunks of `NORMALIZATION_BATCH`, each chunk is normalized
+    /// with a single field inversion
+    #[cfg(any(feature = "secp256k1", feature = "sm2"))]
+    fn encode_batch_normalized<B: Default + AsMut<[u8]>>(
+        points: &[Self],
+        out: &mut [B],
+        compress: bool,
+    ) where
+        E::ProjectivePoint: elliptic_curve::point::BatchNormalize<
+            [E::ProjectivePoint; NORMALIZATION_BATCH],
+            Output = [E::AffinePoint; NORMALIZATION_BATCH],
+        >,
+    {
+        use elliptic_curve::point::BatchNormalize;
+
+        debug_assert_eq!(points.len(), out.len());
+        for (points, out) in points
+            .chunks(NORMALIZATION_BATCH)
+            .zip(out.chunks_mut(NORMALIZATION_BATCH))
+        {
+            // Last chunk may be shorter, it's padded with identity points
+            let mut projective = [E::ProjectivePoint::identity(); NORMALIZATION_BATCH];
+            projective
+                .iter_mut()
+                .zip(points)
+                .for_each(|(p, point)| *p = point.0);
+            let affine = E::ProjectivePoint::batch_normalize(&projective);
+
+            for ((point, affine), out) in points.iter().zip(&affine).zip(out) {
+                *out = point.encode_affine(affine, compress);
+            }
+        }
+    }
+}
+
+/// Implements [`BatchEncoding`] using specified encoding method
+macro_rules! impl_batch_encoding {
+    ($($feature:literal => $curve:ty => $method:ident),+ $(,)?) => {$(
+        #[cfg(feature = $feature)]
+        impl BatchEncoding for RustCryptoPoint<$curve> {
+            fn to_bytes_compressed_batch(
+                points: &[Self],
+                out: &mut [<Self as CompressedEncoding>::Bytes],
+            ) {
+                Self::$method(points, out, true)
+            }
+
+            fn to_bytes_uncompressed_batch(
+                points: &[Self],
+                out: &mut [<Self as UncompressedEncoding>::Bytes],
+            ) {
+                Self::$method(points, out, false)
+            }
+        }
+    )+};
+}
+
+// `k256` and `sm2` implement batch normalization. `p256` and `p384` are backed by `primeorder`
+// too, but their field elements don't implement `Invert` required by `primeorder` batch
+// normalization, and `stark-curve` doesn't provide it at all, so their points are encoded one
+// by one
+impl_batch_encoding! {
+    "secp256k1" => k256::Secp256k1 => encode_batch_normalized,
+    "secp256r1" => p256::NistP256 => encode_one_by_one,
+    "secp384r1" => p384::NistP384 => encode_one_by_one,
+    "sm2" => sm2::Sm2 => encode_batch_normalized,
+    "stark" => stark_curve::StarkCurve => encode_one_by_one,
 }
 
 impl<E> Decode for RustCryptoPoint<E>
@@ -151,6 +265,13 @@ where
     fn decode(mut bytes: &[u8]) -> Option<Self> {
         let all_zero = bytes.iter().all(|b| *b == 0);
         if all_zero {
+            // Identity point is encoded as all zeroes of the same length as compressed or
+            // uncompressed point (or as a single zero byte)
+            let compressed_len = <FieldBytesSize<E> as ModulusSize>::CompressedPointSize::USIZE;
+            let uncompressed_len = <FieldBytesSize<E> as ModulusSize>::UncompressedPointSize::USIZE;
+            if ![1, compressed_len, uncompressed_len].contains(&bytes.len()) {
+                return None;
+            }
             // This is the only representation of identity point recognized
             // by `elliptic-curve` library
             bytes = &[0]
@@ -207,10 +328,16 @@ where
 impl<E> Hash for RustCryptoPoint<E>
 where
     E: CurveArithmetic,
-    E::ProjectivePoint: Hash,
+    E::AffinePoint: ToEncodedPoint<E> + From<E::ProjectivePoint>,
+    FieldBytesSize<E>: ModulusSize,
 {
     fn hash<H: hash::Hasher>(&self, state: &mut H) {
-        self.0.hash(state)
+        // Projective representation of a point is not unique, so we hash
+        // its canonical compressed encoding instead
+        E::AffinePoint::from(self.0)
+            .to_encoded_point(true)
+            .as_bytes()
+            .hash(state)
     }
 }
 
@@ -1,10 +1,10 @@
 use core::ops::Mul;
 
-use elliptic_curve::bigint::{ArrayEncoding, ByteArray, U256, U512};
+use elliptic_curve::bigint::{ArrayEncoding, ByteArray, U256, U384, U512};
 use elliptic_curve::{Curve, CurveArithmetic, Field, Group, PrimeField, ScalarPrimitive};
 use generic_ec_core::{
     Additive, CurveGenerator, IntegerEncoding, Invertible, Multiplicative, One, Reduce, Samplable,
-    Zero,
+    Sqrt, UintConversion, Zero,
 };
 use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 use zeroize::DefaultIsZeroes;
@@ -12,6 +12,18 @@ use zeroize::DefaultIsZeroes;
 /// Scalar wrapper
 pub struct RustCryptoScalar<E: CurveArithmetic>(pub E::Scalar);
 
+impl<E: CurveArithmetic> UintConversion for RustCryptoScalar<E> {
+    type Uint = E::Uint;
+
+    fn to_uint(&self) -> Self::Uint {
+        self.0.into()
+    }
+
+    fn from_uint(uint: &Self::Uint) -> CtOption<Self> {
+        ScalarPrimitive::<E>::new(*uint).map(|s| Self(s.into()))
+    }
+}
+
 impl<E: CurveArithmetic> Additive for RustCryptoScalar<E> {
     fn add(a: &Self, b: &Self) -> Self {
         Self(a.0 + b.0)
@@ -64,6 +76,12 @@ impl<E: CurveArithmetic> Invertible for RustCryptoScalar<E> {
     }
 }
 
+impl<E: CurveArithmetic> Sqrt for RustCryptoScalar<E> {
+    fn sqrt(x: &Self) -> CtOption<Self> {
+        x.0.sqrt().map(Self)
+    }
+}
+
 impl<E: CurveArithmetic> Zero for RustCryptoScalar<E> {
     fn zero() -> Self {
         Self(E::Scalar::ZERO)
@@ -212,6 +230,22 @@ where
     }
 }
 
+impl<E: CurveArithmetic + Curve> Reduce<48> for RustCryptoScalar<E>
+where
+    E::Scalar: elliptic_curve::ops::Reduce<U384>,
+{
+    fn from_be_array_mod_order(bytes: &[u8; 48]) -> Self {
+        Self(elliptic_curve::ops::Reduce::<U384>::reduce(
+            U384::from_be_byte_array((*bytes).into()),
+        ))
+    }
+    fn from_le_array_mod_order(bytes: &[u8; 48]) -> Self {
+        Self(elliptic_curve::ops::Reduce::<U384>::reduce(
+            U384::from_le_byte_array((*bytes).into()),
+        ))
+    }
+}
+
 impl<E: CurveArithmetic + Curve> Reduce<64> for RustCryptoScalar<E>
 where
     E::Scalar: elliptic_curve::ops::Reduce<U512>,
@@ -247,22 +281,58 @@ impl BytesModOrder for RustCryptoScalar<k256::Secp256k1> {
 #[cfg(feature = "secp256r1")]
 impl BytesModOrder for RustCryptoScalar<p256::NistP256> {
     fn from_be_bytes_mod_order(bytes: &[u8]) -> Self {
-        crate::utils::scalar_from_be_bytes_mod_order_reducing_32(bytes, &Self(p256::Scalar::ONE))
+        crate::utils::scalar_from_be_bytes_mod_order_reducing::<_, 32>(
+            bytes,
+            &Self(p256::Scalar::ONE),
+        )
     }
     fn from_le_bytes_mod_order(bytes: &[u8]) -> Self {
-        crate::utils::scalar_from_le_bytes_mod_order_reducing_32(bytes, &Self(p256::Scalar::ONE))
+        crate::utils::scalar_from_le_bytes_mod_order_reducing::<_, 32>(
+            bytes,
+            &Self(p256::Scalar::ONE),
+        )
+    }
+}
+#[cfg(feature = "secp384r1")]
+impl BytesModOrder for RustCryptoScalar<p384::NistP384> {
+    fn from_be_bytes_mod_order(bytes: &[u8]) -> Self {
+        crate::utils::scalar_from_be_bytes_mod_order_reducing::<_, 48>(
+            bytes,
+            &Self(p384::Scalar::ONE),
+        )
+    }
+    fn from_le_bytes_mod_order(bytes: &[u8]) -> Self {
+        crate::utils::scalar_from_le_bytes_mod_order_reducing::<_, 48>(
+            bytes,
+            &Self(p384::Scalar::ONE),
+        )
+    }
+}
+#[cfg(feature = "sm2")]
+impl BytesModOrder for RustCryptoScalar<sm2::Sm2> {
+    fn from_be_bytes_mod_order(bytes: &[u8]) -> Self {
+        crate::utils::scalar_from_be_bytes_mod_order_reducing::<_, 32>(
+            bytes,
+            &Self(sm2::Scalar::ONE),
+        )
+    }
+    fn from_le_bytes_mod_order(bytes: &[u8]) -> Self {
+        crate::utils::scalar_from_le_bytes_mod_order_reducing::<_, 32>(
+            bytes,
+            &Self(sm2::Scalar::ONE),
+        )
     }
 }
 #[cfg(feature = "stark")]
 impl BytesModOrder for RustCryptoScalar<stark_curve::StarkCurve> {
     fn from_be_bytes_mod_order(bytes: &[u8]) -> Self {
-        crate::utils::scalar_from_be_bytes_mod_order_reducing_32(
+        crate::utils::scalar_from_be_bytes_mod_order_reducing::<_, 32>(
             bytes,
             &Self(stark_curve::Scalar::ONE),
         )
     }
     fn from_le_bytes_mod_order(bytes: &[u8]) -> Self {
-        crate::utils::scalar_from_le_bytes_mod_order_reducing_32(
+        crate::utils::scalar_from_le_bytes_mod_order_reducing::<_, 32>(
             bytes,
             &Self(stark_curve::Scalar::ONE),
         )
@@ -1,6 +1,7 @@
 use std::iter;
 
-use generic_ec::{Curve, Scalar};
+use generic_ec::{Curve, Point, Scalar};
+use generic_ec_zkp::polynomial::Polynomial;
 use rand::Rng;
 
 criterion::criterion_main!(benches);
@@ -53,4 +54,35 @@ fn bench_zkp_on_curve<E: Curve>(
             },
         );
     }
+
+    for degree in [10, 50] {
+        c.bench_function(
+            &format!("{curve_name}/polynomial_value/horner/{degree}"),
+            |b| {
+                b.iter_batched(
+                    || random_committed_polynomial::<E>(rng, degree),
+                    |(f, x)| f.value::<_, Point<E>>(&x),
+                    criterion::BatchSize::SmallInput,
+                )
+            },
+        );
+        c.bench_function(
+            &format!("{curve_name}/polynomial_value/multiscalar/{degree}"),
+            |b| {
+                b.iter_batched(
+                    || random_committed_polynomial::<E>(rng, degree),
+                    |(f, x)| f.value_multiscalar(&x),
+                    criterion::BatchSize::SmallInput,
+                )
+            },
+        );
+    }
+}
+
+fn random_committed_polynomial<E: Curve>(
+    rng: &mut rand_dev::DevRng,
+    degree: usize,
+) -> (Polynomial<Point<E>>, Scalar<E>) {
+    let f = Polynomial::<Scalar<E>>::sample(rng, degree);
+    (&f * &Point::generator(), Scalar::random(rng))
 }
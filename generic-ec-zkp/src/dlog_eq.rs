@@ -0,0 +1,202 @@
+//! Non-interactive proof of discrete logarithms equality (Chaum-Pedersen proof)
+//!
+//! Proof lets prover $\P$ convince verifier $\V$ that it knows secret $x$ such as
+//! $A = x \cdot G$ and $B = x \cdot H$, i.e. that $\log_G A = \log_H B$.
+//!
+//! Generators $G$ and $H$ are required to be non-zero: with $H = \O$, any $B = \O$
+//! would trivially satisfy the relation.
+//!
+//! ## Example
+//! ```rust
+//! use generic_ec::{Curve, NonZero, Point, SecretScalar};
+//! use generic_ec_zkp::dlog_eq;
+//! use rand::rngs::OsRng;
+//! use sha2::Sha256;
+//!
+//! # fn doc_fn<E: Curve>(g: NonZero<Point<E>>, h: NonZero<Point<E>>) -> Result<(), dlog_eq::InvalidProof> {
+//! // Prover knows `x`
+//! let x = SecretScalar::<E>::random(&mut OsRng);
+//! let a = g * &x;
+//! let b = h * &x;
+//! let proof = dlog_eq::prove::<E, Sha256>(&mut OsRng, &g, &h, x.as_ref());
+//!
+//! // Verifier knows `g`, `h`, `a`, `b`, and receives the proof
+//! proof.verify::<Sha256>(&g, &h, &a, &b)?;
+//! # Ok(()) }
+//! ```
+//!
+//! ## Algorithm
+//!
+//! * Prove
+//!   1. $\P$ samples $k \gets \Z_q$, computes $R_1 = k \cdot G$, $R_2 = k \cdot H$
+//!   2. $\P$ computes challenge $e = \mathcal{H}(G, H, A, B, R_1, R_2)$
+//!   3. $\P$ computes $z = k + ex$, proof is $(e, z)$
+//! * Verification \
+//!   $\V$ computes $R_1 = z \cdot G - e \cdot A$, $R_2 = z \cdot H - e \cdot B$, and checks
+//!   that $e \\? \mathcal{H}(G, H, A, B, R_1, R_2)$
+//!
+//! Challenge is derived via [`Scalar::from_hash`]: $G, H, A, B, R_1, R_2$ are encoded
+//! unambiguously via [`udigest`], and the scalar is obtained without modulo bias from
+//! hash function `D`.
+
+use digest::Digest;
+use generic_ec::{Curve, NonZero, Point, Scalar, SecretScalar};
+use rand_core::{CryptoRng, RngCore};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The proof that can convince $\V$ that $\log_G A = \log_H B$
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(bound = ""))]
+#[cfg_attr(feature = "udigest", derive(udigest::Digestable), udigest(bound = ""))]
+pub struct DlogEqProof<E: Curve> {
+    /// Challenge $e$
+    pub challenge: Scalar<E>,
+    /// Response $z$
+    pub response: Scalar<E>,
+}
+
+/// Proves that $\log_G A = \log_H B$ where $A = x \cdot G$ and $B = x \cdot H$
+///
+/// Challenge is derived via hash function `D`.
+pub fn prove<E: Curve, D: Digest>(
+    rng: &mut (impl RngCore + CryptoRng),
+    g: &NonZero<Point<E>>,
+    h: &NonZero<Point<E>>,
+    x: &Scalar<E>,
+) -> DlogEqProof<E> {
+    let a = g * x;
+    let b = h * x;
+
+    let k = SecretScalar::<E>::random(rng);
+    let r1 = g * &k;
+    let r2 = h * &k;
+
+    let challenge = derive_challenge::<E, D>(g, h, &a, &b, &r1, &r2);
+    let response = &k + challenge * x;
+
+    DlogEqProof {
+        challenge,
+        response,
+    }
+}
+
+impl<E: Curve> DlogEqProof<E> {
+    /// Verifies that $\log_G A = \log_H B$
+    ///
+    /// `D` must be the same hash function that was used to generate the proof.
+    pub fn verify<D: Digest>(
+        &self,
+        g: &NonZero<Point<E>>,
+        h: &NonZero<Point<E>>,
+        a: &Point<E>,
+        b: &Point<E>,
+    ) -> Result<(), InvalidProof> {
+        let r1 = g * self.response - a * self.challenge;
+        let r2 = h * self.response - b * self.challenge;
+
+        let challenge = derive_challenge::<E, D>(g, h, a, b, &r1, &r2);
+        if challenge == self.challenge {
+            Ok(())
+        } else {
+            Err(InvalidProof)
+        }
+    }
+}
+
+/// Data hashed to derive the challenge in Fiat-Shamir transform
+#[derive(udigest::Digestable)]
+#[udigest(tag = "generic-ec-zkp.dlog_eq")]
+#[udigest(bound = "")]
+struct Challenge<'a, E: Curve> {
+    g: &'a Point<E>,
+    h: &'a Point<E>,
+    a: &'a Point<E>,
+    b: &'a Point<E>,
+    r1: &'a Point<E>,
+    r2: &'a Point<E>,
+}
+
+fn derive_challenge<E: Curve, D: Digest>(
+    g: &Point<E>,
+    h: &Point<E>,
+    a: &Point<E>,
+    b: &Point<E>,
+    r1: &Point<E>,
+    r2: &Point<E>,
+) -> Scalar<E> {
+    Scalar::from_hash::<D>(&Challenge { g, h, a, b, r1, r2 })
+}
+
+/// Invalid proof error
+#[derive(Debug, Clone, Copy)]
+pub struct InvalidProof;
+
+impl core::fmt::Display for InvalidProof {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("invalid dlog equality proof")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidProof {}
+
+#[cfg(test)]
+#[generic_tests::define]
+mod tests {
+    use generic_ec::{Curve, NonZero, Point, Scalar};
+    use rand_dev::DevRng;
+    use sha2::{Sha256, Sha512};
+
+    fn random_generator<E: Curve>(rng: &mut DevRng) -> NonZero<Point<E>> {
+        Point::generator() * NonZero::<Scalar<E>>::random(rng)
+    }
+
+    #[test]
+    fn valid_proof_is_accepted<E: Curve>() {
+        let mut rng = DevRng::new();
+        let g = random_generator::<E>(&mut rng);
+        let h = random_generator::<E>(&mut rng);
+
+        let x = Scalar::<E>::random(&mut rng);
+        let (a, b) = (g * x, h * x);
+
+        let proof = super::prove::<E, Sha256>(&mut rng, &g, &h, &x);
+        proof.verify::<Sha256>(&g, &h, &a, &b).unwrap();
+
+        let proof = super::prove::<E, Sha512>(&mut rng, &g, &h, &x);
+        proof.verify::<Sha512>(&g, &h, &a, &b).unwrap();
+        proof.verify::<Sha256>(&g, &h, &a, &b).unwrap_err();
+    }
+
+    #[test]
+    fn different_witnesses_are_rejected<E: Curve>() {
+        let mut rng = DevRng::new();
+        let g = random_generator::<E>(&mut rng);
+        let h = random_generator::<E>(&mut rng);
+
+        let x = Scalar::<E>::random(&mut rng);
+        let y = Scalar::<E>::random(&mut rng);
+        let (a, b) = (g * x, h * y);
+
+        let proof = super::prove::<E, Sha256>(&mut rng, &g, &h, &x);
+        proof.verify::<Sha256>(&g, &h, &a, &b).unwrap_err();
+        let proof = super::prove::<E, Sha256>(&mut rng, &g, &h, &y);
+        proof.verify::<Sha256>(&g, &h, &a, &b).unwrap_err();
+
+        // Proof doesn't verify with swapped generators
+        let b = h * x;
+        let proof = super::prove::<E, Sha256>(&mut rng, &g, &h, &x);
+        proof.verify::<Sha256>(&h, &g, &b, &a).unwrap_err();
+    }
+
+    #[instantiate_tests(<generic_ec::curves::Secp256k1>)]
+    mod secp256k1 {}
+    #[instantiate_tests(<generic_ec::curves::Secp256r1>)]
+    mod secp256r1 {}
+    #[instantiate_tests(<generic_ec::curves::Stark>)]
+    mod stark {}
+    #[instantiate_tests(<generic_ec::curves::Ed25519>)]
+    mod ed25519 {}
+}
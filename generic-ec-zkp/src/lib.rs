@@ -13,5 +13,12 @@ extern crate alloc;
 // We don't want this dependency to trigger unused dep lint
 use generic_array as _;
 
+#[cfg(feature = "hash-to-scalar")]
+#[cfg_attr(docsrs, doc(cfg(feature = "hash-to-scalar")))]
+pub mod dlog_eq;
+pub mod pedersen;
 pub mod polynomial;
 pub mod schnorr_pok;
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub mod secret_sharing;
@@ -0,0 +1,149 @@
+//! Pedersen commitment
+//!
+//! Pedersen commitment lets committer commit to a value $m$ by publishing
+//! $C = m \cdot G + r \cdot H$, where $r \gets \Z_q$ is a random blinding factor, and
+//! $H$ is a second generator. Later, committer can open the commitment by revealing
+//! $(m, r)$.
+//!
+//! The commitment is perfectly hiding and computationally binding: nobody can open it
+//! to a different value unless they know discrete logarithm of $H$ base $G$. For that reason,
+//! $H$ must be generated in a way that nobody knows its discrete logarithm (e.g. derived
+//! from hash). Using $H = h \cdot G$ for a known $h$ breaks the binding property.
+//!
+//! Commitments are additively homomorphic:
+//!
+//! $$\text{commit}(a, r) + \text{commit}(b, s) = \text{commit}(a + b, r + s)$$
+//!
+//! ## Example
+//! ```rust
+//! use generic_ec::{Curve, NonZero, Point, Scalar};
+//! use generic_ec_zkp::pedersen;
+//! use rand::rngs::OsRng;
+//!
+//! # fn doc_fn<E: Curve>(h: NonZero<Point<E>>) -> Result<(), pedersen::InvalidOpening> {
+//! // Committer commits to value `m`
+//! let m = Scalar::<E>::from(42);
+//! let r = Scalar::random(&mut OsRng);
+//! let commitment = pedersen::commit(&m, &r, &h);
+//!
+//! // ... later, committer reveals `(m, r)`, and verifier checks the opening
+//! commitment.verify(&m, &r, &h)?;
+//! # Ok(()) }
+//! ```
+
+use generic_ec::{Curve, NonZero, Point, Scalar};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Pedersen commitment $C = m \cdot G + r \cdot H$
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(bound = ""))]
+#[cfg_attr(feature = "udigest", derive(udigest::Digestable), udigest(bound = ""))]
+pub struct Commitment<E: Curve>(pub Point<E>);
+
+/// Commits to `value` using `blinding` factor
+///
+/// Returns $C = \text{value} \cdot G + \text{blinding} \cdot H$. Computation is constant time,
+/// so `value` and `blinding` may be secret.
+pub fn commit<E: Curve>(
+    value: &Scalar<E>,
+    blinding: &Scalar<E>,
+    h: &NonZero<Point<E>>,
+) -> Commitment<E> {
+    Commitment(Point::generator() * value + blinding * h)
+}
+
+impl<E: Curve> Commitment<E> {
+    /// Verifies that commitment opens to `value` with `blinding` factor
+    ///
+    /// Opening is public at the moment of verification, so it's computed via (not constant time)
+    /// [multiscalar multiplication](Scalar::multiscalar_mul).
+    pub fn verify(
+        &self,
+        value: &Scalar<E>,
+        blinding: &Scalar<E>,
+        h: &NonZero<Point<E>>,
+    ) -> Result<(), InvalidOpening> {
+        let g = Point::generator().to_point();
+        let expected = Scalar::multiscalar_mul([(value, &g), (blinding, h.as_ref())].into_iter());
+        if self.0.ct_eq(&expected).into() {
+            Ok(())
+        } else {
+            Err(InvalidOpening)
+        }
+    }
+}
+
+impl<E: Curve> core::ops::Add for Commitment<E> {
+    type Output = Commitment<E>;
+
+    /// Homomorphically adds two commitments
+    ///
+    /// Resulting commitment opens to the sum of values with the sum of blinding factors.
+    fn add(self, rhs: Self) -> Self::Output {
+        Commitment(self.0 + rhs.0)
+    }
+}
+
+/// Commitment doesn't open to given value and blinding factor
+#[derive(Debug, Clone, Copy)]
+pub struct InvalidOpening;
+
+impl core::fmt::Display for InvalidOpening {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("invalid Pedersen commitment opening")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidOpening {}
+
+#[cfg(test)]
+#[generic_tests::define]
+mod tests {
+    use generic_ec::{Curve, NonZero, Point, Scalar};
+    use rand_dev::DevRng;
+
+    use super::commit;
+
+    fn random_h<E: Curve>(rng: &mut DevRng) -> NonZero<Point<E>> {
+        Point::generator() * NonZero::<Scalar<E>>::random(rng)
+    }
+
+    #[test]
+    fn commitment_opens<E: Curve>() {
+        let mut rng = DevRng::new();
+        let h = random_h::<E>(&mut rng);
+
+        let m = Scalar::<E>::random(&mut rng);
+        let r = Scalar::<E>::random(&mut rng);
+        let c = commit(&m, &r, &h);
+
+        c.verify(&m, &r, &h).unwrap();
+        c.verify(&(m + Scalar::one()), &r, &h).unwrap_err();
+        c.verify(&m, &(r + Scalar::one()), &h).unwrap_err();
+    }
+
+    #[test]
+    fn commitment_is_additive<E: Curve>() {
+        let mut rng = DevRng::new();
+        let h = random_h::<E>(&mut rng);
+
+        let (a, r) = (Scalar::<E>::random(&mut rng), Scalar::random(&mut rng));
+        let (b, s) = (Scalar::<E>::random(&mut rng), Scalar::random(&mut rng));
+
+        let sum = commit(&a, &r, &h) + commit(&b, &s, &h);
+        assert_eq!(sum, commit(&(a + b), &(r + s), &h));
+        sum.verify(&(a + b), &(r + s), &h).unwrap();
+    }
+
+    #[instantiate_tests(<generic_ec::curves::Secp256k1>)]
+    mod secp256k1 {}
+    #[instantiate_tests(<generic_ec::curves::Secp256r1>)]
+    mod secp256r1 {}
+    #[instantiate_tests(<generic_ec::curves::Stark>)]
+    mod stark {}
+    #[instantiate_tests(<generic_ec::curves::Ed25519>)]
+    mod ed25519 {}
+}
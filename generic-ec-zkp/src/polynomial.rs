@@ -10,7 +10,10 @@ mod requires_alloc {
     use alloc::{vec, vec::Vec};
     use core::{iter, ops};
 
-    use generic_ec::traits::{IsZero, Samplable, Zero};
+    use generic_ec::{
+        traits::{IsZero, Samplable, Zero},
+        Curve, NonZero, Point, Scalar,
+    };
     use rand_core::RngCore;
 
     /// Polynomial $f(x) = \sum_i a_i x^i$ defined as a list of coefficients $[a_0, \dots, a_{\text{degree}}]$
@@ -174,6 +177,151 @@ mod requires_alloc {
         }
     }
 
+    impl<E: Curve> Polynomial<Scalar<E>> {
+        /// Divides polynomial $f(x)$ by $(x - a)$
+        ///
+        /// Returns quotient $q(x)$ and remainder $r$ such that $f(x) = q(x) \cdot (x - a) + r$.
+        /// Remainder is equal to $f(a)$, so $q(x) = \frac{f(x) - f(a)}{x - a}$, which is typically used as
+        /// a witness polynomial in KZG-style commitments and VSS proofs.
+        ///
+        /// Division is done via synthetic division (Horner's method) in $O(\\text{degree})$.
+        ///
+        /// ## Example
+        /// ```rust
+        /// use generic_ec::{Scalar, curves::Secp256k1};
+        /// use generic_ec_zkp::polynomial::Polynomial;
+        /// # use rand_core::OsRng;
+        ///
+        /// let f = Polynomial::<Scalar<Secp256k1>>::sample(&mut OsRng, 5);
+        /// let a = Scalar::random(&mut OsRng);
+        /// let (q, r) = f.divide_by_linear(&a);
+        /// assert_eq!(r, f.value::<_, Scalar<_>>(&a));
+        /// assert_eq!(q.degree(), 4);
+        ///
+        /// let x = Scalar::random(&mut OsRng);
+        /// assert_eq!(
+        ///     q.value::<_, Scalar<_>>(&x) * (x - a) + r,
+        ///     f.value::<_, Scalar<_>>(&x),
+        /// );
+        /// ```
+        pub fn divide_by_linear(&self, a: &Scalar<E>) -> (Polynomial<Scalar<E>>, Scalar<E>) {
+            let mut quotient = vec![Scalar::zero(); self.coefs.len().saturating_sub(1)];
+            let mut acc = Scalar::zero();
+            for (i, coef_i) in self.coefs.iter().enumerate().rev() {
+                acc = acc * a + coef_i;
+                if i > 0 {
+                    quotient[i - 1] = acc;
+                }
+            }
+            (Polynomial::from_coefs(quotient), acc)
+        }
+
+        /// Evaluates polynomial at point $x$ and multiplies $P$ at the result, returns $f(x) \cdot P$
+        ///
+        /// Same as `point * f.value(x)`. Typically used when opening polynomial commitments,
+        /// where $P$ is fixed and the polynomial is public.
+        ///
+        /// ## Example
+        /// ```rust
+        /// use generic_ec::{Point, Scalar, curves::Secp256k1};
+        /// use generic_ec_zkp::polynomial::Polynomial;
+        /// # use rand_core::OsRng;
+        ///
+        /// let f = Polynomial::<Scalar<Secp256k1>>::sample(&mut OsRng, 3);
+        /// let p = Point::generator() * Scalar::random(&mut OsRng);
+        /// let x = Scalar::random(&mut OsRng);
+        /// assert_eq!(f.mul_value(&x, &p), p * f.value::<_, Scalar<_>>(&x));
+        /// ```
+        pub fn mul_value(&self, x: &Scalar<E>, point: &Point<E>) -> Point<E> {
+            point * self.value::<_, Scalar<E>>(x)
+        }
+    }
+
+    impl<E: Curve> Polynomial<Point<E>> {
+        /// Evaluates polynomial at point $x$ via multiscalar multiplication
+        ///
+        /// Returns the same result as [`value`](Self::value), but instead of Horner's method which
+        /// does one point multiplication per coefficient, it precomputes powers of $x$ and computes
+        /// $\sum_i x^i \cdot a_i$ via [`Scalar::multiscalar_mul`]. It's much faster for polynomials of
+        /// high degree, e.g. when evaluating a commitment to polynomial in VSS.
+        ///
+        /// Multiscalar multiplication is not constant time, so $x$ must be public.
+        ///
+        /// ## Example
+        /// ```rust
+        /// use generic_ec::{Point, Scalar, curves::Secp256k1};
+        /// use generic_ec_zkp::polynomial::Polynomial;
+        /// # use rand_core::OsRng;
+        ///
+        /// let f = Polynomial::<Scalar<Secp256k1>>::sample(&mut OsRng, 10);
+        /// let F = &f * &Point::generator();
+        ///
+        /// let x = Scalar::random(&mut OsRng);
+        /// assert_eq!(F.value_multiscalar(&x), F.value::<_, Point<_>>(&x));
+        /// ```
+        pub fn value_multiscalar(&self, point: &Scalar<E>) -> Point<E> {
+            let powers = iter::successors(Some(Scalar::<E>::one()), |x_i| Some(x_i * point))
+                .take(self.coefs.len())
+                .collect::<Vec<_>>();
+            Scalar::multiscalar_mul(powers.iter().zip(&self.coefs))
+        }
+    }
+
+    /// Interpolates a polynomial $f(x)$ such that $f(\text{xs}_i) = \text{ys}_i$
+    ///
+    /// Returns polynomial of degree at most $\text{len}(xs) - 1$ which coefficients are
+    /// reconstructed via Lagrange basis in $O(\text{len}(xs)^2)$.
+    ///
+    /// Returns `None` if `xs` and `ys` have different lengths or if elements of `xs` are not pairwise
+    /// distinct.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use generic_ec::{Scalar, NonZero, curves::Secp256k1};
+    /// use generic_ec_zkp::polynomial::{Polynomial, interpolate};
+    /// # use rand_core::OsRng;
+    ///
+    /// let f = Polynomial::<Scalar<Secp256k1>>::sample(&mut OsRng, 2);
+    ///
+    /// let xs = [1, 2, 3].map(|i| NonZero::from_scalar(Scalar::from(i)).unwrap());
+    /// let ys = xs.map(|x| f.value::<_, Scalar<_>>(x.as_ref()));
+    ///
+    /// let interpolated = interpolate(&xs, &ys).unwrap();
+    /// assert_eq!(interpolated.coefs(), f.coefs());
+    /// ```
+    pub fn interpolate<E: Curve>(
+        xs: &[NonZero<Scalar<E>>],
+        ys: &[Scalar<E>],
+    ) -> Option<Polynomial<Scalar<E>>> {
+        if xs.len() != ys.len() {
+            return None;
+        }
+
+        // N(x) = \prod_m (x - xs_m)
+        let mut n = vec![Scalar::<E>::zero(); xs.len() + 1];
+        n[0] = Scalar::one();
+        for (m, x_m) in xs.iter().enumerate() {
+            for i in (0..=m + 1).rev() {
+                let prev = if i > 0 { n[i - 1] } else { Scalar::zero() };
+                n[i] = prev - x_m * n[i];
+            }
+        }
+        let n = Polynomial { coefs: n };
+
+        let mut coefs = vec![Scalar::zero(); xs.len()];
+        for (x_j, y_j) in xs.iter().zip(ys) {
+            // L_j(x) = N(x) / (x - xs_j) / \prod_{m != j} (xs_j - xs_m)
+            let (q_j, _) = n.divide_by_linear(x_j.as_ref());
+            let denom = q_j.value::<_, Scalar<E>>(x_j.as_ref());
+            let scale = y_j * denom.invert()?;
+            coefs
+                .iter_mut()
+                .zip(q_j.coefs())
+                .for_each(|(coef_i, q_ji)| *coef_i += scale * q_ji);
+        }
+        Some(Polynomial::from_coefs(coefs))
+    }
+
     /// Multiplies polyinomial $F(x)$ at $k$ returning resulting polyinomial
     /// $F'(x) = k \cdot F(x)$ without allocations
     ///
@@ -209,6 +357,46 @@ mod requires_alloc {
         }
     }
 
+    /// Multiplies two polynomials $f(x)$ and $g(x)$, returns $h(x) = f(x) \cdot g(x)$
+    ///
+    /// Product is computed via schoolbook multiplication in $O(deg(f) \cdot deg(g))$. Degree
+    /// of resulting polynomial is $deg(f) + deg(g)$. If any of the polynomials is zero, the
+    /// product is zero.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use generic_ec::{Scalar, curves::Secp256k1};
+    /// use generic_ec_zkp::polynomial::Polynomial;
+    /// # use rand_core::OsRng;
+    ///
+    /// let f = Polynomial::<Scalar<Secp256k1>>::sample(&mut OsRng, 3);
+    /// let g = Polynomial::<Scalar<Secp256k1>>::sample(&mut OsRng, 2);
+    /// let h = &f * &g;
+    /// assert_eq!(h.degree(), 5);
+    ///
+    /// let x = Scalar::random(&mut OsRng);
+    /// assert_eq!(
+    ///     h.value::<_, Scalar<_>>(&x),
+    ///     f.value::<_, Scalar<_>>(&x) * g.value::<_, Scalar<_>>(&x),
+    /// );
+    /// ```
+    impl<E: Curve> ops::Mul<&Polynomial<Scalar<E>>> for &Polynomial<Scalar<E>> {
+        type Output = Polynomial<Scalar<E>>;
+
+        fn mul(self, rhs: &Polynomial<Scalar<E>>) -> Self::Output {
+            if self.coefs.is_empty() || rhs.coefs.is_empty() {
+                return Polynomial { coefs: vec![] };
+            }
+            let mut coefs = vec![Scalar::zero(); self.coefs.len() + rhs.coefs.len() - 1];
+            for (i, f_i) in self.coefs.iter().enumerate() {
+                for (j, g_j) in rhs.coefs.iter().enumerate() {
+                    coefs[i + j] += f_i * g_j;
+                }
+            }
+            Polynomial::from_coefs(coefs)
+        }
+    }
+
     impl<C> ops::AddAssign<&Polynomial<C>> for Polynomial<C>
     where
         C: Clone + for<'a> ops::AddAssign<&'a C>,
@@ -391,7 +579,7 @@ mod tests {
     use alloc::vec::Vec;
     use core::iter;
 
-    use generic_ec::{Curve, Point, Scalar, SecretScalar};
+    use generic_ec::{Curve, NonZero, Point, Scalar, SecretScalar};
     use rand::Rng;
     use rand_dev::DevRng;
 
@@ -483,6 +671,123 @@ mod tests {
         assert_eq!(value_expected, value_actual2);
     }
 
+    #[test]
+    fn polynomial_divide_by_linear<E: Curve>() {
+        let mut rng = DevRng::new();
+
+        for degree in [0, 1, 2, 10] {
+            let f = Polynomial::<Scalar<E>>::sample(&mut rng, degree);
+            let a = Scalar::random(&mut rng);
+
+            let (q, r) = f.divide_by_linear(&a);
+            assert_eq!(r, f.value::<_, Scalar<E>>(&a));
+            assert_eq!(q.degree(), degree.saturating_sub(1));
+
+            for _ in 0..10 {
+                let x = Scalar::random(&mut rng);
+                assert_eq!(
+                    q.value::<_, Scalar<E>>(&x) * (x - a) + r,
+                    f.value::<_, Scalar<E>>(&x)
+                );
+            }
+        }
+
+        let zero = Polynomial::<Scalar<E>>::from_coefs(Vec::new());
+        let (q, r) = zero.divide_by_linear(&Scalar::random(&mut rng));
+        assert!(q.coefs().is_empty());
+        assert_eq!(r, Scalar::zero());
+    }
+
+    #[test]
+    fn polynomial_mul_value<E: Curve>() {
+        let mut rng = DevRng::new();
+
+        let point = Point::generator() * Scalar::<E>::random(&mut rng);
+        for degree in [0, 1, 5] {
+            let f = Polynomial::<Scalar<E>>::sample(&mut rng, degree);
+            let x = Scalar::random(&mut rng);
+            assert_eq!(f.mul_value(&x, &point), point * f.value::<_, Scalar<E>>(&x));
+        }
+
+        let zero = Polynomial::<Scalar<E>>::from_coefs(Vec::new());
+        assert!(zero.mul_value(&Scalar::random(&mut rng), &point).is_zero());
+    }
+
+    #[test]
+    fn polynomial_value_multiscalar<E: Curve>() {
+        let mut rng = DevRng::new();
+
+        for degree in [0, 1, 5, 50] {
+            let f = Polynomial::<Scalar<E>>::sample(&mut rng, degree);
+            let F = &f * &Point::generator();
+            for _ in 0..5 {
+                let x = Scalar::random(&mut rng);
+                assert_eq!(F.value_multiscalar(&x), F.value::<_, Point<E>>(&x));
+            }
+            assert_eq!(F.value_multiscalar(&Scalar::zero()), F.coefs()[0]);
+        }
+
+        let zero = Polynomial::<Point<E>>::from_coefs(Vec::new());
+        assert!(zero.value_multiscalar(&Scalar::random(&mut rng)).is_zero());
+    }
+
+    #[test]
+    fn polynomial_mul<E: Curve>() {
+        let mut rng = DevRng::new();
+
+        for (f_degree, g_degree) in [(0, 0), (0, 3), (1, 1), (3, 5), (10, 7)] {
+            let f = Polynomial::<Scalar<E>>::sample(&mut rng, f_degree);
+            let g = Polynomial::<Scalar<E>>::sample(&mut rng, g_degree);
+            let h = &f * &g;
+            assert_eq!(h.degree(), f_degree + g_degree);
+
+            for _ in 0..10 {
+                let x = Scalar::random(&mut rng);
+                assert_eq!(
+                    h.value::<_, Scalar<E>>(&x),
+                    f.value::<_, Scalar<E>>(&x) * g.value::<_, Scalar<E>>(&x)
+                );
+            }
+        }
+
+        let f = Polynomial::<Scalar<E>>::sample(&mut rng, 3);
+        let zero = Polynomial::<Scalar<E>>::from_coefs(Vec::new());
+        assert!((&f * &zero).coefs().is_empty());
+        assert!((&zero * &f).coefs().is_empty());
+    }
+
+    #[test]
+    fn polynomial_interpolate<E: Curve>() {
+        let mut rng = DevRng::new();
+
+        for degree in [0, 1, 2, 10] {
+            let f = Polynomial::<Scalar<E>>::sample(&mut rng, degree);
+
+            let xs = iter::repeat_with(|| NonZero::<Scalar<E>>::random(&mut rng))
+                .take(degree + 1)
+                .collect::<Vec<_>>();
+            let ys = xs
+                .iter()
+                .map(|x| f.value::<_, Scalar<E>>(x.as_ref()))
+                .collect::<Vec<_>>();
+
+            let interpolated = super::interpolate(&xs, &ys).unwrap();
+            assert_eq!(interpolated.coefs(), f.coefs());
+
+            // Length mismatch
+            assert!(super::interpolate(&xs, &ys[1..]).is_none());
+            // Duplicated point
+            let mut xs_dup = xs.clone();
+            xs_dup.push(xs[0]);
+            let mut ys_dup = ys.clone();
+            ys_dup.push(ys[0]);
+            assert!(super::interpolate(&xs_dup, &ys_dup).is_none());
+        }
+
+        let zero = super::interpolate::<E>(&[], &[]).unwrap();
+        assert!(zero.coefs().is_empty());
+    }
+
     #[test]
     fn polynomial_from_coefs<E: Curve>() {
         let mut rng = DevRng::new();
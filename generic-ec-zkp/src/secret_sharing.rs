@@ -0,0 +1,220 @@
+//! Shamir secret sharing
+//!
+//! Secret $s$ is split into $n$ shares such that any $t$ of them are sufficient to reconstruct
+//! the secret, while fewer than $t$ shares reveal nothing about it. Sharing is done by sampling
+//! a random [polynomial](Polynomial) $f(x)$ of degree $t-1$ with $f(0) = s$, and the share
+//! with index $i$ is $f(i)$. Secret is reconstructed via [lagrange interpolation](lagrange_coefficient)
+//! at point zero.
+//!
+//! ## Example
+//! ```rust
+//! use generic_ec::{SecretScalar, curves::Secp256k1};
+//! use generic_ec_zkp::secret_sharing;
+//! # use rand_core::OsRng;
+//!
+//! # fn main() -> Result<(), secret_sharing::SecretSharingError> {
+//! let secret = SecretScalar::<Secp256k1>::random(&mut OsRng);
+//! // Split the secret into 5 shares, any 3 of them are sufficient to reconstruct it
+//! let shares = secret_sharing::split(&secret, 3, 5, &mut OsRng)?;
+//!
+//! let subset = [&shares[0], &shares[2], &shares[4]].map(|(i, share)| (*i, *share.as_ref()));
+//! let reconstructed = secret_sharing::reconstruct(3, &subset)?;
+//! assert_eq!(secret.as_ref(), &reconstructed);
+//! # Ok(()) }
+//! ```
+
+use alloc::vec::Vec;
+
+use generic_ec::{Curve, NonZero, Scalar, SecretScalar};
+use rand_core::{CryptoRng, RngCore};
+
+use crate::polynomial::{lagrange_coefficient, Polynomial};
+
+/// Secret share: its index $i$ and value $f(i)$
+pub type SecretShare<E> = (NonZero<Scalar<E>>, SecretScalar<E>);
+
+/// Splits a secret into `n` shares with threshold `t`
+///
+/// Shares have indices $1, \dots, n$. Any `t` shares are sufficient to [reconstruct] the secret.
+///
+/// Returns error if `t == 0` or `t > n`.
+pub fn split<E: Curve>(
+    secret: &SecretScalar<E>,
+    t: usize,
+    n: usize,
+    rng: &mut (impl RngCore + CryptoRng),
+) -> Result<Vec<SecretShare<E>>, SecretSharingError> {
+    if t == 0 || t > n {
+        return Err(SecretSharingError::InvalidThreshold);
+    }
+    let f = Polynomial::sample_with_const_term(rng, t - 1, secret.clone());
+    // Indices start from 1, so none of them is filtered out
+    Ok((1..=n)
+        .filter_map(|i| NonZero::from_scalar(Scalar::from(i)))
+        .map(|i| {
+            let mut share = f.value::<_, Scalar<E>>(i.as_ref());
+            (i, SecretScalar::new(&mut share))
+        })
+        .collect())
+}
+
+/// Reconstructs a secret from at least `t` shares
+///
+/// Each share is a pair of its index and value. All provided shares take part in reconstruction,
+/// so they all must be valid shares of the same secret.
+///
+/// Returns error if `t == 0`, if fewer than `t` shares are provided, or if share indices are not
+/// pairwise distinct.
+pub fn reconstruct<E: Curve>(
+    t: usize,
+    shares: &[(NonZero<Scalar<E>>, Scalar<E>)],
+) -> Result<Scalar<E>, SecretSharingError> {
+    if t == 0 {
+        return Err(SecretSharingError::InvalidThreshold);
+    }
+    if shares.len() < t {
+        return Err(SecretSharingError::NotEnoughShares);
+    }
+    let indices = shares.iter().map(|(i, _)| *i).collect::<Vec<_>>();
+    if indices
+        .iter()
+        .enumerate()
+        .any(|(j, i)| indices[..j].contains(i))
+    {
+        return Err(SecretSharingError::DuplicateIndex);
+    }
+
+    shares
+        .iter()
+        .enumerate()
+        .map(|(j, (_, share))| {
+            let lambda_j = lagrange_coefficient(Scalar::zero(), j, &indices)
+                .ok_or(SecretSharingError::DuplicateIndex)?;
+            Ok(lambda_j * share)
+        })
+        .sum()
+}
+
+/// Secret sharing or reconstruction failed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SecretSharingError {
+    /// Threshold is zero or greater than number of shares
+    InvalidThreshold,
+    /// Fewer than threshold shares provided
+    NotEnoughShares,
+    /// Two shares have the same index
+    DuplicateIndex,
+}
+
+impl core::fmt::Display for SecretSharingError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::InvalidThreshold => f.write_str("invalid threshold"),
+            Self::NotEnoughShares => f.write_str("not enough shares to reconstruct the secret"),
+            Self::DuplicateIndex => f.write_str("share indices are not distinct"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SecretSharingError {}
+
+#[cfg(test)]
+#[generic_tests::define]
+mod tests {
+    use alloc::vec::Vec;
+
+    use generic_ec::{Curve, NonZero, Scalar, SecretScalar};
+    use rand::seq::index::sample;
+    use rand_dev::DevRng;
+
+    use super::SecretSharingError;
+
+    #[test]
+    fn split_and_reconstruct<E: Curve>() {
+        let mut rng = DevRng::new();
+
+        for (t, n) in [(1, 1), (1, 3), (2, 3), (3, 5), (5, 5)] {
+            let secret = SecretScalar::<E>::random(&mut rng);
+            let shares = super::split(&secret, t, n, &mut rng).unwrap();
+            assert_eq!(shares.len(), n);
+
+            // Reconstruct from random `t`-subset of shares
+            let subset = sample(&mut rng, n, t)
+                .into_iter()
+                .map(|j| (shares[j].0, *shares[j].1.as_ref()))
+                .collect::<Vec<_>>();
+            let reconstructed = super::reconstruct(t, &subset).unwrap();
+            assert_eq!(secret.as_ref(), &reconstructed);
+
+            // Reconstruct from all shares
+            let all = shares
+                .iter()
+                .map(|(i, share)| (*i, *share.as_ref()))
+                .collect::<Vec<_>>();
+            assert_eq!(secret.as_ref(), &super::reconstruct(t, &all).unwrap());
+
+            // Not enough shares
+            assert_eq!(
+                super::reconstruct(t, &subset[1..]),
+                Err(SecretSharingError::NotEnoughShares)
+            );
+            if t > 1 {
+                // Fewer than `t` shares don't reconstruct the secret even if threshold is lowered
+                let reconstructed = super::reconstruct(t - 1, &subset[1..]).unwrap();
+                assert_ne!(secret.as_ref(), &reconstructed);
+            }
+        }
+    }
+
+    #[test]
+    fn shares_have_expected_indices<E: Curve>() {
+        let mut rng = DevRng::new();
+        let secret = SecretScalar::<E>::random(&mut rng);
+
+        let shares = super::split(&secret, 3, 5, &mut rng).unwrap();
+        for (j, (i, _)) in shares.iter().enumerate() {
+            assert_eq!(i.as_ref(), &Scalar::from(j + 1));
+        }
+
+        // Threshold 1 gives out the secret itself
+        let shares = super::split(&secret, 1, 5, &mut rng).unwrap();
+        assert!(shares.iter().all(|(_, s)| s.as_ref() == secret.as_ref()));
+    }
+
+    #[test]
+    fn invalid_inputs<E: Curve>() {
+        let mut rng = DevRng::new();
+        let secret = SecretScalar::<E>::random(&mut rng);
+
+        assert_eq!(
+            super::split(&secret, 0, 3, &mut rng).unwrap_err(),
+            SecretSharingError::InvalidThreshold
+        );
+        assert_eq!(
+            super::split(&secret, 4, 3, &mut rng).unwrap_err(),
+            SecretSharingError::InvalidThreshold
+        );
+
+        let i = NonZero::from_scalar(Scalar::<E>::from(1)).unwrap();
+        let j = NonZero::from_scalar(Scalar::<E>::from(2)).unwrap();
+        let share = Scalar::random(&mut rng);
+        assert_eq!(
+            super::reconstruct(2, &[(i, share), (j, share), (i, share)]),
+            Err(SecretSharingError::DuplicateIndex)
+        );
+        assert_eq!(
+            super::reconstruct(0, &[(i, share)]),
+            Err(SecretSharingError::InvalidThreshold)
+        );
+    }
+
+    #[instantiate_tests(<generic_ec::curves::Secp256k1>)]
+    mod secp256k1 {}
+    #[instantiate_tests(<generic_ec::curves::Secp256r1>)]
+    mod secp256r1 {}
+    #[instantiate_tests(<generic_ec::curves::Stark>)]
+    mod stark {}
+    #[instantiate_tests(<generic_ec::curves::Ed25519>)]
+    mod ed25519 {}
+}
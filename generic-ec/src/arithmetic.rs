@@ -1,4 +1,4 @@
-use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 
 use crate::{Curve, Generator, NonZero, Point, Scalar, SecretScalar};
 
@@ -250,6 +250,18 @@ mod laws {
         // Correctness: refer to doc commnet of the function
         NonZero::new_unchecked(prod)
     }
+
+    /// If $A$ and $B$ are non-zero scalars mod prime integer $q$, then $A \cdot B^{-1} \ne 0 \pmod{q}$
+    ///
+    /// $B^{-1}$ is always defined and is non-zero, as $B \ne 0$ and $q$ is prime. Then refer to
+    /// [`non_zero_scalar_at_non_zero_scalar_is_non_zero_scalar`] which proves that product of two
+    /// non-zero scalars is non-zero.
+    pub fn div_of_non_zero_scalars_is_non_zero_scalar<E: Curve>(
+        a: &NonZero<Scalar<E>>,
+        b: &NonZero<Scalar<E>>,
+    ) -> NonZero<Scalar<E>> {
+        non_zero_scalar_at_non_zero_scalar_is_non_zero_scalar(a, &b.invert())
+    }
 }
 
 mod scalar {
@@ -473,6 +485,11 @@ impl_binary_ops! {
     Mul (NonZero<SecretScalar<E>>, mul, NonZero<Scalar<E>> = NonZero<Scalar<E>>) laws::non_zero_scalar_at_non_zero_scalar_is_non_zero_scalar,
 }
 
+// NonZero<Scalar> / NonZero<Scalar>
+impl_binary_ops! {
+    Div (NonZero<Scalar<E>>, div, NonZero<Scalar<E>> = NonZero<Scalar<E>>) laws::div_of_non_zero_scalars_is_non_zero_scalar,
+}
+
 // Point <> NonZero<Scalar>, NonZero<Point> <> Scalar
 impl_binary_ops! {
     Mul (Point<E>, mul, NonZero<Scalar<E>> = Point<E>) laws::mul_of_point_at_scalar_is_valid_point,
@@ -523,6 +540,7 @@ impl_op_assign! {
     NonZero<Point<E>>, MulAssign, NonZero<SecretScalar<E>>, mul_assign, *,
     NonZero<Scalar<E>>, MulAssign, NonZero<Scalar<E>>, mul_assign, *,
     NonZero<Scalar<E>>, MulAssign, NonZero<SecretScalar<E>>, mul_assign, *,
+    NonZero<Scalar<E>>, DivAssign, NonZero<Scalar<E>>, div_assign, /,
 }
 
 impl<E: Curve> Point<E> {
@@ -628,6 +646,8 @@ fn ensure_ops_implemented<E: Curve>(
         non_zero_secret_scalar + non_zero_secret_scalar => Scalar<E>,
         non_zero_secret_scalar - non_zero_secret_scalar => Scalar<E>,
         non_zero_secret_scalar * non_zero_secret_scalar => NonZero<Scalar<E>>,
+
+        non_zero_scalar / non_zero_scalar => NonZero<Scalar<E>>,
     );
 
     assert_unary_ops!(
@@ -671,5 +691,6 @@ fn ensure_ops_implemented<E: Curve>(
         scalar *= non_zero_secret_scalar;
 
         non_zero_scalar *= non_zero_scalar;
+        non_zero_scalar /= non_zero_scalar;
     );
 }
@@ -45,9 +45,9 @@ use core::fmt;
 #[doc(inline)]
 pub use crate::core::coords::{Parity, Sign};
 use crate::{
-    core::{ByteArray, Curve},
-    errors::InvalidCoordinate,
-    Scalar,
+    core::{coords as coords_core, ByteArray, Curve},
+    errors::{InvalidCoordinate, InvalidPoint, InvalidPointReason},
+    EncodedPoint, Scalar,
 };
 
 /// Affine $x, y$ coordinates of a point on elliptic curve
@@ -59,6 +59,63 @@ pub struct Coordinates<E: Curve> {
     pub y: Coordinate<E>,
 }
 
+impl<E: Curve + coords_core::HasAffineXY> Coordinates<E> {
+    /// Encodes coordinates as SEC1 uncompressed point: `04 || x || y`
+    ///
+    /// Output is the same as [`Point::to_bytes(false)`](crate::Point::to_bytes) of the point with
+    /// these coordinates.
+    ///
+    /// ```rust
+    /// use generic_ec::{Point, coords::HasAffineXY, curves::Secp256k1};
+    ///
+    /// let point = Point::<Secp256k1>::generator().to_point();
+    /// let coords = point.coords().unwrap();
+    /// assert_eq!(coords.to_uncompressed_point_bytes(), point.to_bytes(false));
+    /// ```
+    pub fn to_uncompressed_point_bytes(&self) -> EncodedPoint<E> {
+        let mut bytes = E::UncompressedPointArray::zeroes();
+        let encoding = core::iter::once(&0x04)
+            .chain(self.x.as_be_bytes())
+            .chain(self.y.as_be_bytes());
+        bytes
+            .as_mut()
+            .iter_mut()
+            .zip(encoding)
+            .for_each(|(out, byte)| *out = *byte);
+        EncodedPoint::new_uncompressed(bytes)
+    }
+
+    /// Parses coordinates from SEC1 uncompressed point encoding: `04 || x || y`
+    ///
+    /// It only checks the tag and length of the encoding, it doesn't check that coordinates
+    /// correspond to a point on the curve. Use [`HasAffineXY::from_coords`] to convert them
+    /// into a point. Identity point has no coordinates, so its encoding is rejected.
+    ///
+    /// ```rust
+    /// use generic_ec::{Point, coords::{Coordinates, HasAffineXY}, curves::Secp256k1};
+    ///
+    /// let point = Point::<Secp256k1>::generator().to_point();
+    /// let coords = Coordinates::from_uncompressed_point_bytes(&point.to_bytes(false))?;
+    /// assert_eq!(Point::from_coords(&coords), Some(point));
+    /// # Ok::<(), generic_ec::errors::InvalidPoint>(())
+    /// ```
+    pub fn from_uncompressed_point_bytes(bytes: &[u8]) -> Result<Self, InvalidPoint> {
+        let coord_len = E::CoordinateArray::zeroes().as_ref().len();
+        if bytes.len() != 1 + 2 * coord_len {
+            return Err(InvalidPoint::new(InvalidPointReason::WrongLength));
+        }
+        let Some((0x04, coords)) = bytes.split_first() else {
+            return Err(InvalidPoint::new(InvalidPointReason::MalformedEncoding));
+        };
+        let (x, y) = coords.split_at(coord_len);
+        let invalid_coord = InvalidPoint::new(InvalidPointReason::MalformedEncoding);
+        Ok(Self {
+            x: Coordinate::from_be_bytes(x).or(Err(invalid_coord))?,
+            y: Coordinate::from_be_bytes(y).or(Err(invalid_coord))?,
+        })
+    }
+}
+
 /// Affine coordinate of a point on elliptic curve
 #[derive(Clone)]
 pub struct Coordinate<E: Curve>(E::CoordinateArray);
@@ -85,6 +142,14 @@ impl<E: Curve> Coordinate<E> {
         Scalar::from_be_bytes_mod_order(self.as_be_bytes())
     }
 
+    /// Checks whether [`.to_scalar()`](Self::to_scalar) preserves coordinate value
+    ///
+    /// Returns `false` if coordinate is not less than curve order, i.e. if reducing it modulo
+    /// curve order changes its value, or if scalars and coordinates have different byte length.
+    pub fn fits_into_scalar(&self) -> bool {
+        self.to_scalar().to_be_bytes().as_bytes() == self.as_be_bytes()
+    }
+
     /// Constructs a coordinate from a byte array
     pub fn new(bytes: E::CoordinateArray) -> Self {
         Self(bytes)
@@ -96,6 +161,59 @@ impl<E: Curve> Coordinate<E> {
     }
 }
 
+/// Coordinates are elements of the field $\mathbb{F}_p$ the curve is defined over, whereas
+/// scalars are elements of $\Zq$, where $q$ is the curve order. Generally, $p \ne q$: for
+/// instance, for secp256k1 $q < p$, so [`.to_scalar()`](Coordinate::to_scalar) may change
+/// the value of a coordinate when reducing it modulo $q$, and a scalar always fits into a
+/// coordinate. Methods below, together with [`.fits_into_scalar()`](Coordinate::fits_into_scalar),
+/// let you check whether conversion between them is lossless.
+impl<E: Curve + coords_core::HasFieldModulus> Coordinate<E> {
+    /// Returns modulus $p$ of the field coordinates belong to (big-endian)
+    ///
+    /// ```rust
+    /// use generic_ec::{coords::Coordinate, curves::Secp256k1};
+    ///
+    /// let mut p_minus_one = Coordinate::<Secp256k1>::field_modulus_be_bytes();
+    /// p_minus_one[31] -= 1;
+    ///
+    /// // p - 1 is a valid coordinate, but it's greater than curve order
+    /// let coord = Coordinate::<Secp256k1>::from_be_bytes(&p_minus_one)?;
+    /// assert!(!coord.fits_into_scalar());
+    /// # Ok::<(), generic_ec::errors::InvalidCoordinate>(())
+    /// ```
+    pub fn field_modulus_be_bytes() -> E::CoordinateArray {
+        E::field_modulus()
+    }
+
+    /// Converts a scalar into a coordinate
+    ///
+    /// Returns error if scalar and coordinate have different byte length, or if scalar value
+    /// is not less than [field modulus](Self::field_modulus_be_bytes).
+    ///
+    /// ```rust
+    /// use generic_ec::{Scalar, coords::Coordinate, curves::Secp256k1};
+    /// # use rand_core::OsRng;
+    ///
+    /// let scalar = Scalar::<Secp256k1>::random(&mut OsRng);
+    /// let coord = Coordinate::from_scalar(&scalar)?;
+    /// assert_eq!(coord.to_scalar(), scalar);
+    /// # Ok::<(), generic_ec::errors::InvalidCoordinate>(())
+    /// ```
+    pub fn from_scalar(scalar: &Scalar<E>) -> Result<Self, InvalidCoordinate> {
+        let coord = Self::from_be_bytes(&scalar.to_be_bytes())?;
+        if coord.is_reduced() {
+            Ok(coord)
+        } else {
+            Err(InvalidCoordinate)
+        }
+    }
+
+    /// Checks that coordinate is less than field modulus
+    fn is_reduced(&self) -> bool {
+        self.as_be_bytes() < Self::field_modulus_be_bytes().as_ref()
+    }
+}
+
 impl<E: Curve> AsRef<[u8]> for Coordinate<E> {
     #[inline(always)]
     fn as_ref(&self) -> &[u8] {
@@ -1,8 +1,25 @@
 use core::{fmt, ops};
 
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
 use crate::{as_raw::AsRaw, core::ByteArray, Curve};
 
 /// Bytes representation of an elliptic point
+///
+/// Obtained via [`Point::to_bytes`](crate::Point::to_bytes). Encoding is computed once when
+/// `EncodedPoint` is constructed, and it's stored inline (without heap allocation). It can
+/// be kept around and borrowed as `&[u8]` as many times as needed via [`.as_bytes()`](Self::as_bytes),
+/// [`AsRef<[u8]>`](AsRef) or [`Deref`](ops::Deref) without recomputing the encoding.
+///
+/// ```rust
+/// use generic_ec::{Point, curves::Secp256k1};
+///
+/// let point = Point::<Secp256k1>::generator().to_point();
+/// let encoded = point.to_bytes(true);
+/// let bytes: &[u8] = encoded.as_bytes();
+/// assert_eq!(bytes.len(), 33);
+/// assert_eq!(&encoded[..], bytes);
+/// ```
 pub struct EncodedPoint<E: Curve>(EncodedPointInner<E>);
 
 impl<E: Curve> EncodedPoint<E> {
@@ -15,6 +32,8 @@ impl<E: Curve> EncodedPoint<E> {
     }
 
     /// Returns bytes representation of the point
+    ///
+    /// Doesn't recompute the encoding, simply borrows the bytes.
     pub fn as_bytes(&self) -> &[u8] {
         match &self.0 {
             EncodedPointInner::Compressed(bytes) => bytes.as_ref(),
@@ -67,7 +86,66 @@ impl<E: Curve> AsRef<[u8]> for EncodedPoint<E> {
     }
 }
 
+/// Serializes encoded point as bytes
+///
+/// Bytes are serialized as hex string if serialization format is human-readable, or as byte
+/// array otherwise. Unlike [`Point`](crate::Point) serialization, curve name is not included.
+#[cfg(feature = "serde")]
+impl<E: Curve> serde::Serialize for EncodedPoint<E> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde_with::SerializeAs;
+        crate::serde::utils::Bytes::serialize_as(self, serializer)
+    }
+}
+
+/// Deserializes encoded point from bytes
+///
+/// Length of bytes must be equal to the length of either compressed or uncompressed point
+/// encoding on curve `E`. Bytes are not checked to be a valid point encoding, use
+/// [`Point::from_bytes`](crate::Point::from_bytes) to decode the point.
+#[cfg(feature = "serde")]
+impl<'de, E: Curve> serde::Deserialize<'de> for EncodedPoint<E> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let compressed_len = E::CompressedPointArray::LEN;
+        let uncompressed_len = E::UncompressedPointArray::LEN;
+        let (bytes, len) = crate::serde::utils::deserialize_bytes_of_len::<
+            _,
+            E::UncompressedPointArray,
+        >(deserializer, &[compressed_len, uncompressed_len])?;
+
+        if len == compressed_len {
+            let mut compressed = E::CompressedPointArray::zeroes();
+            compressed.as_mut().copy_from_slice(&bytes.as_ref()[..len]);
+            Ok(Self::new_compressed(compressed))
+        } else {
+            Ok(Self::new_uncompressed(bytes))
+        }
+    }
+}
+
 /// Bytes representation of a scalar (either in big-endian or in little-endian)
+///
+/// Obtained via [`Scalar::to_be_bytes`](crate::Scalar::to_be_bytes) or
+/// [`Scalar::to_le_bytes`](crate::Scalar::to_le_bytes). Similarly to [`EncodedPoint`], encoding
+/// is computed once and stored inline, so `EncodedScalar` can be kept around and borrowed as
+/// `&[u8]` via [`.as_bytes()`](Self::as_bytes), [`AsRef<[u8]>`](AsRef) or [`Deref`](ops::Deref).
+///
+/// ```rust
+/// use generic_ec::{Scalar, curves::Secp256k1};
+///
+/// let encoded = Scalar::<Secp256k1>::one().to_be_bytes();
+/// let bytes: &[u8] = encoded.as_bytes();
+/// assert_eq!(bytes.len(), 32);
+/// assert_eq!(bytes[31], 1);
+/// ```
+///
+/// Encoded scalar may hold bytes of a secret scalar, so its buffer is zeroized on drop.
 #[derive(Clone)]
 pub struct EncodedScalar<E: Curve>(E::ScalarArray);
 
@@ -77,6 +155,8 @@ impl<E: Curve> EncodedScalar<E> {
     }
 
     /// Returns bytes representation of a scalar
+    ///
+    /// Doesn't recompute the encoding, simply borrows the bytes.
     pub fn as_bytes(&self) -> &[u8] {
         self.0.as_ref()
     }
@@ -127,6 +207,52 @@ impl<E: Curve> Default for EncodedScalar<E> {
     }
 }
 
+/// Serializes encoded scalar as bytes
+///
+/// Bytes are serialized as hex string if serialization format is human-readable, or as byte
+/// array otherwise. Unlike [`Scalar`](crate::Scalar) serialization, curve name is not included.
+#[cfg(feature = "serde")]
+impl<E: Curve> serde::Serialize for EncodedScalar<E> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde_with::SerializeAs;
+        crate::serde::utils::Bytes::serialize_as(&self.0, serializer)
+    }
+}
+
+/// Deserializes encoded scalar from bytes
+///
+/// Length of bytes must be equal to [`Scalar::<E>::SERIALIZED_LEN`](crate::Scalar::SERIALIZED_LEN).
+/// Bytes are not checked to encode an integer less than group order, use
+/// [`Scalar::from_be_bytes`](crate::Scalar::from_be_bytes) or
+/// [`Scalar::from_le_bytes`](crate::Scalar::from_le_bytes) to decode the scalar.
+#[cfg(feature = "serde")]
+impl<'de, E: Curve> serde::Deserialize<'de> for EncodedScalar<E> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde_with::DeserializeAs;
+        crate::serde::utils::Bytes::deserialize_as(deserializer).map(Self)
+    }
+}
+
+impl<E: Curve> Zeroize for EncodedScalar<E> {
+    fn zeroize(&mut self) {
+        self.0.as_mut().zeroize()
+    }
+}
+
+impl<E: Curve> Drop for EncodedScalar<E> {
+    fn drop(&mut self) {
+        self.zeroize()
+    }
+}
+
+impl<E: Curve> ZeroizeOnDrop for EncodedScalar<E> {}
+
 impl<E: Curve> AsRaw for EncodedScalar<E> {
     type Raw = E::ScalarArray;
     fn as_raw(&self) -> &Self::Raw {
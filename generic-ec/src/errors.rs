@@ -19,18 +19,155 @@ impl fmt::Display for InvalidCoordinate {
 impl Error for InvalidCoordinate {}
 
 /// Indicates that point is not valid
+///
+/// Use [`.reason()`](Self::reason) to find out why the point was rejected.
 #[derive(Debug, Clone, Copy)]
-pub struct InvalidPoint;
+pub struct InvalidPoint {
+    reason: InvalidPointReason,
+}
+
+impl InvalidPoint {
+    pub(crate) fn new(reason: InvalidPointReason) -> Self {
+        Self { reason }
+    }
+
+    /// Reason why the point is not valid
+    pub fn reason(&self) -> InvalidPointReason {
+        self.reason
+    }
+}
 
 impl fmt::Display for InvalidPoint {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_str("invalid point")
+        write!(f, "invalid point: {}", self.reason)
+    }
+}
+
+/// Reason why point is not valid
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum InvalidPointReason {
+    /// Encoding has length that doesn't correspond to any point encoding on the curve
+    WrongLength,
+    /// Encoding is malformed (e.g. it has unknown SEC1 tag)
+    ///
+    /// Some curve backends can't distinguish a malformed encoding from encoding of a point which
+    /// doesn't lie on the curve, in this case `MalformedEncoding` is returned
+    MalformedEncoding,
+    /// Point is not on the curve
+    NotOnCurve,
+    /// Point has a small-order (torsion) component
+    HasTorsion,
+    /// Point is the identity, but non-zero point is expected
+    ///
+    /// Returned by [`NonZero::<Point<E>>::from_bytes`](crate::NonZero::from_bytes)
+    Identity,
+}
+
+impl fmt::Display for InvalidPointReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::WrongLength => "wrong length",
+            Self::MalformedEncoding => "malformed encoding",
+            Self::NotOnCurve => "not on curve",
+            Self::HasTorsion => "has torsion component",
+            Self::Identity => "point is identity",
+        })
     }
 }
 
 #[cfg(feature = "std")]
 impl Error for InvalidPoint {}
 
+/// Indicates that string is not a valid hex-encoded point
+///
+/// Use [`.reason()`](Self::reason) to find out why the string was rejected.
+#[derive(Debug, Clone, Copy)]
+pub struct ParsePointError {
+    reason: ParsePointErrorReason,
+}
+
+impl ParsePointError {
+    pub(crate) fn new(reason: ParsePointErrorReason) -> Self {
+        Self { reason }
+    }
+
+    /// Reason why the string is not a valid point
+    pub fn reason(&self) -> ParsePointErrorReason {
+        self.reason
+    }
+}
+
+impl fmt::Display for ParsePointError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid hex-encoded point: {}", self.reason)
+    }
+}
+
+/// Reason why string is not a valid hex-encoded point
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParsePointErrorReason {
+    /// String has odd number of hex digits
+    OddLength,
+    /// String contains a character which is not a hex digit
+    InvalidCharacter,
+    /// String is a valid hex, but decoded bytes are not a valid point
+    InvalidPoint(InvalidPointReason),
+}
+
+impl fmt::Display for ParsePointErrorReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OddLength => f.write_str("odd number of hex digits"),
+            Self::InvalidCharacter => f.write_str("invalid hex character"),
+            Self::InvalidPoint(reason) => reason.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for ParsePointError {}
+
+/// Provided output buffer is too small to fit the encoding
+///
+/// Returned by [`Point::encode_compressed_into`](crate::Point::encode_compressed_into) and
+/// [`Point::encode_uncompressed_into`](crate::Point::encode_uncompressed_into)
+#[derive(Debug, Clone, Copy)]
+pub struct BufferTooSmall {
+    required: usize,
+    provided: usize,
+}
+
+impl BufferTooSmall {
+    pub(crate) fn new(required: usize, provided: usize) -> Self {
+        Self { required, provided }
+    }
+
+    /// Amount of bytes required to fit the encoding
+    pub fn required_len(&self) -> usize {
+        self.required
+    }
+
+    /// Size of the buffer that was provided
+    pub fn provided_len(&self) -> usize {
+        self.provided
+    }
+}
+
+impl fmt::Display for BufferTooSmall {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "buffer too small: required {} bytes, provided {} bytes",
+            self.required, self.provided
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for BufferTooSmall {}
+
 /// Indicates that scalar is not valid
 #[derive(Debug, Clone, Copy)]
 pub struct InvalidScalar;
@@ -44,19 +181,106 @@ impl fmt::Display for InvalidScalar {
 #[cfg(feature = "std")]
 impl Error for InvalidScalar {}
 
+/// Indicates that string is not a valid hex-encoded scalar
+///
+/// Use [`.reason()`](Self::reason) to find out why the string was rejected.
+#[derive(Debug, Clone, Copy)]
+pub struct ParseScalarError {
+    reason: ParseScalarErrorReason,
+}
+
+impl ParseScalarError {
+    pub(crate) fn new(reason: ParseScalarErrorReason) -> Self {
+        Self { reason }
+    }
+
+    /// Reason why the string is not a valid scalar
+    pub fn reason(&self) -> ParseScalarErrorReason {
+        self.reason
+    }
+}
+
+impl fmt::Display for ParseScalarError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid hex-encoded scalar: {}", self.reason)
+    }
+}
+
+/// Reason why string is not a valid hex-encoded scalar
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseScalarErrorReason {
+    /// String has odd number of hex digits
+    OddLength,
+    /// String encodes more bytes than scalar can have
+    TooLong,
+    /// String contains a character which is not a hex digit
+    InvalidCharacter,
+    /// Encoded integer is not less than group order
+    OutOfRange,
+}
+
+impl fmt::Display for ParseScalarErrorReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::OddLength => "odd number of hex digits",
+            Self::TooLong => "too long",
+            Self::InvalidCharacter => "invalid hex character",
+            Self::OutOfRange => "integer is not less than group order",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for ParseScalarError {}
+
 /// Appeared zero point is not expected/accepted
+///
+/// In particular, it's returned when deserializing [`NonZero<Point<E>>`](crate::NonZero)
+/// from an encoding of the identity point.
 #[derive(Debug, Clone, Copy)]
 pub struct ZeroPoint;
 
 impl fmt::Display for ZeroPoint {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_str("zero point")
+        f.write_str("expected non-zero point, got identity")
     }
 }
 
 #[cfg(feature = "std")]
 impl Error for ZeroPoint {}
 
+/// Indicates that bytes are not a valid public key
+///
+/// Returned by [`Point::validate_public_key`](crate::Point::validate_public_key)
+#[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
+pub enum PublicKeyError {
+    /// Bytes are not a valid encoding of a point
+    Malformed(InvalidPoint),
+    /// Bytes encode the identity point
+    Identity,
+}
+
+impl fmt::Display for PublicKeyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Malformed(err) => write!(f, "malformed public key: {err}"),
+            Self::Identity => f.write_str("public key is identity point"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for PublicKeyError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Malformed(err) => Some(err),
+            Self::Identity => None,
+        }
+    }
+}
+
 /// Appeared zero scalar is not expected/accepted
 #[derive(Debug, Clone, Copy)]
 pub struct ZeroScalar;
@@ -69,3 +293,180 @@ impl fmt::Display for ZeroScalar {
 
 #[cfg(feature = "std")]
 impl Error for ZeroScalar {}
+
+/// Indicates that bytes are not a valid encoding of non-zero scalar
+///
+/// Returned by [`NonZero::<Scalar<E>>::from_be_bytes`](crate::NonZero::from_be_bytes) and
+/// [`NonZero::<Scalar<E>>::from_le_bytes`](crate::NonZero::from_le_bytes)
+#[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
+pub enum InvalidNonZeroScalar {
+    /// Bytes are not a valid encoding of a scalar
+    Invalid(InvalidScalar),
+    /// Bytes encode zero scalar
+    Zero,
+}
+
+impl fmt::Display for InvalidNonZeroScalar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Invalid(err) => err.fmt(f),
+            Self::Zero => f.write_str("expected non-zero scalar, got zero"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for InvalidNonZeroScalar {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Invalid(err) => Some(err),
+            Self::Zero => None,
+        }
+    }
+}
+
+/// Value belongs to a different curve than expected
+///
+/// Returned when converting [`serde::AnyCurvePoint`](crate::serde::AnyCurvePoint) into a point
+/// on specific curve.
+#[derive(Debug, Clone, Copy)]
+pub struct CurveMismatch {
+    expected: &'static str,
+    got: &'static str,
+}
+
+impl CurveMismatch {
+    #[cfg(all(feature = "serde", feature = "curves"))]
+    pub(crate) fn new(expected: &'static str, got: &'static str) -> Self {
+        Self { expected, got }
+    }
+
+    /// Name of the curve that was expected
+    pub fn expected(&self) -> &'static str {
+        self.expected
+    }
+
+    /// Name of the curve the value belongs to
+    pub fn got(&self) -> &'static str {
+        self.got
+    }
+}
+
+impl fmt::Display for CurveMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "expected {} curve, got {}", self.expected, self.got)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for CurveMismatch {}
+
+/// Randomness source is broken
+///
+/// Returned when rejection sampling didn't produce a valid value after a reasonable number of
+/// attempts, which practically means that randomness source is defected.
+#[derive(Debug, Clone, Copy)]
+pub struct BrokenRng;
+
+impl fmt::Display for BrokenRng {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("defected source of randomness")
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for BrokenRng {}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use std::string::ToString;
+
+    use super::*;
+
+    fn assert_error<E: Error + Send + Sync + 'static>(err: E) -> std::string::String {
+        // Makes sure that error can be propagated via `?` into a boxed error
+        let boxed: std::boxed::Box<dyn Error + Send + Sync> = err.into();
+        boxed.to_string()
+    }
+
+    #[test]
+    fn errors_are_displayed() {
+        let point_reasons = [
+            InvalidPointReason::WrongLength,
+            InvalidPointReason::MalformedEncoding,
+            InvalidPointReason::NotOnCurve,
+            InvalidPointReason::HasTorsion,
+            InvalidPointReason::Identity,
+        ];
+        for reason in point_reasons {
+            let err = assert_error(InvalidPoint::new(reason));
+            assert!(!reason.to_string().is_empty());
+            assert_eq!(err, std::format!("invalid point: {reason}"));
+        }
+
+        let parse_point_reasons = [
+            ParsePointErrorReason::OddLength,
+            ParsePointErrorReason::InvalidCharacter,
+            ParsePointErrorReason::InvalidPoint(InvalidPointReason::NotOnCurve),
+        ];
+        for reason in parse_point_reasons {
+            let err = assert_error(ParsePointError::new(reason));
+            assert_eq!(err, std::format!("invalid hex-encoded point: {reason}"));
+        }
+
+        let parse_scalar_reasons = [
+            ParseScalarErrorReason::OddLength,
+            ParseScalarErrorReason::TooLong,
+            ParseScalarErrorReason::InvalidCharacter,
+            ParseScalarErrorReason::OutOfRange,
+        ];
+        for reason in parse_scalar_reasons {
+            let err = assert_error(ParseScalarError::new(reason));
+            assert_eq!(err, std::format!("invalid hex-encoded scalar: {reason}"));
+        }
+
+        assert_eq!(assert_error(InvalidCoordinate), "invalid coordinate");
+        assert_eq!(assert_error(InvalidScalar), "invalid scalar");
+        assert_eq!(
+            assert_error(ZeroPoint),
+            "expected non-zero point, got identity"
+        );
+        assert_eq!(assert_error(ZeroScalar), "zero scalar");
+        assert_eq!(assert_error(BrokenRng), "defected source of randomness");
+        assert_eq!(
+            assert_error(CurveMismatch {
+                expected: "secp256k1",
+                got: "secp256r1"
+            }),
+            "expected secp256k1 curve, got secp256r1"
+        );
+        assert_eq!(
+            assert_error(BufferTooSmall::new(33, 32)),
+            "buffer too small: required 33 bytes, provided 32 bytes"
+        );
+
+        let malformed = InvalidPoint::new(InvalidPointReason::WrongLength);
+        assert_eq!(
+            assert_error(PublicKeyError::Malformed(malformed)),
+            "malformed public key: invalid point: wrong length"
+        );
+        assert!(!assert_error(PublicKeyError::Identity).is_empty());
+        assert_eq!(
+            assert_error(InvalidNonZeroScalar::Invalid(InvalidScalar)),
+            "invalid scalar"
+        );
+        assert!(!assert_error(InvalidNonZeroScalar::Zero).is_empty());
+    }
+
+    #[test]
+    fn errors_expose_source() {
+        let malformed = InvalidPoint::new(InvalidPointReason::NotOnCurve);
+        assert!(PublicKeyError::Malformed(malformed).source().is_some());
+        assert!(PublicKeyError::Identity.source().is_none());
+        assert!(InvalidNonZeroScalar::Invalid(InvalidScalar)
+            .source()
+            .is_some());
+        assert!(InvalidNonZeroScalar::Zero.source().is_none());
+    }
+}
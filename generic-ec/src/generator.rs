@@ -2,6 +2,9 @@ use phantom_type::PhantomType;
 
 use crate::{core::*, NonZero, Point};
 
+#[cfg(feature = "alloc")]
+pub use self::table::{GeneratorTable, PrecomputedBase};
+
 /// Generator of curve `E`
 ///
 /// Curve generator is a point on curve defined in curve specs. For some curves,
@@ -49,8 +52,160 @@ impl<E: Curve> From<Generator<E>> for NonZero<Point<E>> {
     }
 }
 
+impl<E: Curve> core::ops::Neg for Generator<E> {
+    type Output = NonZero<Point<E>>;
+    #[inline]
+    fn neg(self) -> Self::Output {
+        -self.to_nonzero_point()
+    }
+}
+
+impl<E: Curve> core::ops::Neg for &Generator<E> {
+    type Output = NonZero<Point<E>>;
+    #[inline]
+    fn neg(self) -> Self::Output {
+        -*self
+    }
+}
+
 impl<E: Curve> Default for Generator<E> {
     fn default() -> Self {
         Self(PhantomType::new())
     }
 }
+
+#[cfg(feature = "alloc")]
+mod table {
+    use alloc::vec::Vec;
+
+    use subtle::ConstantTimeEq;
+
+    use crate::{Curve, NonZero, Point, Scalar};
+
+    /// Precomputed table for fast multiplication of a fixed point at a scalar
+    ///
+    /// Table stores $d \cdot 16^i \cdot B$ for every digit $0 \le d < 16$ and every position $i$ of
+    /// [radix 16 representation](Scalar::as_radix16_le) of a scalar, where $B$ is the base point.
+    /// Multiplication then doesn't need any point doublings: it sums up one table entry per
+    /// radix 16 digit of the scalar.
+    ///
+    /// Multiplication is constant time with respect to the scalar, as a table entry is selected
+    /// by scanning the whole row, so the table can be used with secret scalars (e.g. blinding
+    /// factors of Pedersen commitments). Constructing the table is expensive and takes
+    /// $O(\log_2 q)$ points of memory, it should only be done once and then reused for many
+    /// multiplications.
+    ///
+    /// For the curve generator, use [`GeneratorTable`].
+    ///
+    /// ```rust
+    /// use generic_ec::{NonZero, Point, PrecomputedBase, Scalar, curves::Secp256k1};
+    /// use rand::rngs::OsRng;
+    ///
+    /// let h = NonZero::<Point<Secp256k1>>::from_point(
+    ///     Point::generator() * Scalar::random(&mut OsRng),
+    /// ).expect("random point is not zero");
+    /// let table = PrecomputedBase::new(h);
+    ///
+    /// let k = Scalar::random(&mut OsRng);
+    /// assert_eq!(table.mul(&k), h * k);
+    /// ```
+    #[derive(Clone)]
+    pub struct PrecomputedBase<E: Curve> {
+        /// `table[i][d] = d * 16^i * B`
+        table: Vec<[Point<E>; 16]>,
+    }
+
+    impl<E: Curve> PrecomputedBase<E> {
+        /// Precomputes the table for base point $B$
+        pub fn new(base: NonZero<Point<E>>) -> Self {
+            let digits = 2 * Scalar::<E>::serialized_len();
+
+            let mut table = Vec::with_capacity(digits);
+            let mut base = *base;
+            for _ in 0..digits {
+                let mut row = [Point::zero(); 16];
+                for d in 1..row.len() {
+                    row[d] = row[d - 1] + base;
+                }
+                base = row[15] + base;
+                table.push(row);
+            }
+
+            Self { table }
+        }
+
+        /// Returns the base point $B$
+        pub fn base(&self) -> NonZero<Point<E>> {
+            // Correctness: `table[0][1]` is the base point which is non-zero by construction.
+            // Table always has at least one row, as scalars are never serialized into zero bytes.
+            NonZero::new_unchecked(self.table[0][1])
+        }
+
+        /// Computes $s \cdot B$
+        pub fn mul(&self, scalar: &Scalar<E>) -> Point<E> {
+            let mut result = Point::zero();
+            for (digit, row) in scalar.as_radix16_le().zip(&self.table) {
+                let mut term = row[0];
+                for (d, row_d) in (0u8..).zip(row).skip(1) {
+                    term.conditional_assign(row_d, d.ct_eq(&digit));
+                }
+                result += term;
+            }
+            result
+        }
+    }
+
+    /// Precomputed table for fast multiplication of the curve generator at a scalar
+    ///
+    /// It's a [`PrecomputedBase`] for the curve generator $G$, refer to its documentation
+    /// for the details.
+    ///
+    /// Note that some curves (e.g. [Ed25519](crate::curves::Ed25519)) already use precomputed
+    /// tables for [generator multiplication](crate::Generator), so using `GeneratorTable`
+    /// may not bring any speedup for them.
+    ///
+    /// ```rust
+    /// use generic_ec::{Point, Scalar, curves::Secp256k1};
+    /// use rand::rngs::OsRng;
+    ///
+    /// let table = Point::<Secp256k1>::generator_table();
+    ///
+    /// let k = Scalar::random(&mut OsRng);
+    /// assert_eq!(table.mul(&k), Point::generator() * k);
+    /// ```
+    #[derive(Clone)]
+    pub struct GeneratorTable<E: Curve>(PrecomputedBase<E>);
+
+    impl<E: Curve> GeneratorTable<E> {
+        /// Precomputes the table
+        pub fn new() -> Self {
+            Self(PrecomputedBase::new(Point::generator().to_nonzero_point()))
+        }
+
+        /// Computes $s \cdot G$
+        pub fn mul(&self, scalar: &Scalar<E>) -> Point<E> {
+            self.0.mul(scalar)
+        }
+    }
+
+    impl<E: Curve> Default for GeneratorTable<E> {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    impl<E: Curve> From<GeneratorTable<E>> for PrecomputedBase<E> {
+        fn from(table: GeneratorTable<E>) -> Self {
+            table.0
+        }
+    }
+
+    impl<E: Curve> Point<E> {
+        /// Precomputes [a table](GeneratorTable) for fast generator multiplication
+        ///
+        /// Same as [`GeneratorTable::new()`]
+        pub fn generator_table() -> GeneratorTable<E> {
+            GeneratorTable::new()
+        }
+    }
+}
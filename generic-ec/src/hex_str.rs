@@ -0,0 +1,46 @@
+//! Hex encoding and decoding used by `Display`, `LowerHex`/`UpperHex` and `FromStr` impls
+//!
+//! `hex` crate is only available with `serde` feature, and formatting and parsing need to work
+//! without it.
+
+use core::fmt;
+
+/// String contains a character which is not a hex digit
+pub(crate) struct InvalidHexCharacter;
+
+/// Decodes hex string into `out`
+///
+/// Length of `hex` must be exactly twice as long as `out`, caller is responsible for checking that.
+pub(crate) fn decode_to_slice(hex: &[u8], out: &mut [u8]) -> Result<(), InvalidHexCharacter> {
+    debug_assert_eq!(hex.len(), 2 * out.len());
+    for (byte, digits) in out.iter_mut().zip(hex.chunks_exact(2)) {
+        if let &[hi, lo] = digits {
+            *byte = (hex_digit(hi)? << 4) | hex_digit(lo)?;
+        }
+    }
+    Ok(())
+}
+
+fn hex_digit(c: u8) -> Result<u8, InvalidHexCharacter> {
+    char::from(c)
+        .to_digit(16)
+        .map(|d| d as u8)
+        .ok_or(InvalidHexCharacter)
+}
+
+/// Writes `bytes` as hex string into the formatter
+///
+/// Output is prefixed with `0x` if alternate flag is specified (e.g. `{:#x}`).
+pub(crate) fn fmt_hex(bytes: &[u8], f: &mut fmt::Formatter<'_>, upper_case: bool) -> fmt::Result {
+    if f.alternate() {
+        f.write_str("0x")?;
+    }
+    for byte in bytes {
+        if upper_case {
+            write!(f, "{byte:02X}")?;
+        } else {
+            write!(f, "{byte:02x}")?;
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,49 @@
+//! Conversions between `generic-ec` types and types of RustCrypto curve crates
+//!
+//! Allow mixing `generic-ec` with code based on [`k256`](crate::curves::k256) or
+//! [`p256`](crate::curves::p256) crates without serializing values through bytes.
+
+use crate::{as_raw::AsRaw, as_raw::FromRaw, errors::InvalidPoint, Point, Scalar};
+use generic_ec_curves::rust_crypto::{RustCryptoPoint, RustCryptoScalar};
+
+/// Implements conversions between scalars and points of `generic-ec` and of the backend crate
+macro_rules! impl_interop {
+    ($curve:ty, $backend:ident) => {
+        impl From<generic_ec_curves::rust_crypto::$backend::Scalar> for Scalar<$curve> {
+            fn from(scalar: generic_ec_curves::rust_crypto::$backend::Scalar) -> Self {
+                Scalar::from_raw(RustCryptoScalar(scalar))
+            }
+        }
+
+        impl From<Scalar<$curve>> for generic_ec_curves::rust_crypto::$backend::Scalar {
+            fn from(scalar: Scalar<$curve>) -> Self {
+                scalar.as_raw().0
+            }
+        }
+
+        /// Validates that the point is free of torsion component
+        ///
+        /// Curve has prime order, so any point is valid, but the check is kept for uniformity
+        /// with other ways of constructing a point.
+        impl TryFrom<generic_ec_curves::rust_crypto::$backend::ProjectivePoint> for Point<$curve> {
+            type Error = InvalidPoint;
+
+            fn try_from(
+                point: generic_ec_curves::rust_crypto::$backend::ProjectivePoint,
+            ) -> Result<Self, Self::Error> {
+                Point::try_from_raw_with_reason(RustCryptoPoint(point))
+            }
+        }
+
+        impl From<Point<$curve>> for generic_ec_curves::rust_crypto::$backend::ProjectivePoint {
+            fn from(point: Point<$curve>) -> Self {
+                point.as_raw().0
+            }
+        }
+    };
+}
+
+#[cfg(feature = "curve-secp256k1")]
+impl_interop!(crate::curves::Secp256k1, k256);
+#[cfg(feature = "curve-secp256r1")]
+impl_interop!(crate::curves::Secp256r1, p256);
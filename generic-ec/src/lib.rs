@@ -67,17 +67,24 @@
 //!
 //! Crate provides support for following elliptic curves out of box:
 //!
-//! | Curve        | Feature            | Backend           |
-//! |--------------|--------------------|-------------------|
-//! | secp256k1    | `curve-secp256k1`  | [RustCrypto/k256] |
-//! | secp256r1    | `curve-secp256r1`  | [RustCrypto/p256] |
-//! | stark-curve  | `curve-stark`      | [Dfns/stark]      |
-//! | Ed25519      | `curve-ed25519`    | [curve25519-dalek]|
+//! | Curve           | Feature              | Backend               |
+//! |-----------------|----------------------|-----------------------|
+//! | secp256k1       | `curve-secp256k1`    | [RustCrypto/k256]     |
+//! | secp256r1       | `curve-secp256r1`    | [RustCrypto/p256]     |
+//! | secp384r1       | `curve-secp384r1`    | [RustCrypto/p384]     |
+//! | SM2             | `curve-sm2`          | [RustCrypto/sm2]      |
+//! | stark-curve     | `curve-stark`        | [Dfns/stark]          |
+//! | Ed25519         | `curve-ed25519`      | [curve25519-dalek]    |
+//! | Ristretto255    | `curve-ristretto255` | [curve25519-dalek]    |
+//! | BLS12-381 G1/G2 | `curve-bls12-381`    | [zkcrypto/bls12_381]  |
 //!
 //! [RustCrypto/k256]: https://github.com/RustCrypto/elliptic-curves/tree/master/k256
 //! [RustCrypto/p256]: https://github.com/RustCrypto/elliptic-curves/tree/master/p256
+//! [RustCrypto/p384]: https://github.com/RustCrypto/elliptic-curves/tree/master/p384
+//! [RustCrypto/sm2]: https://github.com/RustCrypto/elliptic-curves/tree/master/sm2
 //! [Dfns/stark]: https://github.com/LFDT-Lockness/stark-curve/
 //! [curve25519-dalek]: https://docs.rs/curve25519-dalek/
+//! [zkcrypto/bls12_381]: https://docs.rs/bls12_381/
 //!
 //! In order to use one of the supported curves, you need to turn on corresponding feature. E.g. if you want
 //! to use secp256k1 curve, add this to Cargo.toml:
@@ -106,8 +113,23 @@
 //!
 //! * `curve-{name}` enables specified curve support. See list of [supported curves].
 //! * `all-curves` enables all supported curves
+//! * `curve-bls12-381-hash-to-curve` enables hashing to BLS12-381 curves. It relies on `experimental`
+//!   API of `bls12_381` crate, which has no semver guarantees, and is not included into `all-curves`
 //! * `serde` enables points/scalar (de)serialization support. (enabled by default)
 //! * `std` enables support of standard library (enabled by default)
+//! * `rayon` enables parallel multiscalar multiplication [`multiscalar::Parallel`]
+//! * `insecure-test-rng` enables [`SecretScalar::random_from_non_crypto_rng`] which is only
+//!   supposed to be used in tests
+//! * `test-harness` enables [`test_harness`] module with utilities for generating reproducible
+//!   test data
+//! * `crypto-bigint` enables [`Scalar::to_uint`] and [`Scalar::from_uint`] for Ed25519,
+//!   Ristretto255 and BLS12-381 curves (other curves support them regardless of the feature)
+//! * `keccak` enables [`Scalar::from_keccak256`] for secp256k1 which derives scalars from
+//!   Keccak-256 digests the same way Ethereum does
+//! * `rfc6979` enables [`Scalar::derive_nonce_rfc6979`] for deterministic nonce generation
+//! * `arbitrary` implements [`arbitrary::Arbitrary`](https://docs.rs/arbitrary) for [`Scalar`]
+//!   and [`Point`], which is useful for fuzzing
+//! * `proptest` enables [`proptest`](crate::proptest) module with strategies for property testing
 //!
 //! ## Examples
 //!
@@ -193,6 +215,8 @@ extern crate std;
 extern crate alloc;
 
 pub use generic_ec_core as core;
+/// Constant-time boolean used for branchless selection, re-exported from [`subtle`]
+pub use subtle::Choice;
 
 mod arithmetic;
 pub mod as_raw;
@@ -200,8 +224,13 @@ pub mod coords;
 mod encoded;
 pub mod errors;
 mod generator;
+mod hex_str;
+#[cfg(any(feature = "curve-secp256k1", feature = "curve-secp256r1"))]
+mod interop;
 pub mod multiscalar;
 mod non_zero;
+#[cfg(feature = "rfc6979")]
+mod nonce;
 mod point;
 mod scalar;
 mod secret_scalar;
@@ -218,7 +247,7 @@ mod _unused_deps {
 /// Common traits for points and scalars
 pub mod traits {
     #[doc(inline)]
-    pub use crate::core::{One, Reduce, Samplable, Zero};
+    pub use crate::core::{HashToCurve, One, Reduce, Samplable, Zero};
 
     /// Trait that allows you to check whether value is zero
     pub trait IsZero {
@@ -227,30 +256,63 @@ pub mod traits {
     }
 }
 
+#[cfg(feature = "proptest")]
+pub mod proptest;
 pub mod serde;
+#[cfg(feature = "test-harness")]
+pub mod test_harness;
 
 pub use self::{
     core::Curve,
     encoded::{EncodedPoint, EncodedScalar},
     generator::Generator,
     non_zero::definition::NonZero,
-    point::definition::Point,
-    scalar::{Radix16Iter, Scalar},
+    point::{definition::Point, PointInputFormat},
+    scalar::{ConstMul, Radix16Iter, Scalar},
     secret_scalar::definition::SecretScalar,
 };
 
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use self::generator::{GeneratorTable, PrecomputedBase};
+
 /// Curves supported out of the box
 pub mod curves {
     #[cfg(feature = "curve-ed25519")]
     #[cfg_attr(docsrs, doc(cfg(feature = "curve-ed25519")))]
     pub use generic_ec_curves::Ed25519;
+    #[cfg(feature = "curve-ristretto255")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "curve-ristretto255")))]
+    pub use generic_ec_curves::Ristretto255;
     #[cfg(feature = "curve-secp256k1")]
     #[cfg_attr(docsrs, doc(cfg(feature = "curve-secp256k1")))]
     pub use generic_ec_curves::Secp256k1;
     #[cfg(feature = "curve-secp256r1")]
     #[cfg_attr(docsrs, doc(cfg(feature = "curve-secp256r1")))]
     pub use generic_ec_curves::Secp256r1;
+    #[cfg(feature = "curve-secp384r1")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "curve-secp384r1")))]
+    pub use generic_ec_curves::Secp384r1;
+    #[cfg(feature = "curve-sm2")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "curve-sm2")))]
+    pub use generic_ec_curves::Sm2;
     #[cfg(feature = "curve-stark")]
     #[cfg_attr(docsrs, doc(cfg(feature = "curve-stark")))]
     pub use generic_ec_curves::Stark;
+    #[cfg(feature = "curve-bls12-381")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "curve-bls12-381")))]
+    pub use generic_ec_curves::{Bls12_381G1, Bls12_381G2};
+
+    /// [`k256`](https://docs.rs/k256) crate that implements [`Secp256k1`]
+    ///
+    /// Scalars and points of `Secp256k1` can be converted into `k256` scalars and points and back.
+    #[cfg(feature = "curve-secp256k1")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "curve-secp256k1")))]
+    pub use generic_ec_curves::rust_crypto::k256;
+    /// [`p256`](https://docs.rs/p256) crate that implements [`Secp256r1`]
+    ///
+    /// Scalars and points of `Secp256r1` can be converted into `p256` scalars and points and back.
+    #[cfg(feature = "curve-secp256r1")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "curve-secp256r1")))]
+    pub use generic_ec_curves::rust_crypto::p256;
 }
@@ -20,18 +20,27 @@
 //! to the most efficient available algorithm, similarly to [`struct@Default`].
 //!
 //! Alternatively, if you need to use a specific algorithm, this module provides
-//! [`Straus`] and [`Dalek`].
+//! [`Straus`], [`Pippenger`] and [`Dalek`].
+//!
+//! [`Straus`] is the most efficient for small $n$, while [`Pippenger`] outperforms it
+//! for large $n$ (hundreds of terms).
 //!
 //! On [`Ed25519`](crate::curves::Ed25519) curve, consider using [`Dalek`] multiscalar
 //! implementation.
+//!
+//! When `rayon` feature is on, [`Parallel`] algorithm is available which splits the work
+//! between multiple threads. It's beneficial only for large $n$ (thousands of terms). If
+//! terms are already produced by a parallel iterator, use [`parallel_multiscalar_mul`].
 
 use crate::{Curve, Point, Scalar};
 
+#[cfg(feature = "alloc")]
+mod pippenger;
 #[cfg(feature = "alloc")]
 mod straus;
 
 #[cfg(feature = "alloc")]
-pub use self::straus::Straus;
+pub use self::{pippenger::Pippenger, straus::Straus};
 
 /// Multiscalar multiplication algorithm
 ///
@@ -56,7 +65,15 @@ pub trait MultiscalarMul<E: Curve> {
 ///
 /// When `alloc` feature is off, it always falls back to [`Naive`] implementation.
 ///
-/// When `alloc` feature is on, it uses [`Straus`] algorithm.
+/// When `alloc` feature is on, it uses [`Straus`] algorithm for small number of terms,
+/// and [`Pippenger`] algorithm when number of terms is large enough.
+///
+/// When `rayon` feature is on and number of terms is large enough, it uses [`Parallel`] algorithm.
+///
+/// When `alloc` feature is on and the curve provides its own multiscalar multiplication (e.g.
+/// [`Ed25519`](crate::curves::Ed25519) uses the same algorithm as [`Dalek`]), it's used instead of
+/// [`Straus`] and [`Pippenger`]. Curve-specific implementation is variable time, however, the same
+/// applies to any multiscalar algorithm in this module.
 ///
 /// It may be more convenient to use [`Scalar::multiscalar_mul`] which is an alias
 /// to `Default`.
@@ -80,7 +97,48 @@ impl<E: Curve> MultiscalarMul<E> for Default {
         S: AsRef<Scalar<E>>,
         P: AsRef<Point<E>>,
     {
-        Straus::multiscalar_mul(scalar_points)
+        #[cfg(feature = "rayon")]
+        if scalar_points.len() >= Parallel::THRESHOLD {
+            return Parallel::multiscalar_mul(scalar_points);
+        }
+        if E::HAS_VARTIME_MULTISCALAR_MUL {
+            return Self::curve_specific(scalar_points);
+        }
+        if scalar_points.len() >= Self::PIPPENGER_THRESHOLD {
+            Pippenger::multiscalar_mul(scalar_points)
+        } else {
+            Straus::multiscalar_mul(scalar_points)
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Default {
+    /// Number of terms starting from which [`Pippenger`] algorithm is used
+    const PIPPENGER_THRESHOLD: usize = 256;
+
+    /// Uses multiscalar multiplication provided by the curve implementation
+    fn curve_specific<E: Curve, S, P>(
+        scalar_points: impl ExactSizeIterator<Item = (S, P)>,
+    ) -> Point<E>
+    where
+        S: AsRef<Scalar<E>>,
+        P: AsRef<Point<E>>,
+    {
+        use alloc::vec::Vec;
+
+        use generic_ec_core::{OnCurve, SmallFactor};
+
+        use crate::as_raw::AsRaw;
+
+        let (scalars, points): (Vec<_>, Vec<_>) = scalar_points
+            .map(|(s, p)| (*s.as_ref().as_raw(), *p.as_ref().as_raw()))
+            .unzip();
+        let result = E::vartime_multiscalar_mul(&scalars, &points);
+
+        // Resulting point must be valid
+        debug_assert!(result.is_on_curve().into() && result.is_torsion_free().into());
+        Point::from_raw_unchecked(result)
     }
 }
 
@@ -148,3 +206,90 @@ impl MultiscalarMul<crate::curves::Ed25519> for Dalek {
         Point::from_raw_unchecked(result)
     }
 }
+
+/// Parallel multiscalar multiplication
+///
+/// Splits the input into chunks, computes multiscalar multiplication of each chunk with [`Straus`]
+/// or [`Pippenger`] algorithm (depending on the chunk size) on [`rayon`] thread pool, and sums
+/// the partial results.
+///
+/// Running in parallel has its overhead, so it outperforms [`Straus`] only for large $n$ (thousands
+/// of terms). [`struct@Default`] switches to this algorithm when $n$ is large enough.
+///
+/// See also [`parallel_multiscalar_mul`] which takes a parallel iterator.
+#[cfg(feature = "rayon")]
+pub struct Parallel;
+
+#[cfg(feature = "rayon")]
+impl Parallel {
+    /// Number of terms starting from which [`struct@Default`] uses parallel algorithm
+    const THRESHOLD: usize = 1024;
+    /// Minimal number of terms processed by a single thread
+    const MIN_CHUNK_SIZE: usize = 256;
+}
+
+#[cfg(feature = "rayon")]
+impl<E: Curve> MultiscalarMul<E> for Parallel {
+    fn multiscalar_mul<S, P>(scalar_points: impl ExactSizeIterator<Item = (S, P)>) -> Point<E>
+    where
+        S: AsRef<Scalar<E>>,
+        P: AsRef<Point<E>>,
+    {
+        use alloc::vec::Vec;
+        use rayon::prelude::*;
+
+        let scalar_points = scalar_points
+            .map(|(s, p)| (*s.as_ref(), *p.as_ref()))
+            .collect::<Vec<_>>();
+        parallel_multiscalar_mul(scalar_points.par_iter().map(|(s, p)| (s, p)))
+    }
+}
+
+/// Performs multiscalar multiplication on [`rayon`] thread pool
+///
+/// Same as [`Parallel`] algorithm, but takes a parallel iterator of pairs `(scalar, point)`, so
+/// the terms don't need to be collected before splitting them between the threads. Iterator must
+/// be [indexed](rayon::iter::IndexedParallelIterator): its length is used to determine the chunk
+/// size.
+///
+/// Similarly to [`MultiscalarMul`], it's not constant-time, thus it should not be used with
+/// [`SecretScalar<E>`](crate::SecretScalar).
+///
+/// ```rust
+/// use generic_ec::{curves::Secp256k1, multiscalar, Point, Scalar};
+/// use rayon::prelude::*;
+/// # let mut rng = rand::rngs::OsRng;
+///
+/// let scalars = (0..2000).map(|_| Scalar::<Secp256k1>::random(&mut rng)).collect::<Vec<_>>();
+/// let points = (0..2000).map(|_| Point::generator() * Scalar::random(&mut rng)).collect::<Vec<_>>();
+///
+/// let sum = multiscalar::parallel_multiscalar_mul(scalars.par_iter().zip(&points));
+/// assert_eq!(sum, Scalar::multiscalar_mul(scalars.iter().zip(&points)));
+/// ```
+#[cfg(feature = "rayon")]
+pub fn parallel_multiscalar_mul<E, S, P>(
+    scalar_points: impl rayon::iter::IndexedParallelIterator<Item = (S, P)>,
+) -> Point<E>
+where
+    E: Curve,
+    S: AsRef<Scalar<E>> + Send,
+    P: AsRef<Point<E>> + Send,
+{
+    use rayon::prelude::*;
+
+    let chunk_size = scalar_points
+        .len()
+        .div_ceil(rayon::current_num_threads())
+        .max(Parallel::MIN_CHUNK_SIZE);
+
+    scalar_points
+        .chunks(chunk_size)
+        .map(|chunk| {
+            if chunk.len() >= Default::PIPPENGER_THRESHOLD {
+                Pippenger::multiscalar_mul(chunk.into_iter())
+            } else {
+                Straus::multiscalar_mul(chunk.into_iter())
+            }
+        })
+        .reduce(Point::zero, |a, b| a + b)
+}
@@ -0,0 +1,231 @@
+use alloc::{vec, vec::Vec};
+
+use crate::{Curve, Point, Scalar};
+
+/// Pippenger algorithm
+///
+/// Bucket-based multiscalar multiplication which outperforms [`Straus`](super::Straus)
+/// for large $n$ (amount of input scalar/point pairs).
+///
+/// # How it works
+/// Below we'll briefly explain how the algorithm works for better auditability.
+///
+/// Recall that the multiscalar algorithm takes list of $n$ points $P_1, \dots, P_n$, and a list
+/// of $n$ scalars $s_1, \dots, s_n$, and it outputs $Q$ such that:
+///
+/// $$Q = s_1 P_1 + \dots + s_n P_n$$
+///
+/// ## Signed radix $2^c$ representation
+/// Each scalar $s_i$ is represented in signed radix $2^c$:
+///
+/// $$s_i = s_{i,0} + s_{i,1} 2^c + \dots + s_{i,k} 2^{kc}$$
+///
+/// where $-2^{c-1} \le s_{i,j} < 2^{c-1}$. Parameter $c$ (window size) is chosen based on $n$:
+/// the larger $n$ is, the larger window is used.
+///
+/// ## Buckets
+/// For each digit position $j$, we compute a sum:
+///
+/// $$R_j = \sum_{i=1}^n s_{i,j} P_i$$
+///
+/// To do that, we allocate $2^{c-1}$ buckets $B_1, \dots, B_{2^{c-1}}$ and put each $P_i$ into
+/// bucket $B_{|s_{i,j}|}$ (negating the point if $s_{i,j} < 0$). Then
+///
+/// $$R_j = \sum_{b=1}^{2^{c-1}} b \cdot B_b$$
+///
+/// which can be computed using only $2 \cdot 2^{c-1}$ point additions via running sum:
+/// $R_j = \sum_{b} \sum_{b' \ge b} B_{b'}$.
+///
+/// ## Computing the sum
+/// Resulting point is obtained from $R_j$ via Horner's method:
+///
+/// $$Q = R_0 + 2^c (R_1 + 2^c (R_2 + \dots + 2^c R_k))$$
+///
+/// ## Credits
+/// Algorithm is similar to the one implemented in [`curve25519_dalek`](curve25519), with the
+/// modification that it works with any curve, not only with ed25519. You can find original
+/// implementation [here](https://github.com/dalek-cryptography/curve25519-dalek/blob/1efe6a93b176c4389b78e81e52b2cf85d728aac6/curve25519-dalek/src/backend/serial/scalar_mul/pippenger.rs).
+pub struct Pippenger;
+
+impl Pippenger {
+    /// Chooses window size $c$ depending on number of terms $n$
+    fn window_size(n: usize) -> usize {
+        match n {
+            0..=31 => 4,
+            32..=127 => 5,
+            128..=499 => 6,
+            500..=799 => 7,
+            _ => 8,
+        }
+    }
+}
+
+impl<E: Curve> super::MultiscalarMul<E> for Pippenger {
+    fn multiscalar_mul<S, P>(
+        scalar_points: impl ExactSizeIterator<Item = (S, P)>,
+    ) -> crate::Point<E>
+    where
+        S: AsRef<Scalar<E>>,
+        P: AsRef<Point<E>>,
+    {
+        let c = Self::window_size(scalar_points.len());
+        let mut digits = RadixMatrix::new(c, scalar_points.len());
+        let points: Vec<Point<E>> = scalar_points
+            .map(|(scalar, point)| {
+                digits.add_scalar(scalar.as_ref());
+                *point.as_ref()
+            })
+            .collect();
+        if points.is_empty() {
+            return Point::zero();
+        }
+
+        let mut buckets = vec![Point::<E>::zero(); 1 << (c - 1)];
+        let mut r = Point::zero();
+        for j in (0..digits.digits_count).rev() {
+            for _ in 0..c {
+                r = r.double();
+            }
+
+            buckets.iter_mut().for_each(|b| *b = Point::zero());
+            for (scalar_digits, point) in digits.iter().zip(&points) {
+                let digit = scalar_digits[j];
+                match digit.cmp(&0) {
+                    core::cmp::Ordering::Greater => {
+                        buckets[usize::from(digit.unsigned_abs()) - 1] += point;
+                    }
+                    core::cmp::Ordering::Less => {
+                        buckets[usize::from(digit.unsigned_abs()) - 1] -= point;
+                    }
+                    core::cmp::Ordering::Equal => {}
+                }
+            }
+
+            let mut running_sum = Point::zero();
+            let mut r_j = Point::zero();
+            for bucket in buckets.iter().rev() {
+                running_sum += bucket;
+                r_j += running_sum;
+            }
+            r += r_j;
+        }
+        r
+    }
+}
+
+/// Stores signed radix $2^c$ representation of multiple scalars
+struct RadixMatrix<E: Curve> {
+    /// Amount of digits in representation of one scalar
+    digits_count: usize,
+    /// Input parameter `c`
+    c: usize,
+    matrix: Vec<i16>,
+
+    _curve: core::marker::PhantomData<E>,
+}
+
+impl<E: Curve> RadixMatrix<E> {
+    /// Constructs a new matrix with parameter `c`
+    ///
+    /// Preallocates memory to fit `capacity` amount of scalars
+    fn new(c: usize, capacity: usize) -> Self {
+        assert!((2..=15).contains(&c));
+        // Extra digit is needed to fit the carry
+        let digits_count = (Scalar::<E>::serialized_len() * 8).div_ceil(c) + 1;
+
+        Self {
+            digits_count,
+            c,
+            matrix: Vec::with_capacity(digits_count * capacity),
+            _curve: Default::default(),
+        }
+    }
+
+    /// Adds a scalar into matrix
+    fn add_scalar(&mut self, scalar: &Scalar<E>) {
+        let scalar_bytes = scalar.to_le_bytes();
+        let window_mask = (1u32 << self.c) - 1;
+        let width_half = 1i32 << (self.c - 1);
+
+        let offset = self.matrix.len();
+        debug_assert!(
+            offset + self.digits_count <= self.matrix.capacity(),
+            "unnecessary allocations detected"
+        );
+        self.matrix.resize(offset + self.digits_count, 0);
+        let digits = &mut self.matrix[offset..];
+
+        let mut carry = 0;
+        for (j, digit) in digits.iter_mut().enumerate() {
+            let pos = j * self.c;
+            let byte_idx = pos / 8;
+            let bit_buf = scalar_bytes
+                .get(byte_idx..)
+                .unwrap_or_default()
+                .iter()
+                .take(3)
+                .rev()
+                .fold(0u32, |acc, byte| (acc << 8) | u32::from(*byte));
+            let window = ((bit_buf >> (pos % 8)) & window_mask) as i32 + carry;
+
+            carry = (window + width_half) >> self.c;
+            // Window is within `[-2^(c-1), 2^(c-1))`, fits into i16 as c <= 15
+            *digit = (window - (carry << self.c)) as i16;
+        }
+
+        debug_assert_eq!(carry, 0);
+    }
+
+    /// Iterates over scalars representations in the same order as
+    /// scalars were added into the matrix
+    fn iter(&self) -> impl Iterator<Item = &[i16]> {
+        self.matrix.chunks_exact(self.digits_count)
+    }
+}
+
+#[cfg(test)]
+#[generic_tests::define]
+mod tests {
+    use alloc::vec::Vec;
+    use core::iter;
+
+    use crate::{Curve, Scalar};
+
+    #[test]
+    fn radix_representation_is_correct<E: Curve>() {
+        let mut rng = rand_dev::DevRng::new();
+
+        let scalars = iter::once(Scalar::<E>::zero())
+            .chain(iter::once(Scalar::one()))
+            .chain(iter::once(-Scalar::one()))
+            .chain(iter::repeat_with(|| Scalar::random(&mut rng)).take(15))
+            .collect::<Vec<_>>();
+
+        for c in 2..=15 {
+            let mut digits = super::RadixMatrix::new(c, scalars.len());
+            scalars.iter().for_each(|scalar| digits.add_scalar(scalar));
+
+            for (scalar, scalar_digits) in scalars.iter().zip(digits.iter()) {
+                assert!(scalar_digits
+                    .iter()
+                    .all(|&d| -(1i32 << (c - 1)) <= i32::from(d) && i32::from(d) < (1 << (c - 1))));
+
+                let base = Scalar::<E>::from(1u32 << c);
+                let expected = scalar_digits
+                    .iter()
+                    .rev()
+                    .fold(Scalar::<E>::zero(), |acc, d| acc * base + Scalar::from(*d));
+                assert_eq!(*scalar, expected)
+            }
+        }
+    }
+
+    #[instantiate_tests(<crate::curves::Secp256k1>)]
+    mod secp256k1 {}
+    #[instantiate_tests(<crate::curves::Secp256r1>)]
+    mod secp256r1 {}
+    #[instantiate_tests(<crate::curves::Stark>)]
+    mod stark {}
+    #[instantiate_tests(<crate::curves::Ed25519>)]
+    mod ed25519 {}
+}
@@ -5,13 +5,19 @@ use crate::{Curve, Point, Scalar};
 
 /// Straus algorithm
 ///
+/// Algorithm is variable time: it skips zero NAF digits and starts from the most
+/// significant non-zero digit among all scalars, so it's faster when all scalars are
+/// short (e.g. random 128 bits coefficients in batch verification). It must only be
+/// used with public inputs.
+///
 /// # How it works
 /// Below we'll briefly explain how the algorithm works for better auditability. You can
 /// also refer to [original](#credits) implementation.
 ///
-/// Note that algorithm is defined for a parameter $w$, however, in our implementation we hardcoded
-/// $w = 5$. It was observed in the benchmarks that $w=5$ gives the best performance for all
-/// $n$ (amount of input scalar/point pairs).
+/// Note that algorithm is defined for a parameter $w$. [`MultiscalarMul`](super::MultiscalarMul)
+/// implementation uses $w = 5$: it was observed in the benchmarks that $w=5$ gives the best
+/// performance for all $n$ (amount of input scalar/point pairs) on supported curves. Other
+/// values of $w$ can be used via [`Straus::multiscalar_mul_with_width`].
 ///
 /// Recall that the multiscalar algorithm takes list of $n$ points $P_1, \dots, P_n$, and a list
 /// of $n$ scalars $s_1, \dots, s_n$, and it outputs $Q$ such that:
@@ -68,31 +74,66 @@ use crate::{Curve, Point, Scalar};
 /// [here](https://github.com/dalek-cryptography/curve25519-dalek/blob/1efe6a93b176c4389b78e81e52b2cf85d728aac6/curve25519-dalek/src/backend/serial/scalar_mul/straus.rs#L147-L201).
 pub struct Straus;
 
-impl<E: Curve> super::MultiscalarMul<E> for Straus {
-    fn multiscalar_mul<S, P>(
+impl Straus {
+    /// Default width $w$ of NAF used by [`MultiscalarMul`](super::MultiscalarMul) implementation
+    pub const DEFAULT_WIDTH: usize = 5;
+
+    /// Performs multiscalar multiplication with given width $w$ of NAF
+    ///
+    /// Same as [`Straus::multiscalar_mul`](super::MultiscalarMul::multiscalar_mul), but allows
+    /// to choose parameter $w$. Larger $w$ means fewer point additions, but larger lookup tables
+    /// ($2^{w-2}$ points per input point). It may be useful for tuning performance on specific
+    /// curves.
+    ///
+    /// ## Panics
+    /// Panics if $w$ is not within range $[2, 8]$.
+    ///
+    /// ```rust
+    /// use generic_ec::{Point, Scalar, curves::Secp256k1, multiscalar::{MultiscalarMul, Straus}};
+    /// # let mut rng = rand::rngs::OsRng;
+    ///
+    /// let scalar_points = (0..10)
+    ///     .map(|_| (Scalar::<Secp256k1>::random(&mut rng), Point::generator() * Scalar::random(&mut rng)))
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(
+    ///     Straus::multiscalar_mul_with_width(4, scalar_points.iter().copied()),
+    ///     Straus::multiscalar_mul(scalar_points.iter().copied()),
+    /// );
+    /// ```
+    pub fn multiscalar_mul_with_width<E, S, P>(
+        w: usize,
         scalar_points: impl ExactSizeIterator<Item = (S, P)>,
-    ) -> crate::Point<E>
+    ) -> Point<E>
     where
+        E: Curve,
         S: AsRef<Scalar<E>>,
         P: AsRef<Point<E>>,
     {
-        let mut nafs = NafMatrix::new(5, scalar_points.len());
+        let mut nafs = NafMatrix::new(w, scalar_points.len());
         let lookup_tables: Vec<_> = scalar_points
             .into_iter()
             .map(|(scalar, point)| {
                 nafs.add_scalar(scalar.as_ref());
                 point
             })
-            .map(|point| LookupTable::new(*point.as_ref()))
+            .map(|point| LookupTable::new(w, *point.as_ref()))
             .collect();
         if lookup_tables.is_empty() {
             return Point::zero();
         }
 
-        let naf_size = nafs.naf_size;
+        // Leading columns where all the digits are zero don't contribute to the sum,
+        // so we start from the most significant non-zero digit
+        let Some(top) = nafs
+            .iter()
+            .filter_map(|naf| naf.iter().rposition(|naf_i| *naf_i != 0))
+            .max()
+        else {
+            return Point::zero();
+        };
 
         let mut r = Point::zero();
-        for (i, is_first_iter) in (0..naf_size)
+        for (i, is_first_iter) in (0..=top)
             .rev()
             .zip(iter::once(true).chain(iter::repeat(false)))
         {
@@ -116,22 +157,37 @@ impl<E: Curve> super::MultiscalarMul<E> for Straus {
     }
 }
 
-struct LookupTable<E: Curve>([Point<E>; 8]);
+impl<E: Curve> super::MultiscalarMul<E> for Straus {
+    fn multiscalar_mul<S, P>(
+        scalar_points: impl ExactSizeIterator<Item = (S, P)>,
+    ) -> crate::Point<E>
+    where
+        S: AsRef<Scalar<E>>,
+        P: AsRef<Point<E>>,
+    {
+        Self::multiscalar_mul_with_width(Self::DEFAULT_WIDTH, scalar_points)
+    }
+}
+
+/// Stores $P, 3P, 5P, \dots, (2^{w-1} - 1) P$
+struct LookupTable<E: Curve>(Vec<Point<E>>);
 
 impl<E: Curve> LookupTable<E> {
-    /// Builds a lookup table for point $P$
-    fn new(point: Point<E>) -> Self {
-        let mut table = [point; 8];
+    /// Builds a lookup table for point $P$ and NAF width $w$
+    fn new(w: usize, point: Point<E>) -> Self {
+        let size = 1 << (w - 2);
+        let mut table = Vec::with_capacity(size);
+        table.push(point);
         let point2 = point.double();
-        for i in 0..7 {
-            table[i + 1] = point2 + table[i];
+        for i in 1..size {
+            table.push(point2 + table[i - 1]);
         }
         Self(table)
     }
-    /// Takes odd integer $x$ such as $0 < x < 2^4$, returns $x P$
+    /// Takes odd integer $x$ such as $0 < x < 2^{w-1}$, returns $x P$
     fn get(&self, x: usize) -> Point<E> {
         debug_assert_eq!(x & 1, 1);
-        debug_assert!(x < 16);
+        debug_assert!(x < 2 * self.0.len());
 
         self.0[x / 2]
     }
@@ -185,8 +241,9 @@ impl<E: Curve> NafMatrix<E> {
     /// Adds a scalar into matrix
     fn add_scalar(&mut self, scalar: &Scalar<E>) {
         let scalar_bytes = scalar.to_le_bytes();
-        let mut x_u64 = vec![0u64; scalar_bytes.len() / 8 + 1];
-        read_le_u64_into(&scalar_bytes, &mut x_u64[0..4]);
+        let limbs = scalar_bytes.len() / 8;
+        let mut x_u64 = vec![0u64; limbs + 1];
+        read_le_u64_into(&scalar_bytes, &mut x_u64[0..limbs]);
 
         let offset = self.matrix.len();
         debug_assert!(
@@ -308,10 +365,12 @@ mod tests {
         let points = iter::once(Point::<E>::generator().to_point())
             .chain(iter::repeat_with(|| Scalar::random(&mut rng) * Point::generator()).take(50));
         for point in points {
-            let table = super::LookupTable::new(point);
+            for w in 2..=8 {
+                let table = super::LookupTable::new(w, point);
 
-            for x in (1..16).step_by(2) {
-                assert_eq!(table.get(x), point * Scalar::from(x));
+                for x in (1..1 << (w - 1)).step_by(2) {
+                    assert_eq!(table.get(x), point * Scalar::from(x));
+                }
             }
         }
     }
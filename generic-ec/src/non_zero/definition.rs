@@ -1,8 +1,11 @@
 use core::ops::Deref;
 
-use zeroize::Zeroize;
+use zeroize::{Zeroize, ZeroizeOnDrop};
 
 /// Non zero [Point](crate::Point) or [Scalar](crate::Scalar)
+///
+/// Comparison and ordering of `NonZero<T>` are delegated to `T`, so e.g. `NonZero<Scalar<E>>`
+/// can be used as a `BTreeMap` key, ordered the same way as the inner scalars.
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Zeroize, Debug)]
 #[cfg_attr(
     feature = "serde",
@@ -43,3 +46,7 @@ impl<T> Deref for NonZero<T> {
         &self.0
     }
 }
+
+/// `NonZero` doesn't have its own drop logic, so wrapped value is zeroized on drop as long as `T`
+/// does that
+impl<T: ZeroizeOnDrop> ZeroizeOnDrop for NonZero<T> {}
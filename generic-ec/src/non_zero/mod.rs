@@ -9,7 +9,9 @@ use subtle::{ConstantTimeEq, CtOption};
 use crate::{
     as_raw::FromRaw,
     core::Samplable,
-    errors::{ZeroPoint, ZeroScalar},
+    errors::{
+        BrokenRng, InvalidNonZeroScalar, InvalidPoint, InvalidPointReason, ZeroPoint, ZeroScalar,
+    },
     Curve, Point, Scalar, SecretScalar,
 };
 
@@ -19,6 +21,24 @@ pub mod coords;
 pub mod definition;
 
 impl<E: Curve> NonZero<Point<E>> {
+    /// Generates random non-zero point
+    ///
+    /// Point is computed as $P = G \cdot s$ where $s$ is a [random non-zero scalar](NonZero::<Scalar<E>>::random).
+    /// As $G$ generates prime-order group, $P$ is guaranteed to be non-zero, and it's uniformly
+    /// distributed over non-identity points of the group.
+    ///
+    /// ```rust
+    /// use generic_ec::{Point, NonZero, curves::Secp256k1};
+    /// use rand::rngs::OsRng;
+    ///
+    /// // E.g. a fresh Pedersen commitment base
+    /// let h = NonZero::<Point<Secp256k1>>::random(&mut OsRng);
+    /// assert_ne!(*h, Point::zero());
+    /// ```
+    pub fn random<R: RngCore>(rng: &mut R) -> Self {
+        Point::generator() * NonZero::<Scalar<E>>::random(rng)
+    }
+
     /// Constructs non-zero point
     ///
     /// Returns `None` if point is zero
@@ -38,6 +58,50 @@ impl<E: Curve> NonZero<Point<E>> {
         // zero value is not accessible by anyone
         CtOption::new(Self::new_unchecked(point), is_non_zero)
     }
+
+    /// Decodes non-zero point from its bytes representation
+    ///
+    /// Same as [`Point::from_bytes`], but also rejects the identity point, in which case returned
+    /// error has [`InvalidPointReason::Identity`] reason.
+    ///
+    /// ```rust
+    /// use generic_ec::{Point, NonZero, curves::Secp256k1, errors::InvalidPointReason};
+    ///
+    /// let g = Point::<Secp256k1>::generator().to_point();
+    /// assert!(NonZero::<Point<Secp256k1>>::from_bytes(g.to_bytes(true)).is_ok());
+    ///
+    /// let zero = Point::<Secp256k1>::zero();
+    /// let err = NonZero::<Point<Secp256k1>>::from_bytes(zero.to_bytes(true)).unwrap_err();
+    /// assert_eq!(err.reason(), InvalidPointReason::Identity);
+    /// ```
+    pub fn from_bytes(bytes: impl AsRef<[u8]>) -> Result<Self, InvalidPoint> {
+        let point = Point::from_bytes(bytes)?;
+        Self::from_point(point).ok_or(InvalidPoint::new(InvalidPointReason::Identity))
+    }
+
+    /// Computes $P + Q$, returns `None` if result is zero
+    ///
+    /// Sum of two non-zero points may be zero (e.g. $P + (-P) = O$), so it can't be
+    /// non-zero by construction. This method adds points and checks that the sum is non-zero
+    /// in one step.
+    ///
+    /// ```rust
+    /// use generic_ec::{Point, NonZero, curves::Secp256k1};
+    ///
+    /// let g = Point::<Secp256k1>::generator().to_nonzero_point();
+    /// assert!(g.checked_add(&g).is_some());
+    /// assert!(g.checked_add(&-g).is_none());
+    /// ```
+    pub fn checked_add(&self, other: &Point<E>) -> Option<Self> {
+        Self::from_point(**self + other)
+    }
+
+    /// Computes $P - Q$, returns `None` if result is zero
+    ///
+    /// Same as [`checked_add`](Self::checked_add), but subtracts points.
+    pub fn checked_sub(&self, other: &Point<E>) -> Option<Self> {
+        Self::from_point(**self - other)
+    }
 }
 
 impl<E: Curve> NonZero<Scalar<E>> {
@@ -51,14 +115,22 @@ impl<E: Curve> NonZero<Scalar<E>> {
     /// Panics if randomness source returned 100 zero scalars in a row. It happens with
     /// $2^{-25600}$ probability, which practically means that randomness source is broken.
     pub fn random<R: RngCore>(rng: &mut R) -> Self {
-        match iter::repeat_with(|| E::Scalar::random(rng))
+        match Self::try_random(rng) {
+            Ok(s) => s,
+            Err(_) => panic!("defected source of randomness"),
+        }
+    }
+
+    /// Generates random non-zero scalar
+    ///
+    /// Same as [`NonZero::<Scalar<E>>::random`](Self::random), but returns an error instead of
+    /// panicking if randomness source returned 100 zero scalars in a row.
+    pub fn try_random<R: RngCore>(rng: &mut R) -> Result<Self, BrokenRng> {
+        iter::repeat_with(|| E::Scalar::random(rng))
             .take(100)
             .flat_map(|s| NonZero::from_scalar(Scalar::from_raw(s)))
             .next()
-        {
-            Some(s) => s,
-            None => panic!("defected source of randomness"),
-        }
+            .ok_or(BrokenRng)
     }
 
     #[doc = include_str!("../../docs/hash_to_scalar.md")]
@@ -115,6 +187,32 @@ impl<E: Curve> NonZero<Scalar<E>> {
         CtOption::new(Self::new_unchecked(scalar), is_non_zero)
     }
 
+    /// Decodes non-zero scalar from its bytes representation in big-endian order
+    ///
+    /// Same as [`Scalar::from_be_bytes`], but also rejects zero scalar.
+    ///
+    /// ```rust
+    /// use generic_ec::{Scalar, NonZero, curves::Secp256k1, errors::InvalidNonZeroScalar};
+    ///
+    /// assert!(NonZero::<Scalar<Secp256k1>>::from_be_bytes(&[1]).is_ok());
+    /// assert!(matches!(
+    ///     NonZero::<Scalar<Secp256k1>>::from_be_bytes(&[0; 32]),
+    ///     Err(InvalidNonZeroScalar::Zero),
+    /// ));
+    /// ```
+    pub fn from_be_bytes(bytes: impl AsRef<[u8]>) -> Result<Self, InvalidNonZeroScalar> {
+        let scalar = Scalar::from_be_bytes(bytes).map_err(InvalidNonZeroScalar::Invalid)?;
+        Self::from_scalar(scalar).ok_or(InvalidNonZeroScalar::Zero)
+    }
+
+    /// Decodes non-zero scalar from its bytes representation in little-endian order
+    ///
+    /// Same as [`Scalar::from_le_bytes`], but also rejects zero scalar.
+    pub fn from_le_bytes(bytes: impl AsRef<[u8]>) -> Result<Self, InvalidNonZeroScalar> {
+        let scalar = Scalar::from_le_bytes(bytes).map_err(InvalidNonZeroScalar::Invalid)?;
+        Self::from_scalar(scalar).ok_or(InvalidNonZeroScalar::Zero)
+    }
+
     /// Returns scalar inverse $S^{-1}$
     ///
     /// Similar to [Scalar::invert], but this function is always defined as inverse is defined for all
@@ -128,6 +226,13 @@ impl<E: Curve> NonZero<Scalar<E>> {
         Self::new_unchecked(inv)
     }
 
+    /// Multiplies curve generator at the scalar, returns $S \cdot G$
+    ///
+    /// Similar to [Scalar::mul_generator], but output is guaranteed to be non-zero
+    pub fn mul_generator(&self) -> NonZero<Point<E>> {
+        self * Point::generator()
+    }
+
     /// Upgrades the non-zero scalar into non-zero [`SecretScalar`]
     pub fn into_secret(self) -> NonZero<SecretScalar<E>> {
         let mut scalar = self.into_inner();
@@ -300,6 +405,12 @@ impl<'s, E: Curve> Sum<&'s NonZero<Point<E>>> for Point<E> {
     }
 }
 
+impl<E: Curve> crate::traits::Samplable for NonZero<Point<E>> {
+    fn random<R: RngCore>(rng: &mut R) -> Self {
+        Self::random(rng)
+    }
+}
+
 impl<E: Curve> crate::traits::Samplable for NonZero<Scalar<E>> {
     fn random<R: RngCore>(rng: &mut R) -> Self {
         Self::random(rng)
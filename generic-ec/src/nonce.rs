@@ -0,0 +1,135 @@
+//! Deterministic nonce generation as defined in [RFC 6979](https://www.rfc-editor.org/rfc/rfc6979)
+
+use digest::{crypto_common::BlockSizeUser, Digest, Mac};
+use hmac::SimpleHmac;
+use zeroize::Zeroize;
+
+use crate::{core::ByteArray, Curve, NonZero, Scalar};
+
+impl<E: Curve> Scalar<E> {
+    /// Derives a nonce from the secret key and message hash as specified in [RFC 6979, Section 3.2](https://www.rfc-editor.org/rfc/rfc6979#section-3.2)
+    ///
+    /// Nonce is derived via HMAC-DRBG instantiated with hash function `D` and seeded with the
+    /// secret key and the message hash. `msg_hash` is the message hashed by the signature
+    /// scheme (RFC calls it $H(m)$), normally, but not necessarily, with the same hash function
+    /// `D`. Candidates that are zero or not less than group order $q$ are rejected, as RFC
+    /// prescribes.
+    ///
+    /// Output is the same as `k` in RFC 6979 Appendix A test vectors, so it can be used to
+    /// implement deterministic ECDSA, as well as deterministic Schnorr signatures.
+    ///
+    /// ```rust
+    /// use generic_ec::{Scalar, SecretScalar, curves::Secp256k1};
+    /// use sha2::{Digest, Sha256};
+    /// # let mut rng = rand::rngs::OsRng;
+    ///
+    /// let secret_key = SecretScalar::<Secp256k1>::random(&mut rng);
+    /// let msg_hash = Sha256::digest(b"message");
+    /// let nonce = Scalar::derive_nonce_rfc6979::<Sha256>(secret_key.as_ref(), &msg_hash);
+    /// assert_eq!(
+    ///     nonce,
+    ///     Scalar::derive_nonce_rfc6979::<Sha256>(secret_key.as_ref(), &msg_hash),
+    /// );
+    /// ```
+    pub fn derive_nonce_rfc6979<D>(secret: &Scalar<E>, msg_hash: &[u8]) -> NonZero<Scalar<E>>
+    where
+        D: Digest + BlockSizeUser,
+    {
+        let qlen = Self::bits();
+        let rlen = qlen.div_ceil(8);
+
+        // int2octets(x)
+        let secret = secret.to_be_bytes();
+        let secret = secret.as_bytes();
+        let secret = &secret[secret.len() - rlen..];
+        // bits2octets(h1) = int2octets(bits2int(h1) mod q)
+        let msg_hash = Scalar::<E>::from_be_bytes_mod_order(bits2int::<E>(msg_hash, qlen));
+        let msg_hash = msg_hash.to_be_bytes();
+        let msg_hash = msg_hash.as_bytes();
+        let msg_hash = &msg_hash[msg_hash.len() - rlen..];
+
+        let mut v = digest::Output::<D>::default();
+        v.iter_mut().for_each(|b| *b = 0x01);
+        let mut k = digest::Output::<D>::default();
+
+        for i in [0x00, 0x01] {
+            k = hmac::<D>(&k)
+                .chain_update(&v)
+                .chain_update([i])
+                .chain_update(secret)
+                .chain_update(msg_hash)
+                .finalize()
+                .into_bytes();
+            v = hmac::<D>(&k).chain_update(&v).finalize().into_bytes();
+        }
+
+        let nonce = loop {
+            let mut t = E::ScalarArray::zeroes();
+            let mut t_len = 0;
+            while t_len < rlen {
+                v = hmac::<D>(&k).chain_update(&v).finalize().into_bytes();
+                let chunk_len = v.len().min(rlen - t_len);
+                t.as_mut()[t_len..t_len + chunk_len].copy_from_slice(&v[..chunk_len]);
+                t_len += chunk_len;
+            }
+            let mut candidate = bits2int::<E>(&t.as_ref()[..rlen], qlen);
+            t.as_mut().zeroize();
+
+            let nonce = Scalar::from_be_bytes(&candidate)
+                .ok()
+                .and_then(NonZero::from_scalar);
+            candidate.as_mut().zeroize();
+            if let Some(nonce) = nonce {
+                break nonce;
+            }
+
+            k = hmac::<D>(&k)
+                .chain_update(&v)
+                .chain_update([0x00])
+                .finalize()
+                .into_bytes();
+            v = hmac::<D>(&k).chain_update(&v).finalize().into_bytes();
+        };
+
+        k.zeroize();
+        v.zeroize();
+        nonce
+    }
+}
+
+/// Instantiates HMAC keyed with `key`
+fn hmac<D: Digest + BlockSizeUser>(key: &[u8]) -> SimpleHmac<D> {
+    #[allow(clippy::expect_used)]
+    <SimpleHmac<D> as Mac>::new_from_slice(key).expect("HMAC accepts keys of any length")
+}
+
+/// `bits2int` as defined in [RFC 6979, Section 2.3.2](https://www.rfc-editor.org/rfc/rfc6979#section-2.3.2)
+///
+/// Takes leftmost `qlen` bits of `bytes` and interprets them as big-endian integer. Output
+/// is right-aligned in the scalar-sized bytes array.
+fn bits2int<E: Curve>(bytes: &[u8], qlen: usize) -> E::ScalarArray {
+    let mut out = E::ScalarArray::zeroes();
+    let out_len = out.as_ref().len();
+    let rlen = qlen.div_ceil(8);
+
+    if bytes.len() * 8 <= qlen {
+        out.as_mut()[out_len - bytes.len()..].copy_from_slice(bytes);
+        return out;
+    }
+
+    // Take leftmost `rlen` bytes and shift them right by the excess bits
+    let significant = &mut out.as_mut()[out_len - rlen..];
+    significant.copy_from_slice(&bytes[..rlen]);
+    let shift = rlen * 8 - qlen;
+    if shift > 0 {
+        for i in (0..rlen).rev() {
+            let carry = if i > 0 {
+                significant[i - 1] << (8 - shift)
+            } else {
+                0
+            };
+            significant[i] = (significant[i] >> shift) | carry;
+        }
+    }
+    out
+}
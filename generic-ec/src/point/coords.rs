@@ -1,3 +1,5 @@
+use subtle::Choice;
+
 use crate::as_raw::{AsRaw, TryFromRaw};
 use crate::coords::*;
 use crate::core::coords as coords_core;
@@ -27,6 +29,33 @@ where
     }
 }
 
+impl<E: Curve> Point<E>
+where
+    E: coords_core::HasAffineXAndParity,
+{
+    /// Indicates whether affine $y$ coordinate of the point is odd
+    ///
+    /// Returns `None` for [identity point](Self::zero) as it doesn't have affine coordinates.
+    ///
+    /// Unlike [`.x_and_parity()`](HasAffineXAndParity::x_and_parity), it doesn't encode $x$
+    /// coordinate, so it's cheaper when only parity is needed.
+    ///
+    /// ```rust
+    /// use generic_ec::{Point, Scalar, curves::Secp256k1};
+    /// use generic_ec::coords::HasAffineXAndParity;
+    /// # use rand_core::OsRng;
+    ///
+    /// let point = Point::<Secp256k1>::generator() * Scalar::random(&mut OsRng);
+    /// let (_x, parity) = point.x_and_parity().unwrap();
+    /// assert_eq!(point.y_is_odd().map(bool::from), Some(parity.is_odd()));
+    ///
+    /// assert!(Point::<Secp256k1>::zero().y_is_odd().is_none());
+    /// ```
+    pub fn y_is_odd(&self) -> Option<Choice> {
+        E::y_parity(self.as_raw()).map(|parity| Choice::from(u8::from(parity.is_odd())))
+    }
+}
+
 impl<E: Curve> HasAffineY<E> for Point<E>
 where
     E: coords_core::HasAffineY,
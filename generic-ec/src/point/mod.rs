@@ -2,13 +2,17 @@ use core::fmt;
 use core::hash::{self, Hash};
 use core::iter::Sum;
 
+use rand_core::RngCore;
 use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 
 use crate::{
     as_raw::{AsRaw, TryFromRaw},
-    core::*,
-    errors::InvalidPoint,
-    EncodedPoint, Generator,
+    core::{coords as coords_core, *},
+    errors::{
+        BufferTooSmall, InvalidPoint, InvalidPointReason, ParsePointError, ParsePointErrorReason,
+        PublicKeyError,
+    },
+    EncodedPoint, Generator, NonZero, Scalar,
 };
 
 use self::definition::Point;
@@ -24,6 +28,64 @@ impl<E: Curve> Point<E> {
         Generator::default()
     }
 
+    /// Multiplies curve generator at the scalar, returns $s \cdot G$
+    ///
+    /// It's the canonical way to do fixed-base multiplication by the generator. It's equivalent
+    /// to `Point::generator() * scalar`, and it's dispatched to generator multiplication
+    /// provided by the curve backend, which normally uses precomputed tables and is
+    /// significantly faster than multiplying an arbitrary point. If you need to multiply a
+    /// different fixed point many times, consider [`PrecomputedBase`](crate::PrecomputedBase).
+    ///
+    /// ```rust
+    /// use generic_ec::{Point, Scalar, curves::Secp256k1};
+    /// use rand::rngs::OsRng;
+    ///
+    /// let s = Scalar::<Secp256k1>::random(&mut OsRng);
+    /// assert_eq!(Point::mul_by_generator(&s), Point::generator() * s);
+    /// ```
+    pub fn mul_by_generator(scalar: &Scalar<E>) -> Self {
+        Point::generator() * scalar
+    }
+
+    /// Computes $s \cdot G + P$
+    ///
+    /// A common pattern, e.g. in Schnorr signature verification. Generator multiplication is
+    /// dispatched to the curve backend which normally uses precomputed tables (same as
+    /// [`mul_by_generator`](Self::mul_by_generator)), so it's potentially much faster than the
+    /// naive form where $G$ is multiplied as a regular point, e.g. when the generator was
+    /// received as a `Point<E>`. If you already have a [`GeneratorTable`](crate::GeneratorTable),
+    /// `table.mul(s) + p` is equally efficient.
+    ///
+    /// ```rust
+    /// use generic_ec::{Point, Scalar, curves::Secp256k1};
+    /// use rand::rngs::OsRng;
+    ///
+    /// let s = Scalar::<Secp256k1>::random(&mut OsRng);
+    /// let p = Point::generator() * Scalar::random(&mut OsRng);
+    /// assert_eq!(Point::mul_base_add(&s, &p), Point::generator() * s + p);
+    /// ```
+    pub fn mul_base_add(s: &Scalar<E>, p: &Point<E>) -> Self {
+        Self::mul_by_generator(s) + p
+    }
+
+    /// Generates random point
+    ///
+    /// Point is computed as $P = G \cdot s$ where $s$ is sampled via [`Scalar::random`]. As $s$
+    /// is uniform over non-zero scalars, $P$ is uniformly distributed over the prime-order
+    /// subgroup excluding identity, i.e. it never returns [zero point](Self::zero). Discrete
+    /// logarithm of $P$ is not exposed, but it's only as secret as randomness source is.
+    ///
+    /// ```rust
+    /// use generic_ec::{Point, curves::Secp256k1};
+    /// use rand::rngs::OsRng;
+    ///
+    /// let p = Point::<Secp256k1>::random(&mut OsRng);
+    /// assert!(!p.is_zero());
+    /// ```
+    pub fn random<R: RngCore>(rng: &mut R) -> Self {
+        Self::mul_by_generator(&Scalar::random(rng))
+    }
+
     /// Returns identity point $\O$ (sometimes called as _point at infinity_)
     ///
     /// Identity point has special properties:
@@ -61,6 +123,84 @@ impl<E: Curve> Point<E> {
         Zero::is_zero(self.as_raw())
     }
 
+    /// Checks whether two points are equal (in constant time)
+    ///
+    /// Same as [`ConstantTimeEq::ct_eq`], but doesn't require importing `subtle` trait. Note that
+    /// `==` operator ([`PartialEq`]) is delegated to the curve backend and is not guaranteed to be
+    /// constant time, so prefer `ct_eq` when comparing points derived from secret data.
+    ///
+    /// ```rust
+    /// use generic_ec::{Point, curves::Secp256k1};
+    ///
+    /// let g = Point::<Secp256k1>::generator().to_point();
+    /// assert!(bool::from(g.ct_eq(&g)));
+    /// assert!(!bool::from(g.ct_eq(&Point::zero())));
+    /// ```
+    pub fn ct_eq(&self, other: &Self) -> Choice {
+        ConstantTimeEq::ct_eq(self, other)
+    }
+
+    /// Returns $-P$ if `choice` is set, otherwise returns $P$
+    ///
+    /// Negation is done in constant time, i.e. it doesn't leak whether the point
+    /// was negated.
+    pub fn conditional_negate(&self, choice: Choice) -> Self {
+        Self::conditional_select(self, &-self, choice)
+    }
+
+    /// Returns `b` if `choice` is set, otherwise returns `a`
+    ///
+    /// Selection is done in constant time, i.e. it doesn't leak which point was selected.
+    /// Same as [`ConditionallySelectable::conditional_select`], but doesn't require importing
+    /// the trait.
+    ///
+    /// ```rust
+    /// use generic_ec::{Choice, Point, curves::Secp256k1};
+    ///
+    /// let a = Point::<Secp256k1>::zero();
+    /// let b = Point::<Secp256k1>::generator().to_point();
+    /// assert_eq!(Point::conditional_select(&a, &b, Choice::from(0)), a);
+    /// assert_eq!(Point::conditional_select(&a, &b, Choice::from(1)), b);
+    /// ```
+    pub fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        <Self as ConditionallySelectable>::conditional_select(a, b, choice)
+    }
+
+    /// Assigns `other` to `self` if `choice` is set, otherwise leaves `self` unchanged
+    ///
+    /// Assignment is done in constant time, i.e. it doesn't leak whether `self` was changed.
+    /// Same as [`ConditionallySelectable::conditional_assign`], but doesn't require importing
+    /// the trait.
+    pub fn conditional_assign(&mut self, other: &Self, choice: Choice) {
+        <Self as ConditionallySelectable>::conditional_assign(self, other, choice)
+    }
+
+    /// Returns `points[index]`, selected in constant time
+    ///
+    /// Performs a linear scan over the whole slice, conditionally assigning every element,
+    /// so it takes $O(n)$ time regardless of `index` and doesn't leak which point was selected.
+    /// Useful for private lookups, e.g. when index is derived from a secret.
+    ///
+    /// Returns [zero point](Self::zero) if `index` is out of bounds. Note that the length of
+    /// `points` is not hidden.
+    ///
+    /// ```rust
+    /// use generic_ec::{Point, Scalar, curves::Secp256k1};
+    ///
+    /// let points = [1u64, 2, 3].map(|i| Point::<Secp256k1>::generator() * Scalar::from(i));
+    /// let secret_index = 1;
+    /// let selected = Point::conditional_select_from_slice(&points, secret_index);
+    /// assert_eq!(selected, points[1]);
+    /// ```
+    pub fn conditional_select_from_slice(points: &[Self], index: u8) -> Self {
+        let index = u64::from(index);
+        let mut out = Self::zero();
+        for (i, point) in (0u64..).zip(points) {
+            out.conditional_assign(point, i.ct_eq(&index));
+        }
+        out
+    }
+
     /// Encodes a point as bytes
     ///
     /// Function can return both compressed and uncompressed bytes representation of a point.
@@ -91,11 +231,95 @@ impl<E: Curve> Point<E> {
         }
     }
 
+    /// Writes compressed encoding of the point into `out`
+    ///
+    /// Returns amount of bytes written, which equals to [`Point::serialized_len(true)`](Self::serialized_len).
+    /// Written bytes are the same as returned by [`Point::to_bytes(true)`](Self::to_bytes). Bytes
+    /// of `out` beyond the encoding are left untouched. Returns error if `out` is too small.
+    ///
+    /// ```rust
+    /// use generic_ec::{Point, curves::Secp256k1};
+    ///
+    /// let mut buffer = [0u8; 64];
+    /// let g = Point::<Secp256k1>::generator().to_point();
+    /// let len = g.encode_compressed_into(&mut buffer)?;
+    /// assert_eq!(&buffer[..len], g.to_bytes(true).as_bytes());
+    /// # Ok::<(), generic_ec::errors::BufferTooSmall>(())
+    /// ```
+    pub fn encode_compressed_into(&self, out: &mut [u8]) -> Result<usize, BufferTooSmall> {
+        copy_into(self.as_raw().to_bytes_compressed().as_ref(), out)
+    }
+
+    /// Writes uncompressed encoding of the point into `out`
+    ///
+    /// Returns amount of bytes written, which equals to [`Point::serialized_len(false)`](Self::serialized_len).
+    /// Written bytes are the same as returned by [`Point::to_bytes(false)`](Self::to_bytes).
+    /// Bytes of `out` beyond the encoding are left untouched. Returns error if `out` is too
+    /// small.
+    pub fn encode_uncompressed_into(&self, out: &mut [u8]) -> Result<usize, BufferTooSmall> {
+        copy_into(self.as_raw().to_bytes_uncompressed().as_ref(), out)
+    }
+
     /// Decodes a point from bytes
+    ///
+    /// Returned error indicates [a reason](InvalidPoint::reason) why the bytes were rejected.
+    ///
+    /// ```rust
+    /// use generic_ec::{Point, curves::Secp256k1, errors::InvalidPointReason};
+    ///
+    /// let err = Point::<Secp256k1>::from_bytes([1, 2, 3]).unwrap_err();
+    /// assert_eq!(err.reason(), InvalidPointReason::WrongLength);
+    /// ```
     pub fn from_bytes(bytes: impl AsRef<[u8]>) -> Result<Self, InvalidPoint> {
-        E::Point::decode(bytes.as_ref())
-            .and_then(Self::try_from_raw)
-            .ok_or(InvalidPoint)
+        let bytes = bytes.as_ref();
+        let Some(point) = E::Point::decode(bytes) else {
+            return Err(Self::decoding_error(bytes));
+        };
+        Self::try_from_raw_with_reason(point)
+    }
+
+    /// Decodes and validates a public key
+    ///
+    /// Public key is accepted if `bytes` is a valid encoding of a point, and the point is not
+    /// the identity. Points are guaranteed to be in the prime-order subgroup by construction,
+    /// so no further checks are needed. Use it whenever a public key is received from an
+    /// untrusted party: accepting identity as a public key is a common source of vulnerabilities.
+    ///
+    /// ```rust
+    /// use generic_ec::{Point, curves::Secp256k1, errors::PublicKeyError};
+    ///
+    /// let pk = Point::<Secp256k1>::generator().to_point();
+    /// let decoded = Point::<Secp256k1>::validate_public_key(&pk.to_bytes(true))?;
+    /// assert_eq!(pk, *decoded);
+    ///
+    /// let identity = Point::<Secp256k1>::zero().to_bytes(true);
+    /// assert!(matches!(
+    ///     Point::<Secp256k1>::validate_public_key(&identity),
+    ///     Err(PublicKeyError::Identity),
+    /// ));
+    /// assert!(matches!(
+    ///     Point::<Secp256k1>::validate_public_key([1, 2, 3]),
+    ///     Err(PublicKeyError::Malformed(_)),
+    /// ));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn validate_public_key(
+        bytes: impl AsRef<[u8]>,
+    ) -> Result<NonZero<Point<E>>, PublicKeyError> {
+        let point = Self::from_bytes(bytes).map_err(PublicKeyError::Malformed)?;
+        NonZero::from_point(point).ok_or(PublicKeyError::Identity)
+    }
+
+    /// Returns an error explaining why `bytes` couldn't be decoded as a point
+    fn decoding_error(bytes: &[u8]) -> InvalidPoint {
+        let reason = if bytes.len() == Self::serialized_len(true)
+            || bytes.len() == Self::serialized_len(false)
+        {
+            InvalidPointReason::MalformedEncoding
+        } else {
+            InvalidPointReason::WrongLength
+        };
+        InvalidPoint::new(reason)
     }
 
     /// Returns size of bytes buffer that can fit a serialized point
@@ -105,13 +329,514 @@ impl<E: Curve> Point<E> {
     /// exactly the serialization `p.to_bytes(compress)`.
     pub fn serialized_len(compressed: bool) -> usize {
         if compressed {
-            E::CompressedPointArray::zeroes().as_ref().len()
+            E::CompressedPointArray::LEN
+        } else {
+            E::UncompressedPointArray::LEN
+        }
+    }
+
+    /// Returns cofactor $h$ of the curve, encoded in big-endian without leading zeroes
+    ///
+    /// Prime-order curves have $h = 1$. Same as [`Curve::cofactor`].
+    pub fn cofactor() -> &'static [u8] {
+        E::cofactor()
+    }
+}
+
+/// Copies `bytes` into the beginning of `out`, returns amount of bytes written
+fn copy_into(bytes: &[u8], out: &mut [u8]) -> Result<usize, BufferTooSmall> {
+    let out_len = out.len();
+    let out = out
+        .get_mut(..bytes.len())
+        .ok_or(BufferTooSmall::new(bytes.len(), out_len))?;
+    out.copy_from_slice(bytes);
+    Ok(bytes.len())
+}
+
+/// Bytes encoding of a point expected by [`Point::from_bytes_with_format`]
+///
+/// Some encodings are ambiguous by length (e.g. 33 bytes compressed SEC1 point vs 32 bytes
+/// $x$-only point), so the format has to be stated explicitly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PointInputFormat {
+    /// SEC1 encoding
+    ///
+    /// Compressed point (`02`/`03` tag followed by $x$ coordinate) or uncompressed point
+    /// (`04` tag followed by $x$ and $y$ coordinates). Identity point is accepted either as
+    /// a single `00` byte (as defined in SEC1), or as zero bytes of the same length as
+    /// compressed or uncompressed point.
+    ///
+    /// For curves that don't use SEC1 encoding (e.g. Ed25519, BLS12-381), it's the curve own
+    /// compressed or uncompressed encoding, i.e. the same as [`Point::from_bytes`].
+    Sec1,
+    /// Affine $x$ coordinate only
+    ///
+    /// Point with even $y$ coordinate is chosen (the same convention as in BIP-340). Only
+    /// supported by curves using SEC1 encoding.
+    XOnly,
+    /// Affine $x$ and $y$ coordinates concatenated, without tag byte
+    ///
+    /// It's 64 bytes long for curves with 256 bits coordinates. Only supported by curves using
+    /// SEC1 encoding.
+    Raw64,
+}
+
+impl<E: Curve> Point<E> {
+    /// Decodes a point from bytes in specified format
+    ///
+    /// Unlike [`Point::from_bytes`], which relies on the curve backend to guess an encoding
+    /// from bytes length, this function accepts only encoding specified by `format`. Bytes
+    /// must have exactly the length of the encoding, otherwise [`InvalidPointReason::WrongLength`]
+    /// error is returned. [`PointInputFormat::XOnly`] and [`PointInputFormat::Raw64`] formats
+    /// are rejected with [`InvalidPointReason::MalformedEncoding`] for curves that don't use
+    /// SEC1 encoding.
+    ///
+    /// ```rust
+    /// use generic_ec::{Point, PointInputFormat, curves::Secp256r1};
+    ///
+    /// let point = Point::<Secp256r1>::generator().to_point();
+    /// let compressed = point.to_bytes(true);
+    ///
+    /// let decoded = Point::<Secp256r1>::from_bytes_with_format(&compressed, PointInputFormat::Sec1)?;
+    /// assert_eq!(point, decoded);
+    ///
+    /// // compressed point is not a valid x-only encoding
+    /// assert!(Point::<Secp256r1>::from_bytes_with_format(&compressed, PointInputFormat::XOnly).is_err());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn from_bytes_with_format(
+        bytes: impl AsRef<[u8]>,
+        format: PointInputFormat,
+    ) -> Result<Self, InvalidPoint> {
+        let bytes = bytes.as_ref();
+        let compressed_len = E::CompressedPointArray::LEN;
+        let uncompressed_len = E::UncompressedPointArray::LEN;
+        // SEC1 points are `tag || x` and `tag || x || y`
+        let is_sec1 = compressed_len > 1 && uncompressed_len == 2 * compressed_len - 1;
+        let coord_len = compressed_len.saturating_sub(1);
+
+        match format {
+            PointInputFormat::Sec1 if !is_sec1 => {
+                if bytes.len() != compressed_len && bytes.len() != uncompressed_len {
+                    return Err(InvalidPoint::new(InvalidPointReason::WrongLength));
+                }
+                Self::from_bytes(bytes)
+            }
+            PointInputFormat::Sec1 => {
+                let tag_matches_len = match bytes.first() {
+                    Some(0x02 | 0x03) => bytes.len() == compressed_len,
+                    Some(0x04) => bytes.len() == uncompressed_len,
+                    Some(0x00) => {
+                        if !bytes.iter().all(|b| *b == 0) {
+                            return Err(InvalidPoint::new(InvalidPointReason::MalformedEncoding));
+                        }
+                        [1, compressed_len, uncompressed_len].contains(&bytes.len())
+                    }
+                    Some(_) => {
+                        return Err(InvalidPoint::new(InvalidPointReason::MalformedEncoding))
+                    }
+                    None => false,
+                };
+                if !tag_matches_len {
+                    return Err(InvalidPoint::new(InvalidPointReason::WrongLength));
+                }
+                Self::from_bytes(bytes)
+            }
+            PointInputFormat::XOnly | PointInputFormat::Raw64 if !is_sec1 => {
+                Err(InvalidPoint::new(InvalidPointReason::MalformedEncoding))
+            }
+            PointInputFormat::XOnly => {
+                if bytes.len() != coord_len {
+                    return Err(InvalidPoint::new(InvalidPointReason::WrongLength));
+                }
+                // `02` tag stands for even y coordinate
+                let mut sec1 = E::CompressedPointArray::zeroes();
+                sec1.as_mut()[0] = 0x02;
+                sec1.as_mut()[1..].copy_from_slice(bytes);
+                Self::from_bytes(sec1).map_err(coords_not_on_curve)
+            }
+            PointInputFormat::Raw64 => {
+                if bytes.len() != 2 * coord_len {
+                    return Err(InvalidPoint::new(InvalidPointReason::WrongLength));
+                }
+                let mut sec1 = E::UncompressedPointArray::zeroes();
+                sec1.as_mut()[0] = 0x04;
+                sec1.as_mut()[1..].copy_from_slice(bytes);
+                Self::from_bytes(sec1).map_err(coords_not_on_curve)
+            }
+        }
+    }
+}
+
+/// Maps an error of decoding SEC1 point built from coordinates
+///
+/// Tag and length are known to be valid, so the only reason for the backend to reject the point
+/// is that coordinates don't define a point on the curve. Some backends report it as
+/// [`InvalidPointReason::MalformedEncoding`].
+fn coords_not_on_curve(err: InvalidPoint) -> InvalidPoint {
+    match err.reason() {
+        InvalidPointReason::HasTorsion => err,
+        _ => InvalidPoint::new(InvalidPointReason::NotOnCurve),
+    }
+}
+
+impl<E: Curve> Point<E>
+where
+    E: coords_core::HasAffineXAndParity,
+{
+    /// Indicates whether affine $y$ coordinate of the point is even
+    ///
+    /// Returns false for [identity point](Self::zero) as it doesn't have affine coordinates.
+    ///
+    /// Together with [`Scalar::conditional_negate`](crate::Scalar::conditional_negate), it can be
+    /// used to normalize a key pair so that public key has even $y$ coordinate (as required by
+    /// BIP-340):
+    ///
+    /// ```rust
+    /// use generic_ec::{Point, Scalar, curves::Secp256k1};
+    /// use rand::rngs::OsRng;
+    ///
+    /// let sk = Scalar::<Secp256k1>::random(&mut OsRng);
+    /// let pk = Point::generator() * sk;
+    ///
+    /// let sk = sk.conditional_negate(!pk.x_has_even_y());
+    /// let pk = Point::generator() * sk;
+    /// assert!(bool::from(pk.x_has_even_y()));
+    /// ```
+    pub fn x_has_even_y(&self) -> Choice {
+        self.y_is_odd()
+            .map(|is_odd| !is_odd)
+            .unwrap_or(Choice::from(0))
+    }
+}
+
+impl<E: Curve> Point<E> {
+    /// Checks whether two lists of points are equal element-wise
+    ///
+    /// Returns `false` if lists have different length.
+    ///
+    /// Points are compared via [`ConstantTimeEq`] which, for all supported curves, compares
+    /// points in projective coordinates without encoding them, so the check costs a few field
+    /// multiplications per point. It's also exact: unlike a check of a random linear combination
+    /// $\sum_i r_i (a_i - b_i) = \O$, it has no soundness error, and it's faster than such check,
+    /// as the latter requires a multiscalar multiplication of size $n$.
+    ///
+    /// All pairs of points are compared even if a mismatch is found earlier, and the results
+    /// are combined into a single [`Choice`], so the running time doesn't depend on where
+    /// (or whether) lists differ. It does depend on length of the lists.
+    ///
+    /// ```rust
+    /// use generic_ec::{Point, Scalar, curves::Secp256k1};
+    ///
+    /// let a = [Point::<Secp256k1>::generator().to_point(), Point::zero()];
+    /// let b = [Point::generator() * Scalar::one(), Point::zero()];
+    /// assert!(Point::batch_eq(&a, &b));
+    /// assert!(!Point::batch_eq(&a, &b[..1]));
+    /// ```
+    pub fn batch_eq(a: &[Point<E>], b: &[Point<E>]) -> bool {
+        if a.len() != b.len() {
+            return false;
+        }
+        a.iter()
+            .zip(b)
+            .fold(Choice::from(1), |eq, (a_i, b_i)| eq & a_i.ct_eq(b_i))
+            .into()
+    }
+
+    /// Returns indices at which two lists of points differ
+    ///
+    /// If lists have different length, indices which are present only in the longer list
+    /// are considered mismatching as well. Returns an empty vec if and only if
+    /// [`Point::batch_eq(a, b)`](Self::batch_eq) returns `true`.
+    ///
+    /// ```rust
+    /// use generic_ec::{Point, curves::Secp256k1};
+    ///
+    /// let g = Point::<Secp256k1>::generator().to_point();
+    /// let a = [g, g, Point::zero()];
+    /// let b = [g, Point::zero()];
+    /// assert_eq!(Point::batch_diff(&a, &b), [1, 2]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn batch_diff(a: &[Point<E>], b: &[Point<E>]) -> alloc::vec::Vec<usize> {
+        let common_len = a.len().min(b.len());
+        let longest_len = a.len().max(b.len());
+        a.iter()
+            .zip(b)
+            .enumerate()
+            .filter(|(_, (a_i, b_i))| a_i != b_i)
+            .map(|(i, _)| i)
+            .chain(common_len..longest_len)
+            .collect()
+    }
+
+    /// Computes linear combination of two points $a \cdot P + b \cdot Q$
+    ///
+    /// Shorthand for [`Scalar::multiscalar_mul`](crate::Scalar::multiscalar_mul) with two terms,
+    /// which is faster than computing `a * p + b * q` directly. A common use-case is signature
+    /// verification.
+    ///
+    /// Multiscalar multiplication is not constant time, so it must not be used with secret
+    /// scalars.
+    ///
+    /// ```rust
+    /// use generic_ec::{Point, Scalar, curves::Secp256k1};
+    /// # use rand_core::OsRng;
+    ///
+    /// let (a, b) = (Scalar::<Secp256k1>::random(&mut OsRng), Scalar::random(&mut OsRng));
+    /// let p = Point::generator() * Scalar::random(&mut OsRng);
+    /// let q = Point::generator() * Scalar::random(&mut OsRng);
+    ///
+    /// assert_eq!(Point::lincomb(&a, &p, &b, &q), a * p + b * q);
+    /// ```
+    pub fn lincomb(
+        a: impl AsRef<crate::Scalar<E>>,
+        p: impl AsRef<Point<E>>,
+        b: impl AsRef<crate::Scalar<E>>,
+        q: impl AsRef<Point<E>>,
+    ) -> Point<E> {
+        crate::Scalar::multiscalar_mul(
+            [(a.as_ref(), p.as_ref()), (b.as_ref(), q.as_ref())].into_iter(),
+        )
+    }
+
+    /// Computes sum of points $P_1 + \dots + P_n$
+    ///
+    /// Same as [`Sum`] implementation, but accepts anything that can be turned into iterator
+    /// of points or references to points.
+    ///
+    /// If the points need to be multiplied at scalars before summing them up, use
+    /// [`Point::weighted_sum`] instead: it's much faster than computing each
+    /// $s_i \cdot P_i$ separately and then summing them.
+    ///
+    /// ```rust
+    /// use generic_ec::{Point, Scalar, curves::Secp256k1};
+    /// # use rand_core::OsRng;
+    ///
+    /// let points = [
+    ///     Point::<Secp256k1>::generator() * Scalar::random(&mut OsRng),
+    ///     Point::generator() * Scalar::random(&mut OsRng),
+    /// ];
+    /// assert_eq!(Point::sum_iter(&points), points[0] + points[1]);
+    /// assert_eq!(Point::sum_iter(points), points[0] + points[1]);
+    /// assert_eq!(Point::<Secp256k1>::sum_iter(&[] as &[Point<_>]), Point::zero());
+    /// ```
+    pub fn sum_iter<P: AsRef<Point<E>>>(points: impl IntoIterator<Item = P>) -> Point<E> {
+        points
+            .into_iter()
+            .fold(Point::zero(), |acc, p| acc + p.as_ref())
+    }
+
+    /// Computes weighted sum of points $s_1 \cdot P_1 + \dots + s_n \cdot P_n$
+    ///
+    /// Alias for [`Scalar::multiscalar_mul`](crate::Scalar::multiscalar_mul) which accepts
+    /// anything that can be turned into an exact-size iterator of pairs `(scalar, point)`.
+    /// Uses [`Default`](crate::multiscalar::Default) multiscalar algorithm.
+    ///
+    /// Multiscalar multiplication is not constant time, so it must not be used with secret
+    /// scalars.
+    ///
+    /// ```rust
+    /// use generic_ec::{Point, Scalar, curves::Secp256k1};
+    /// # use rand_core::OsRng;
+    ///
+    /// let (a, b) = (Scalar::<Secp256k1>::random(&mut OsRng), Scalar::random(&mut OsRng));
+    /// let p = Point::generator() * Scalar::random(&mut OsRng);
+    /// let q = Point::generator() * Scalar::random(&mut OsRng);
+    ///
+    /// assert_eq!(Point::weighted_sum([(a, p), (b, q)]), a * p + b * q);
+    /// ```
+    pub fn weighted_sum<S, P, I>(scalar_points: I) -> Point<E>
+    where
+        S: AsRef<crate::Scalar<E>>,
+        P: AsRef<Point<E>>,
+        I: IntoIterator<Item = (S, P)>,
+        I::IntoIter: ExactSizeIterator,
+    {
+        crate::Scalar::multiscalar_mul(scalar_points.into_iter())
+    }
+}
+
+impl<E: Curve> Point<E> {
+    /// Same as [`TryFromRaw::try_from_raw`], but returns a reason why the point is invalid
+    ///
+    /// Unlike `try_from_raw`, it's not constant time.
+    pub(crate) fn try_from_raw_with_reason(point: E::Point) -> Result<Self, InvalidPoint> {
+        if !bool::from(point.is_on_curve()) {
+            return Err(InvalidPoint::new(InvalidPointReason::NotOnCurve));
+        }
+        if !bool::from(point.is_torsion_free()) {
+            return Err(InvalidPoint::new(InvalidPointReason::HasTorsion));
+        }
+        // Correctness: we checked that point is on curve and torsion free
+        Ok(Point::from_raw_unchecked(point))
+    }
+}
+
+impl<E: Curve> Point<E>
+where
+    E::Point: BatchEncoding,
+{
+    /// Encodes many points as bytes at once
+    ///
+    /// Output is the same as calling [`.to_bytes(compressed)`](Self::to_bytes) on each point, but
+    /// it's faster for curve backends that share a single field inversion (needed to convert
+    /// a point into affine coordinates) across the batch. Backends that can't do that encode
+    /// points one by one, in which case there's no speedup compared to encoding each point
+    /// separately. Whether a curve batch-normalizes points is an implementation detail of
+    /// its backend, see [`BatchEncoding`](crate::core::BatchEncoding).
+    ///
+    /// Note that there's no batch counterpart for decoding: decompressing a point takes a square
+    /// root rather than an inversion, and it can't be amortized across the batch.
+    ///
+    /// ```rust
+    /// use generic_ec::{Point, Scalar, curves::Secp256k1};
+    /// use rand::rngs::OsRng;
+    ///
+    /// let points = (0..100)
+    ///     .map(|_| Point::<Secp256k1>::generator() * Scalar::random(&mut OsRng))
+    ///     .collect::<Vec<_>>();
+    /// let encoded = Point::batch_to_bytes(&points, true);
+    ///
+    /// for (point, bytes) in points.iter().zip(&encoded) {
+    ///     assert_eq!(point.to_bytes(true).as_bytes(), bytes.as_bytes());
+    /// }
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn batch_to_bytes(
+        points: &[Point<E>],
+        compressed: bool,
+    ) -> alloc::vec::Vec<EncodedPoint<E>> {
+        use alloc::{vec, vec::Vec};
+
+        let raw = points.iter().map(|p| *p.as_raw()).collect::<Vec<_>>();
+        if compressed {
+            let mut out = vec![E::CompressedPointArray::zeroes(); raw.len()];
+            E::Point::to_bytes_compressed_batch(&raw, &mut out);
+            out.into_iter().map(EncodedPoint::new_compressed).collect()
         } else {
-            E::UncompressedPointArray::zeroes().as_ref().len()
+            let mut out = vec![E::UncompressedPointArray::zeroes(); raw.len()];
+            E::Point::to_bytes_uncompressed_batch(&raw, &mut out);
+            out.into_iter()
+                .map(EncodedPoint::new_uncompressed)
+                .collect()
         }
     }
 }
 
+impl<E: Curve + HashToCurve> Point<E> {
+    /// Derives a "nothing-up-my-sleeve" generator $H$ from a domain tag
+    ///
+    /// $H$ is obtained by hashing `domain` to the curve (see [`HashToCurve`]), so nobody knows
+    /// its discrete logarithm relative to $G$ (or any other point derived this way from
+    /// a different domain). It's useful, for instance, as a second base for Pedersen commitments.
+    /// Output is deterministic: the same `domain` always gives the same $H$. Returned point is
+    /// non-zero and torsion-free.
+    ///
+    /// Only available for curves that implement [`HashToCurve`].
+    ///
+    /// ```rust
+    /// use generic_ec::{Point, curves::Secp256k1};
+    ///
+    /// let h = Point::<Secp256k1>::hash_to_generator(b"my-protocol pedersen H");
+    /// assert_eq!(h, Point::<Secp256k1>::hash_to_generator(b"my-protocol pedersen H"));
+    /// assert_ne!(*h, Point::generator().to_point());
+    /// ```
+    pub fn hash_to_generator(domain: &[u8]) -> NonZero<Point<E>> {
+        let suite = E::HASH_TO_CURVE_SUITE.unwrap_or_default();
+        let dsts = [
+            b"GENERIC-EC-HASH-TO-GENERATOR-".as_slice(),
+            suite.as_bytes(),
+        ];
+        #[allow(clippy::expect_used)]
+        let point = E::hash_to_curve(&[domain], &dsts)
+            .expect("DST is not empty, so hash to curve never fails");
+        #[allow(clippy::expect_used)]
+        let point = Point::try_from_raw(point)
+            .expect("hash to curve always outputs a valid torsion-free point");
+        #[allow(clippy::expect_used)]
+        NonZero::from_point(point)
+            .expect("hash to curve outputs identity point with negligible probability")
+    }
+}
+
+impl<E: Curve> Point<E>
+where
+    E::Point: Cofactor,
+{
+    /// Clears cofactor of an arbitrary point on the curve
+    ///
+    /// Takes a point that may have a torsion component (e.g. decoded via
+    /// [`Decode`](crate::core::Decode)) and multiplies it at the cofactor, i.e. returns $h \cdot P$
+    /// which is always torsion-free. Returns error if the point is not on the curve.
+    ///
+    /// Note that the result may be the identity point: it happens when `point` consists only of
+    /// torsion component. Also note that $h \cdot P \ne P$ for curves with $h \ne 1$, even if
+    /// $P$ was torsion-free.
+    pub fn clear_cofactor(point: E::Point) -> Result<Self, InvalidPoint> {
+        if !bool::from(point.is_on_curve()) {
+            return Err(InvalidPoint::new(InvalidPointReason::NotOnCurve));
+        }
+        Self::try_from_raw_with_reason(point.mul_by_cofactor())
+    }
+
+    /// Checks whether an arbitrary point on the curve has small order
+    ///
+    /// Point $P$ has small order if $h \cdot P = \O$, i.e. it consists only of torsion component.
+    /// Identity point has small order.
+    ///
+    /// Note that any valid `Point<E>` is torsion-free by construction, so this function takes
+    /// a raw point (e.g. decoded via [`Decode`](crate::core::Decode)). Use
+    /// [`SmallFactor::is_torsion_free`] to check whether raw point has torsion component.
+    pub fn raw_is_small_order(point: &E::Point) -> Choice {
+        Zero::is_zero(&point.mul_by_cofactor())
+    }
+
+    /// Decodes a point from bytes, allowing it to have torsion component
+    ///
+    /// Unlike [`Point::from_bytes`], it doesn't reject points with torsion component. Returns
+    /// prime-order component of the point, and a flag indicating whether the point had torsion
+    /// component. If the point has [small order](Self::raw_is_small_order), its prime-order
+    /// component is the identity point.
+    ///
+    /// Prime-order component is computed as $(h^{-1} \bmod q) \cdot (h \cdot P)$, which is
+    /// more expensive than regular decoding for curves with $h \ne 1$.
+    ///
+    /// ```rust
+    /// use generic_ec::{Point, curves::Ed25519};
+    ///
+    /// // Point of order 2: (0, -1)
+    /// let mut bytes = [0xff; 32];
+    /// bytes[0] = 0xec;
+    /// bytes[31] = 0x7f;
+    /// assert!(Point::<Ed25519>::from_bytes(&bytes).is_err());
+    ///
+    /// let (point, has_torsion) = Point::<Ed25519>::from_bytes_allowing_torsion(&bytes)?;
+    /// assert!(point.is_zero());
+    /// assert!(bool::from(has_torsion));
+    /// # Ok::<(), generic_ec::errors::InvalidPoint>(())
+    /// ```
+    pub fn from_bytes_allowing_torsion(
+        bytes: impl AsRef<[u8]>,
+    ) -> Result<(Self, Choice), InvalidPoint> {
+        let bytes = bytes.as_ref();
+        let Some(point) = E::Point::decode(bytes) else {
+            return Err(Self::decoding_error(bytes));
+        };
+        let has_torsion = !point.is_torsion_free();
+
+        let cleared = Self::clear_cofactor(point)?;
+        if E::is_prime_order() {
+            return Ok((cleared, has_torsion));
+        }
+        #[allow(clippy::expect_used)]
+        let cofactor_inv = crate::Scalar::<E>::from_be_bytes_mod_order(E::cofactor())
+            .invert()
+            .expect("cofactor is always smaller than group order, so it's invertible");
+        Ok((cleared * cofactor_inv, has_torsion))
+    }
+}
+
 impl<E: Curve> TryFromRaw for Point<E> {
     fn ct_try_from_raw(point: E::Point) -> CtOption<Self> {
         let is_on_curve = point.is_on_curve();
@@ -125,6 +850,45 @@ impl<E: Curve> TryFromRaw for Point<E> {
     }
 }
 
+/// Decodes a point from bytes, same as [`Point::from_bytes`]
+///
+/// ```rust
+/// use generic_ec::{Point, curves::Secp256k1};
+///
+/// let g = Point::<Secp256k1>::generator().to_point();
+/// let bytes = g.to_bytes(true);
+/// let decoded = Point::<Secp256k1>::try_from(bytes.as_bytes())?;
+/// assert_eq!(g, decoded);
+/// # Ok::<(), generic_ec::errors::InvalidPoint>(())
+/// ```
+impl<E: Curve> TryFrom<&[u8]> for Point<E> {
+    type Error = InvalidPoint;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        Self::from_bytes(bytes)
+    }
+}
+
+/// Decodes a point from bytes, same as [`Point::from_bytes`]
+///
+/// ```rust
+/// use generic_ec::{Point, curves::Secp256k1};
+///
+/// let g = Point::<Secp256k1>::generator().to_point();
+/// let mut bytes = [0u8; 33];
+/// bytes.copy_from_slice(&g.to_bytes(true));
+/// let decoded = Point::<Secp256k1>::try_from(bytes)?;
+/// assert_eq!(g, decoded);
+/// # Ok::<(), generic_ec::errors::InvalidPoint>(())
+/// ```
+impl<E: Curve, const N: usize> TryFrom<[u8; N]> for Point<E> {
+    type Error = InvalidPoint;
+
+    fn try_from(bytes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::from_bytes(bytes)
+    }
+}
+
 impl<E: Curve> ConditionallySelectable for Point<E> {
     fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
         // Correctness: both `a` and `b` have to be valid points by construction
@@ -183,6 +947,111 @@ impl<E: Curve> fmt::Debug for Point<E> {
         s.finish()
     }
 }
+
+/// Formats point as hex string of its compressed encoding
+///
+/// Output is the hex encoding of [`Point::to_bytes(true)`](Point::to_bytes), same as `value`
+/// field shown by `Debug` implementation. Alternate flag adds `0x` prefix.
+///
+/// ```rust
+/// use generic_ec::{Point, curves::Secp256k1};
+///
+/// // Identity point is encoded as all zeroes
+/// assert_eq!(Point::<Secp256k1>::zero().to_string(), "00".repeat(33));
+/// assert_eq!(
+///     Point::<Secp256k1>::generator().to_point().to_string(),
+///     "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+/// );
+/// ```
+impl<E: Curve> fmt::Display for Point<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        crate::hex_str::fmt_hex(self.to_bytes(true).as_bytes(), f, false)
+    }
+}
+
+/// Formats point as lower case hex string of its compressed encoding
+///
+/// Output is the same as of [`Display`](fmt::Display) implementation. Alternate flag adds `0x`
+/// prefix.
+///
+/// ```rust
+/// use generic_ec::{Point, curves::Secp256k1};
+///
+/// let g = Point::<Secp256k1>::generator().to_point();
+/// assert_eq!(
+///     format!("{g:#x}"),
+///     "0x0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+/// );
+/// ```
+impl<E: Curve> fmt::LowerHex for Point<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        crate::hex_str::fmt_hex(self.to_bytes(true).as_bytes(), f, false)
+    }
+}
+
+/// Formats point as upper case hex string of its compressed encoding
+///
+/// Alternate flag adds `0x` prefix.
+///
+/// ```rust
+/// use generic_ec::{Point, curves::Secp256k1};
+///
+/// let g = Point::<Secp256k1>::generator().to_point();
+/// assert_eq!(
+///     format!("{g:X}"),
+///     "0279BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798",
+/// );
+/// ```
+impl<E: Curve> fmt::UpperHex for Point<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        crate::hex_str::fmt_hex(self.to_bytes(true).as_bytes(), f, true)
+    }
+}
+
+/// Parses point from hex string
+///
+/// Decoded bytes are parsed via [`Point::from_bytes`], so both compressed and uncompressed
+/// encodings are accepted. Both lower and upper case hex digits are accepted, no `0x` prefix
+/// is expected.
+///
+/// ```rust
+/// use generic_ec::{Point, curves::Secp256k1};
+///
+/// let g: Point<Secp256k1> =
+///     "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798".parse()?;
+/// assert_eq!(g, Point::generator().to_point());
+/// assert_eq!("00".repeat(33).parse::<Point<Secp256k1>>()?, Point::zero());
+/// # Ok::<(), generic_ec::errors::ParsePointError>(())
+/// ```
+impl<E: Curve> core::str::FromStr for Point<E> {
+    type Err = ParsePointError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.as_bytes();
+        if !s.len().is_multiple_of(2) {
+            return Err(ParsePointError::new(ParsePointErrorReason::OddLength));
+        }
+
+        let mut bytes = E::UncompressedPointArray::zeroes();
+        let Some(bytes) = bytes.as_mut().get_mut(..s.len() / 2) else {
+            return Err(ParsePointError::new(ParsePointErrorReason::InvalidPoint(
+                InvalidPointReason::WrongLength,
+            )));
+        };
+        crate::hex_str::decode_to_slice(s, bytes)
+            .map_err(|_| ParsePointError::new(ParsePointErrorReason::InvalidCharacter))?;
+
+        Point::from_bytes(bytes)
+            .map_err(|err| ParsePointError::new(ParsePointErrorReason::InvalidPoint(err.reason())))
+    }
+}
+
+/// Hash is computed over canonical compressed encoding of the point
+///
+/// Point is hashed by writing [`Point::to_bytes(true)`](Point::to_bytes) into the hasher via
+/// a single [`Hasher::write`](hash::Hasher::write) call. Compressed encoding is canonical (each
+/// point has exactly one encoding) and doesn't depend on the platform, so given the same `Hasher`,
+/// the hash value is stable across crate versions and platforms.
 #[allow(clippy::derived_hash_with_manual_eq)]
 impl<E: Curve> Hash for Point<E> {
     fn hash<H: hash::Hasher>(&self, state: &mut H) {
@@ -220,6 +1089,12 @@ impl<E: Curve> crate::traits::Zero for Point<E> {
     }
 }
 
+impl<E: Curve> crate::traits::Samplable for Point<E> {
+    fn random<R: RngCore>(rng: &mut R) -> Self {
+        Point::random(rng)
+    }
+}
+
 #[cfg(feature = "udigest")]
 impl<E: Curve> udigest::Digestable for Point<E> {
     fn unambiguously_encode<B>(&self, encoder: udigest::encoding::EncodeValue<B>)
@@ -232,3 +1107,51 @@ impl<E: Curve> udigest::Digestable for Point<E> {
         s.finish();
     }
 }
+
+/// Generates a point for fuzzing
+///
+/// Generated point is always a valid point on the curve: it's either a [zero point](Point::zero)
+/// (with $1/16$ chance), or $P = G \cdot s$ where $s$ is an [arbitrary](arbitrary::Arbitrary)
+/// scalar. Note that it can't be used to fuzz handling of invalid points, use
+/// [`Point::from_bytes`] over arbitrary bytes for that.
+#[cfg(feature = "arbitrary")]
+impl<'a, E: Curve> arbitrary::Arbitrary<'a> for Point<E> {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        if u.ratio(1u8, 16)? {
+            return Ok(Self::zero());
+        }
+        let scalar = Scalar::<E>::arbitrary(u)?;
+        Ok(Self::generator() * scalar)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        arbitrary::size_hint::and(
+            <u8 as arbitrary::Arbitrary>::size_hint(depth),
+            <Scalar<E> as arbitrary::Arbitrary>::size_hint(depth),
+        )
+    }
+}
+
+#[cfg(feature = "curve-ed25519")]
+#[cfg_attr(docsrs, doc(cfg(feature = "curve-ed25519")))]
+impl Point<crate::curves::Ed25519> {
+    /// Returns Montgomery $u$ coordinate of the point, as used in X25519
+    ///
+    /// Conversion is done via birational map $u = (1 + y) / (1 - y)$. Note that it loses sign of
+    /// $x$ coordinate: $P$ and $-P$ have the same $u$ coordinate, so the point can't be recovered
+    /// from it unambiguously. Identity point is mapped to $u = 0$.
+    ///
+    /// ```rust
+    /// use generic_ec::{Point, curves::Ed25519};
+    ///
+    /// // Generator is mapped to X25519 base point u = 9 (RFC 7748)
+    /// let g = Point::<Ed25519>::generator().to_point();
+    /// let mut expected = [0u8; 32];
+    /// expected[0] = 9;
+    /// assert_eq!(g.to_montgomery(), expected);
+    /// assert_eq!((-g).to_montgomery(), expected);
+    /// ```
+    pub fn to_montgomery(&self) -> [u8; 32] {
+        self.as_raw().to_montgomery()
+    }
+}
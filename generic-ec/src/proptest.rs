@@ -0,0 +1,48 @@
+//! [`proptest`](mod@::proptest) strategies for points and scalars
+//!
+//! Provides strategies generating uniformly random valid scalars and points, which can be used
+//! for property testing of protocols built on top of this crate. When a test fails, generated
+//! values are shrunk towards simple ones: [`scalar`] shrinks towards zero, [`non_zero_scalar`]
+//! towards one, and [`point`] towards identity point.
+//!
+//! Requires `proptest` feature to be enabled.
+//!
+//! ## Example
+//! ```rust
+//! use generic_ec::{curves::Secp256k1, proptest::{point, scalar}};
+//! use proptest::prelude::*;
+//!
+//! proptest!(|(a in scalar::<Secp256k1>(), b in scalar::<Secp256k1>(), p in point::<Secp256k1>())| {
+//!     prop_assert_eq!(p * (a + b), p * a + p * b);
+//! });
+//! ```
+
+use ::proptest::{collection, prelude::*};
+
+use crate::{Curve, NonZero, Point, Scalar};
+
+/// Strategy generating uniformly random scalars
+///
+/// Scalar is obtained by reducing a big-endian integer twice as long as curve order, so the
+/// bias is negligible. Shrinks towards [zero](Scalar::zero).
+pub fn scalar<E: Curve>() -> impl Strategy<Value = Scalar<E>> {
+    collection::vec(any::<u8>(), 2 * Scalar::<E>::serialized_len())
+        .prop_map(Scalar::from_be_bytes_mod_order)
+}
+
+/// Strategy generating uniformly random non-zero scalars
+///
+/// Shrinks towards [one](Scalar::one).
+pub fn non_zero_scalar<E: Curve>() -> impl Strategy<Value = NonZero<Scalar<E>>> {
+    scalar::<E>().prop_filter_map("scalar is zero", |s| {
+        NonZero::from_scalar(s + Scalar::one())
+    })
+}
+
+/// Strategy generating uniformly random points
+///
+/// Point is obtained as $P = G \cdot s$ where $s$ is generated by [`scalar`] strategy, so it's
+/// always a valid point on the curve. Shrinks towards [identity point](Point::zero).
+pub fn point<E: Curve>() -> impl Strategy<Value = Point<E>> {
+    scalar::<E>().prop_map(|s| Point::generator() * s)
+}
@@ -5,18 +5,18 @@ use rand_core::RngCore;
 use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 use zeroize::Zeroize;
 
-use crate::NonZero;
 use crate::{
     as_raw::{AsRaw, FromRaw},
     core::*,
     encoded::EncodedScalar,
-    errors::InvalidScalar,
+    errors::{BrokenRng, InvalidScalar, ParseScalarError, ParseScalarErrorReason},
+    NonZero, Point,
 };
 
 /// Scalar modulo curve `E` group order
 ///
 /// Scalar is an integer modulo curve `E` group order.
-#[derive(Copy, Clone, PartialEq, Eq, Default)]
+#[derive(Copy, Clone, Eq, Default)]
 pub struct Scalar<E: Curve>(E::Scalar);
 
 impl<E: Curve> Scalar<E> {
@@ -39,6 +39,23 @@ impl<E: Curve> Scalar<E> {
         Zero::is_zero(self.as_raw()).into()
     }
 
+    /// Checks whether two scalars are equal (in constant time)
+    ///
+    /// Same as [`ConstantTimeEq::ct_eq`], but doesn't require importing `subtle` trait. `==`
+    /// operator ([`PartialEq`]) is constant time as well, but `ct_eq` returns [`Choice`] which can
+    /// be used in further constant-time computations without branching.
+    ///
+    /// ```rust
+    /// use generic_ec::{Scalar, curves::Secp256k1};
+    ///
+    /// let one = Scalar::<Secp256k1>::one();
+    /// assert!(bool::from(one.ct_eq(&one)));
+    /// assert!(!bool::from(one.ct_eq(&Scalar::zero())));
+    /// ```
+    pub fn ct_eq(&self, other: &Self) -> Choice {
+        ConstantTimeEq::ct_eq(self, other)
+    }
+
     /// Returns scalar $S = 1$
     ///
     /// ```rust
@@ -52,6 +69,23 @@ impl<E: Curve> Scalar<E> {
         Self::from_raw(E::Scalar::one())
     }
 
+    /// Multiplies curve generator at the scalar, returns $S \cdot G$
+    ///
+    /// Same as `self * Point::generator()`. Takes advantage of generator multiplication
+    /// being faster than multiplication of an arbitrary point, so it should be preferred
+    /// over `Point::generator().to_point() * self`.
+    ///
+    /// ```rust
+    /// use generic_ec::{Point, Scalar, curves::Secp256k1};
+    /// use rand::rngs::OsRng;
+    ///
+    /// let s = Scalar::<Secp256k1>::random(&mut OsRng);
+    /// assert_eq!(s.mul_generator(), Point::generator() * s);
+    /// ```
+    pub fn mul_generator(&self) -> Point<E> {
+        Point::mul_by_generator(self)
+    }
+
     /// Returns scalar inverse $S^{-1}$
     ///
     /// Inverse of scalar $S$ is a scalar $S^{-1}$ such as $S \cdot S^{-1} = 1$. Inverse doesn't
@@ -81,6 +115,160 @@ impl<E: Curve> Scalar<E> {
         inv.map(Self::from_raw)
     }
 
+    /// Returns scalar inverse $S^{-1}$ if $S \ne 0$, or zero otherwise (constant time)
+    ///
+    /// Unlike [`Scalar::ct_invert`], output doesn't need to be unwrapped, which is convenient
+    /// in constant-time code. Note that zero is not an inverse of zero: it's the caller's
+    /// responsibility to make sure that either scalar is non-zero, or that zero output is
+    /// handled correctly by the algorithm.
+    ///
+    /// ```rust
+    /// use generic_ec::{Scalar, curves::Secp256k1};
+    /// use rand::rngs::OsRng;
+    ///
+    /// let s = Scalar::<Secp256k1>::random(&mut OsRng);
+    /// assert_eq!(s * s.invert_or_zero(), Scalar::one());
+    /// assert_eq!(Scalar::<Secp256k1>::zero().invert_or_zero(), Scalar::zero());
+    /// ```
+    pub fn invert_or_zero(&self) -> Self {
+        self.ct_invert().unwrap_or(Self::zero())
+    }
+
+    /// Returns $S^k$
+    ///
+    /// Uses fixed-window exponentiation over [radix 16 representation](Self::as_radix16_be)
+    /// of the exponent. It's constant time with respect to the exponent $k$. Returns
+    /// [`Scalar::one()`](Self::one) if $k = 0$.
+    ///
+    /// ```rust
+    /// use generic_ec::{Scalar, curves::Secp256k1};
+    /// use rand::rngs::OsRng;
+    ///
+    /// let s = Scalar::<Secp256k1>::random(&mut OsRng);
+    /// assert_eq!(s.pow(&Scalar::from(3)), s * s * s);
+    /// assert_eq!(s.pow(&Scalar::zero()), Scalar::one());
+    /// ```
+    pub fn pow(&self, exp: &Scalar<E>) -> Self {
+        // table[i] = S^i
+        let mut table = [Scalar::<E>::one(); 16];
+        for i in 1..table.len() {
+            table[i] = table[i - 1] * self;
+        }
+
+        let mut result = Scalar::one();
+        for digit in exp.as_radix16_be() {
+            for _ in 0..4 {
+                result = result * result;
+            }
+
+            let mut term = table[0];
+            for (i, table_i) in (0u8..).zip(&table).skip(1) {
+                term.conditional_assign(table_i, i.ct_eq(&digit));
+            }
+            result *= term;
+        }
+        result
+    }
+
+    /// Returns $S^k$
+    ///
+    /// Same as [`Scalar::pow`] but takes exponent as `u64`
+    pub fn pow_u64(&self, exp: u64) -> Self {
+        self.pow(&Scalar::from(exp))
+    }
+
+    /// Returns $-S$ if `choice` is set, otherwise returns $S$
+    ///
+    /// Negation is done in constant time, i.e. it doesn't leak whether the scalar
+    /// was negated.
+    ///
+    /// ```rust
+    /// use generic_ec::{Scalar, curves::Secp256k1};
+    /// use rand::rngs::OsRng;
+    /// use generic_ec::Choice;
+    ///
+    /// let s = Scalar::<Secp256k1>::random(&mut OsRng);
+    /// assert_eq!(s.conditional_negate(Choice::from(1)), -s);
+    /// assert_eq!(s.conditional_negate(Choice::from(0)), s);
+    /// ```
+    pub fn conditional_negate(&self, choice: Choice) -> Self {
+        Self::conditional_select(self, &-self, choice)
+    }
+
+    /// Returns `b` if `choice` is set, otherwise returns `a`
+    ///
+    /// Selection is done in constant time, i.e. it doesn't leak which scalar was selected.
+    /// Same as [`ConditionallySelectable::conditional_select`], but doesn't require importing
+    /// the trait.
+    ///
+    /// ```rust
+    /// use generic_ec::{Choice, Scalar, curves::Secp256k1};
+    ///
+    /// let a = Scalar::<Secp256k1>::from(1);
+    /// let b = Scalar::<Secp256k1>::from(2);
+    /// assert_eq!(Scalar::conditional_select(&a, &b, Choice::from(0)), a);
+    /// assert_eq!(Scalar::conditional_select(&a, &b, Choice::from(1)), b);
+    /// ```
+    pub fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        <Self as ConditionallySelectable>::conditional_select(a, b, choice)
+    }
+
+    /// Assigns `other` to `self` if `choice` is set, otherwise leaves `self` unchanged
+    ///
+    /// Assignment is done in constant time, i.e. it doesn't leak whether `self` was changed.
+    /// Same as [`ConditionallySelectable::conditional_assign`], but doesn't require importing
+    /// the trait.
+    pub fn conditional_assign(&mut self, other: &Self, choice: Choice) {
+        <Self as ConditionallySelectable>::conditional_assign(self, other, choice)
+    }
+
+    /// Checks whether scalar is in the upper half of $[0, q)$, i.e. $s > \lfloor q/2 \rfloor$
+    ///
+    /// Comparison is done in constant time. Useful for low-S normalization of signatures,
+    /// see [`.normalize_s()`](Self::normalize_s).
+    ///
+    /// ```rust
+    /// use generic_ec::{Scalar, curves::Secp256k1};
+    ///
+    /// assert!(!bool::from(Scalar::<Secp256k1>::one().is_high()));
+    /// assert!(bool::from((-Scalar::<Secp256k1>::one()).is_high()));
+    /// ```
+    pub fn is_high(&self) -> Choice {
+        let s = self.to_be_bytes();
+        let q = E::group_order_be_bytes();
+        let (s, q) = (s.as_ref(), q.as_ref());
+        debug_assert_eq!(s.len(), q.len());
+
+        // Computes $\lfloor q/2 \rfloor - s$ byte by byte, keeping track of the borrow only.
+        // Subtraction underflows if, and only if, $s > \lfloor q/2 \rfloor$.
+        let mut borrow = 0u16;
+        for i in (0..q.len()).rev() {
+            let carried_bit = if i > 0 { q[i - 1] << 7 } else { 0 };
+            let half_q_i = (q[i] >> 1) | carried_bit;
+            let diff = u16::from(half_q_i)
+                .wrapping_sub(u16::from(s[i]))
+                .wrapping_sub(borrow);
+            borrow = diff >> 15;
+        }
+        Choice::from(borrow as u8)
+    }
+
+    /// Returns $-s$ if scalar [is high](Self::is_high), otherwise returns $s$
+    ///
+    /// Output is always $\le \lfloor q/2 \rfloor$. It's used to bring a signature into canonical
+    /// low-S form. Normalization is done in constant time.
+    ///
+    /// ```rust
+    /// use generic_ec::{Scalar, curves::Secp256k1};
+    ///
+    /// let s = -Scalar::<Secp256k1>::from(2);
+    /// assert_eq!(s.normalize_s(), Scalar::from(2));
+    /// assert_eq!(s.normalize_s().normalize_s(), Scalar::from(2));
+    /// ```
+    pub fn normalize_s(self) -> Self {
+        self.conditional_negate(self.is_high())
+    }
+
     /// Encodes scalar as bytes in big-endian order
     ///
     /// ```rust
@@ -106,6 +294,10 @@ impl<E: Curve> Scalar<E> {
     ///
     /// Returns error if encoded integer is larger than group order.
     ///
+    /// Input may be shorter than [`Scalar::serialized_len()`](Self::serialized_len), or longer
+    /// than it as long as extra leading bytes are zeroes (e.g. ASN.1 DER integers may have
+    /// leading `0x00` byte).
+    ///
     /// ```rust
     /// use generic_ec::{Scalar, curves::Secp256k1};
     /// use rand::rngs::OsRng;
@@ -114,14 +306,21 @@ impl<E: Curve> Scalar<E> {
     /// let s_bytes = s.to_be_bytes();
     /// let s_decoded = Scalar::from_be_bytes(&s_bytes)?;
     /// assert_eq!(s, s_decoded);
+    ///
+    /// let s_bytes_padded = [&[0u8][..], &s_bytes].concat();
+    /// assert_eq!(s, Scalar::from_be_bytes(&s_bytes_padded)?);
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn from_be_bytes(bytes: impl AsRef<[u8]>) -> Result<Self, InvalidScalar> {
-        let bytes = bytes.as_ref();
+        let mut bytes = bytes.as_ref();
         let mut bytes_array = E::ScalarArray::zeroes();
         let bytes_array_len = bytes_array.as_ref().len();
         if bytes_array_len < bytes.len() {
-            return Err(InvalidScalar);
+            let (extra, significant) = bytes.split_at(bytes.len() - bytes_array_len);
+            if extra.iter().any(|b| *b != 0) {
+                return Err(InvalidScalar);
+            }
+            bytes = significant;
         }
         bytes_array.as_mut()[bytes_array_len - bytes.len()..].copy_from_slice(bytes);
 
@@ -132,12 +331,20 @@ impl<E: Curve> Scalar<E> {
     /// Decodes scalar from its representation as bytes in little-endian order
     ///
     /// Returns error if encoded integer is larger than group order.
+    ///
+    /// Similarly to [`Scalar::from_be_bytes`], input may be longer than
+    /// [`Scalar::serialized_len()`](Self::serialized_len) as long as extra trailing (i.e. most
+    /// significant) bytes are zeroes.
     pub fn from_le_bytes(bytes: impl AsRef<[u8]>) -> Result<Self, InvalidScalar> {
-        let bytes = bytes.as_ref();
+        let mut bytes = bytes.as_ref();
         let mut bytes_array = E::ScalarArray::zeroes();
         let bytes_array_len = bytes_array.as_ref().len();
         if bytes_array_len < bytes.len() {
-            return Err(InvalidScalar);
+            let (significant, extra) = bytes.split_at(bytes_array_len);
+            if extra.iter().any(|b| *b != 0) {
+                return Err(InvalidScalar);
+            }
+            bytes = significant;
         }
         bytes_array.as_mut()[..bytes.len()].copy_from_slice(bytes);
 
@@ -146,17 +353,82 @@ impl<E: Curve> Scalar<E> {
     }
 
     /// Interprets provided bytes as integer $i$ in big-endian order, returns scalar $s = i \mod q$
+    ///
+    /// Bytes may have any length. Curve backends reduce input by [`Reduce<N>`](crate::traits::Reduce)
+    /// when it's implemented for the input length `N` (all curves implement it for `N = 32`,
+    /// most of them also for `N = 64`), and fall back to reducing the input chunk-by-chunk
+    /// otherwise.
+    ///
+    /// Use it to map uniformly distributed bytes to a scalar: when input is at least
+    /// [`Scalar::serialized_len()`](Self::serialized_len) + 16 bytes long (e.g. 64 bytes for
+    /// 256 bits curves), output is statistically close to uniform.
+    ///
+    /// ```rust
+    /// use generic_ec::{Scalar, curves::Secp256k1};
+    /// use sha2::{Digest, Sha512};
+    ///
+    /// let uniform_bytes: [u8; 64] = Sha512::digest(b"some data").into();
+    /// let scalar = Scalar::<Secp256k1>::from_be_bytes_mod_order(uniform_bytes);
+    /// ```
+    #[doc(alias("from_be_bytes_reduce", "reduce_from_be_array"))]
     pub fn from_be_bytes_mod_order(bytes: impl AsRef<[u8]>) -> Self {
         let scalar = E::Scalar::from_be_bytes_mod_order(bytes.as_ref());
         Self::from_raw(scalar)
     }
 
     /// Interprets provided bytes as integer $i$ in little-endian order, returns scalar $s = i \mod q$
+    ///
+    /// Same as [`Scalar::from_be_bytes_mod_order`], but input is in little-endian order.
+    #[doc(alias("from_le_bytes_reduce", "reduce_from_le_array"))]
     pub fn from_le_bytes_mod_order(bytes: impl AsRef<[u8]>) -> Self {
         let scalar = E::Scalar::from_le_bytes_mod_order(bytes.as_ref());
         Self::from_raw(scalar)
     }
 
+    /// Interprets `digits` as integer $i$ in radix $2^{64}$ from most to least significant
+    /// digit, returns scalar $s = i \mod q$
+    ///
+    /// Useful to convert big integers from other libraries which expose their `u64` limbs.
+    /// Integer may have any size. Note that, **unlike** [`From`] implementations for primitive
+    /// integers which assume that the integer fits into a scalar, the integer is reduced
+    /// modulo curve order $q$.
+    ///
+    /// ```rust
+    /// use generic_ec::{Scalar, curves::Secp256k1};
+    ///
+    /// // 2^64 + 1
+    /// let s = Scalar::<Secp256k1>::from_be_digits(&[1, 1]);
+    /// assert_eq!(s, Scalar::from(u128::from(u64::MAX) + 2));
+    ///
+    /// // Integers larger than group order are reduced
+    /// let q_plus_one = Scalar::<Secp256k1>::from_be_digits(&[
+    ///     0xFFFFFFFFFFFFFFFF,
+    ///     0xFFFFFFFFFFFFFFFE,
+    ///     0xBAAEDCE6AF48A03B,
+    ///     0xBFD25E8CD0364142,
+    /// ]);
+    /// assert_eq!(q_plus_one, Scalar::one());
+    /// ```
+    pub fn from_be_digits(digits: &[u64]) -> Self {
+        let radix = Self::from(u128::from(u64::MAX) + 1);
+        digits
+            .iter()
+            .fold(Self::zero(), |acc, digit| acc * radix + Self::from(*digit))
+    }
+
+    /// Interprets `digits` as integer $i$ in radix $2^{64}$ from least to most significant
+    /// digit, returns scalar $s = i \mod q$
+    ///
+    /// Same as [`Scalar::from_be_digits`], but digits are in reverse order. Integer is reduced
+    /// modulo curve order.
+    pub fn from_le_digits(digits: &[u64]) -> Self {
+        let radix = Self::from(u128::from(u64::MAX) + 1);
+        digits
+            .iter()
+            .rev()
+            .fold(Self::zero(), |acc, digit| acc * radix + Self::from(*digit))
+    }
+
     /// Generates random non-zero scalar
     ///
     /// Algorithm is based on rejection sampling: we sample a scalar, if it's zero try again.
@@ -170,6 +442,65 @@ impl<E: Curve> Scalar<E> {
         NonZero::<Scalar<E>>::random(rng).into()
     }
 
+    /// Generates random non-zero scalar
+    ///
+    /// Same as [`Scalar::random`], but returns an error instead of panicking if randomness
+    /// source returned 100 zero scalars in a row.
+    ///
+    /// ```rust
+    /// use generic_ec::{Scalar, curves::Secp256k1, errors::BrokenRng};
+    /// use rand::rngs::OsRng;
+    ///
+    /// let s = Scalar::<Secp256k1>::try_random(&mut OsRng)?;
+    /// # Ok::<(), BrokenRng>(())
+    /// ```
+    pub fn try_random<R: RngCore>(rng: &mut R) -> Result<Self, BrokenRng> {
+        NonZero::<Scalar<E>>::try_random(rng).map(Into::into)
+    }
+
+    /// Generates random scalar $S < 2^\text{bits}$
+    ///
+    /// Samples `bits` random bits, the rest of bits are set to zero. It's useful for protocols that
+    /// use short exponents for efficiency.
+    ///
+    /// **Warning:** output is NOT uniformly distributed over $\Zq$, it's only uniform in range
+    /// $[0, 2^\text{bits})$. Only use it when security analysis of your protocol permits short
+    /// exponents. Use [`Scalar::random`] otherwise.
+    ///
+    /// If `bits` is greater or equal than bit length of the group order $q$, the sampled integer
+    /// is reduced modulo $q$, so the output is still a valid scalar, but $S < 2^\text{bits}$
+    /// doesn't provide any additional guarantees.
+    ///
+    /// ```rust
+    /// use generic_ec::{Scalar, curves::Secp256k1};
+    /// use rand::rngs::OsRng;
+    ///
+    /// let s = Scalar::<Secp256k1>::random_bits(&mut OsRng, 128);
+    /// // Top 128 bits are zero
+    /// assert!(s.to_be_bytes()[..16].iter().all(|b| *b == 0));
+    /// ```
+    pub fn random_bits<R: RngCore>(rng: &mut R, bits: u32) -> Self {
+        let mut bytes = E::ScalarArray::zeroes();
+        let bytes_len = bytes.as_ref().len();
+        let bits = usize::try_from(bits)
+            .unwrap_or(usize::MAX)
+            .min(bytes_len * 8);
+
+        let full_bytes = bits / 8;
+        let remaining_bits = bits % 8;
+        let bytes = bytes.as_mut();
+        if remaining_bits == 0 {
+            rng.fill_bytes(&mut bytes[..full_bytes]);
+        } else {
+            rng.fill_bytes(&mut bytes[..=full_bytes]);
+            bytes[full_bytes] &= (1 << remaining_bits) - 1;
+        }
+
+        let scalar = Self::from_le_bytes_mod_order(&*bytes);
+        bytes.zeroize();
+        scalar
+    }
+
     #[doc = include_str!("../docs/hash_to_scalar.md")]
     ///
     /// ## Example
@@ -198,9 +529,180 @@ impl<E: Curve> Scalar<E> {
         Self::random(&mut rng)
     }
 
+    /// Reduces finalized digest output modulo curve order
+    ///
+    /// Output is interpreted as big-endian integer. It's equivalent to
+    /// [`Scalar::from_be_bytes_mod_order`], but takes output of [`digest::Digest`] directly.
+    ///
+    /// secp256k1, ed25519, ristretto255 and BLS12-381 reduce 32 and 64 bytes long outputs
+    /// (e.g. SHA-256, SHA-512) with a single [`Reduce`](crate::traits::Reduce) call. Other
+    /// curves only implement [`Reduce`](crate::traits::Reduce) for the length of their scalar
+    /// (32 bytes for secp256r1, sm2 and stark, 48 bytes for secp384r1): output of that length
+    /// is reduced at once, while longer outputs are split into chunks of that length, each of
+    /// which is reduced separately and then combined.
+    ///
+    /// Note that reducing 32 bytes output modulo ~256 bits curve order is slightly biased.
+    /// If you need an unbiased scalar, use 64 bytes output or [`Scalar::from_hash`].
+    ///
+    /// ```rust
+    /// use generic_ec::{Scalar, curves::Secp256k1};
+    /// use sha2::{Digest, Sha512};
+    ///
+    /// let hash = Sha512::new().chain_update(b"transcript").finalize();
+    /// let challenge = Scalar::<Secp256k1>::from_digest_output::<Sha512>(hash);
+    /// assert_eq!(challenge, Scalar::from_be_bytes_mod_order(hash));
+    /// ```
+    #[cfg(feature = "hash-to-scalar")]
+    pub fn from_digest_output<D: digest::Digest>(output: digest::Output<D>) -> Self {
+        Self::from_be_bytes_mod_order(output)
+    }
+
+    /// Hashes message to scalar as specified in [RFC 9380](https://www.rfc-editor.org/rfc/rfc9380.html#section-5)
+    ///
+    /// Implements `hash_to_field` with `expand_message_xmd` instantiated with hash function `D`,
+    /// and field being scalars modulo curve order $q$ (`count = 1`, `m = 1`). `dst` is a domain
+    /// separation tag, it may have any length: tags longer than 255 bytes are hashed as
+    /// specified in RFC. Arguments go in the same order as in
+    /// [`HashToCurve::hash_to_curve`](crate::traits::HashToCurve::hash_to_curve): message first,
+    /// then the tag.
+    ///
+    /// Expanded message has $L = \lceil (\lceil \log_2 q \rceil + k) / 8 \rceil$ bytes, where
+    /// $k = \lceil \log_2 q \rceil / 2$ is the curve security level (e.g. $L = 48$ for
+    /// 256 bits curves, $L = 72$ for secp384r1), so output is statistically close to uniform.
+    /// Expanded message is interpreted as big-endian integer and reduced modulo $q$.
+    ///
+    /// Unlike [`Scalar::from_hash`], it follows an existing standard, so it's interoperable with
+    /// other implementations of RFC 9380 `hash_to_field`.
+    ///
+    /// ```rust
+    /// use generic_ec::{Scalar, curves::Secp256k1};
+    /// use sha2::Sha256;
+    ///
+    /// let challenge = Scalar::<Secp256k1>::hash_to_scalar::<Sha256>(
+    ///     b"transcript",
+    ///     b"MY-PROTOCOL-V1-challenge",
+    /// );
+    /// ```
+    #[cfg(feature = "hash-to-scalar")]
+    pub fn hash_to_scalar<D>(msg: &[u8], dst: &[u8]) -> Self
+    where
+        D: digest::Digest + digest::crypto_common::BlockSizeUser,
+    {
+        let order_bits = Self::bits();
+        let security_bits = order_bits.div_ceil(2);
+        let len_in_bytes = (order_bits + security_bits).div_ceil(8);
+
+        // Expanded message is interpreted as big-endian integer `hi || lo`, where `lo` has
+        // length of the scalar
+        let mut hi = E::ScalarArray::zeroes();
+        let mut lo = E::ScalarArray::zeroes();
+        let scalar_len = lo.as_ref().len();
+        let hi_len = len_in_bytes.saturating_sub(scalar_len);
+        let lo_len = len_in_bytes.min(scalar_len);
+        generic_ec_core::expand_message::expand_message_xmd::<D>(
+            &[msg],
+            &[dst],
+            len_in_bytes,
+            hi.as_mut()[scalar_len - hi_len..]
+                .iter_mut()
+                .chain(&mut lo.as_mut()[scalar_len - lo_len..]),
+        );
+
+        // 2^{8 * scalar_len} mod q
+        let mut max = E::ScalarArray::zeroes();
+        max.as_mut().iter_mut().for_each(|b| *b = 0xff);
+        let shift = Scalar::from_be_bytes_mod_order(&max) + Scalar::one();
+
+        Scalar::from_be_bytes_mod_order(&hi) * shift + Scalar::from_be_bytes_mod_order(&lo)
+    }
+
+    /// Returns big-endian bytes representation of curve group order $q$
+    ///
+    /// Note that $q$ itself is not a valid scalar, so `Scalar::from_be_bytes` would
+    /// refuse to parse the returned bytes.
+    ///
+    /// ```rust
+    /// use generic_ec::{Scalar, curves::Secp256k1};
+    ///
+    /// let q = Scalar::<Secp256k1>::group_order_be_bytes();
+    /// assert_eq!(
+    ///     hex::encode(&q),
+    ///     "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141",
+    /// );
+    /// assert!(Scalar::<Secp256k1>::from_be_bytes(&q).is_err());
+    /// ```
+    pub fn group_order_be_bytes() -> EncodedScalar<E> {
+        EncodedScalar::new(E::group_order_be_bytes())
+    }
+
+    /// Returns $i$-th bit of the scalar (in constant time)
+    ///
+    /// Bits are indexed from the least significant one, i.e. $S = \sum_i \text{bit}_i \cdot 2^i$.
+    /// Returns `Choice::from(0)` if `i` is out of range. Only the value of the scalar is
+    /// protected, index `i` is not considered secret.
+    ///
+    /// ```rust
+    /// use generic_ec::{Scalar, curves::Secp256k1};
+    ///
+    /// let s = Scalar::<Secp256k1>::from(0b1010u64);
+    /// let bits = (0..4).map(|i| s.bit(i).unwrap_u8()).collect::<Vec<_>>();
+    /// assert_eq!(bits, [0, 1, 0, 1]);
+    /// assert_eq!(s.bit(Scalar::<Secp256k1>::bits()).unwrap_u8(), 0);
+    /// ```
+    pub fn bit(&self, i: usize) -> Choice {
+        let bytes = self.to_be_bytes();
+        let byte = bytes
+            .len()
+            .checked_sub(1 + i / 8)
+            .and_then(|byte_index| bytes.get(byte_index))
+            .copied()
+            .unwrap_or(0);
+        Choice::from((byte >> (i % 8)) & 1)
+    }
+
+    /// Bit length of curve group order $q$, i.e. $\lceil \log_2 q \rceil$
+    ///
+    /// Any scalar fits into that many bits, so it's the upper bound for [`Scalar::bit`] index.
+    ///
+    /// ```rust
+    /// use generic_ec::{Scalar, curves::{Ed25519, Secp256k1}};
+    ///
+    /// assert_eq!(Scalar::<Secp256k1>::bits(), 256);
+    /// assert_eq!(Scalar::<Ed25519>::bits(), 253);
+    /// ```
+    pub fn bits() -> usize {
+        let order = E::group_order_be_bytes();
+        let order = order.as_ref();
+        let leading_zeroes = order.iter().take_while(|b| **b == 0).count();
+        8 * (order.len() - leading_zeroes)
+            - order
+                .get(leading_zeroes)
+                .map(|b| b.leading_zeros() as usize)
+                .unwrap_or(0)
+    }
+
+    /// Size of bytes buffer that can fit serialized scalar
+    ///
+    /// All scalars of the curve are serialized into the same number of bytes. Unlike
+    /// [`Scalar::serialized_len`], it can be used in const context:
+    ///
+    /// ```rust
+    /// use generic_ec::{Scalar, curves::Secp256k1};
+    ///
+    /// const FRAME_LEN: usize = 1 + Scalar::<Secp256k1>::SERIALIZED_LEN;
+    /// let mut frame = [0u8; FRAME_LEN];
+    ///
+    /// let s = Scalar::<Secp256k1>::from(42);
+    /// frame[0] = 0x01;
+    /// frame[1..].copy_from_slice(&s.to_be_bytes());
+    /// ```
+    pub const SERIALIZED_LEN: usize = E::ScalarArray::LEN;
+
     /// Returns size of bytes buffer that can fit serialized scalar
+    ///
+    /// Same as [`Scalar::SERIALIZED_LEN`]
     pub fn serialized_len() -> usize {
-        E::ScalarArray::zeroes().as_ref().len()
+        Self::SERIALIZED_LEN
     }
 
     /// Returns scalar big-endian representation in radix $2^4 = 16$
@@ -247,6 +749,186 @@ impl<E: Curve> Scalar<E> {
         use crate::multiscalar::MultiscalarMul;
         crate::multiscalar::Default::multiscalar_mul(scalar_points)
     }
+
+    /// Performs multiscalar multiplication where some of the bases are the curve generator
+    ///
+    /// Returns $\sum_i g_i \cdot G + \sum_j s_j \cdot P_j$, where $g_i$ are `generator_terms`,
+    /// and $(s_j, P_j)$ are `point_terms`.
+    ///
+    /// Generator terms are summed up and multiplied at the generator once via precomputed
+    /// [`GeneratorTable`](crate::GeneratorTable), which doesn't need any point doublings. Point
+    /// terms are computed via [`Scalar::multiscalar_mul`]. The table is expensive to compute, so
+    /// it should be built once and reused across many calls.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use generic_ec::{Point, Scalar, curves::Secp256k1};
+    /// # use rand_core::OsRng;
+    ///
+    /// let table = Point::<Secp256k1>::generator_table();
+    ///
+    /// let (a, b, c) = (Scalar::random(&mut OsRng), Scalar::random(&mut OsRng), Scalar::random(&mut OsRng));
+    /// let (p, q) = (Point::generator() * Scalar::random(&mut OsRng), Point::generator() * Scalar::random(&mut OsRng));
+    ///
+    /// let result = Scalar::multiscalar_mul_mixed(&table, &[a], [(b, p), (c, q)].into_iter());
+    /// assert_eq!(result, Point::generator() * a + p * b + q * c);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn multiscalar_mul_mixed<S, P, I>(
+        table: &crate::GeneratorTable<E>,
+        generator_terms: &[Scalar<E>],
+        point_terms: I,
+    ) -> crate::Point<E>
+    where
+        S: AsRef<Scalar<E>>,
+        P: AsRef<crate::Point<E>>,
+        I: IntoIterator<Item = (S, P)>,
+        I::IntoIter: ExactSizeIterator,
+    {
+        let generator_scalar = generator_terms.iter().sum::<Scalar<E>>();
+        table.mul(&generator_scalar) + Self::multiscalar_mul(point_terms.into_iter())
+    }
+}
+
+impl<E: Curve> Scalar<E>
+where
+    E::Scalar: Sqrt,
+{
+    /// Returns square root of the scalar
+    ///
+    /// Square root of $S$ is a scalar $R$ such that $R^2 = S$. It exists only if $S$ is a
+    /// quadratic residue modulo group order, otherwise `None` is returned. When square root
+    /// exists, both $R$ and $-R$ are square roots, it's not specified which of them is returned.
+    ///
+    /// ```rust
+    /// # fn func() -> Option<()> {
+    /// use generic_ec::{Scalar, curves::Secp256k1};
+    /// use rand::rngs::OsRng;
+    ///
+    /// let s = Scalar::<Secp256k1>::random(&mut OsRng);
+    /// let r = (s * s).sqrt()?;
+    /// assert!(r == s || r == -s);
+    /// # Some(()) }
+    /// # func();
+    /// ```
+    pub fn sqrt(&self) -> Option<Self> {
+        self.ct_sqrt().into()
+    }
+
+    /// Returns square root of the scalar (constant time)
+    ///
+    /// Same as [`Scalar::sqrt`] but doesn't leak whether the scalar is a quadratic residue
+    pub fn ct_sqrt(&self) -> CtOption<Self> {
+        Sqrt::sqrt(self.as_raw()).map(Self::from_raw)
+    }
+}
+
+impl<E: Curve> Scalar<E>
+where
+    E::Scalar: UintConversion,
+{
+    /// Converts scalar into the curve native big integer
+    ///
+    /// Returned integer is always less than curve group order. Curves implemented on top of
+    /// [`elliptic_curve`](https://docs.rs/elliptic-curve) crate (secp256k1, secp256r1, secp384r1,
+    /// SM2, stark) convert scalars into [`crypto_bigint`](https://docs.rs/crypto-bigint) integers
+    /// of their native size. Ed25519 and Ristretto255 convert scalars into `crypto_bigint::U256`
+    /// when `crypto-bigint` feature is enabled.
+    ///
+    /// ```rust
+    /// use generic_ec::{Scalar, curves::Secp256k1};
+    ///
+    /// let uint = Scalar::<Secp256k1>::from(42).to_uint();
+    /// assert_eq!(uint.as_words()[0], 42);
+    /// ```
+    pub fn to_uint(&self) -> <E::Scalar as UintConversion>::Uint {
+        self.0.to_uint()
+    }
+
+    /// Constructs a scalar from the curve native big integer
+    ///
+    /// Returns `None` if the integer is not less than curve group order.
+    ///
+    /// ```rust
+    /// use generic_ec::{Scalar, curves::Secp256k1};
+    ///
+    /// let minus_one = (-Scalar::<Secp256k1>::one()).to_uint();
+    /// let order = minus_one.wrapping_add(&Scalar::<Secp256k1>::one().to_uint());
+    ///
+    /// assert_eq!(Scalar::<Secp256k1>::from_uint(&minus_one).unwrap(), -Scalar::one());
+    /// assert!(bool::from(Scalar::<Secp256k1>::from_uint(&order).is_none()));
+    /// ```
+    pub fn from_uint(uint: &<E::Scalar as UintConversion>::Uint) -> CtOption<Self> {
+        E::Scalar::from_uint(uint).map(Self)
+    }
+}
+
+#[cfg(any(
+    feature = "curve-secp256k1",
+    feature = "curve-secp256r1",
+    feature = "curve-secp384r1",
+    feature = "curve-sm2",
+    feature = "curve-stark"
+))]
+impl<C, X> Scalar<generic_ec_curves::rust_crypto::RustCryptoCurve<C, X>>
+where
+    C: generic_ec_curves::rust_crypto::elliptic_curve::CurveArithmetic,
+    generic_ec_curves::rust_crypto::RustCryptoCurve<C, X>:
+        Curve<Scalar = generic_ec_curves::rust_crypto::RustCryptoScalar<C>>,
+{
+    /// Constructs a scalar from the curve native big integer, reducing it modulo curve order
+    ///
+    /// Available for curves implemented on top of [`elliptic_curve`](generic_ec_curves::rust_crypto::elliptic_curve)
+    /// crate. Use [`Scalar::from_uint`] to reject integers that are not less than curve order.
+    ///
+    /// ```rust
+    /// use generic_ec::{Scalar, curves::Secp256k1};
+    ///
+    /// let one = Scalar::<Secp256k1>::one().to_uint();
+    /// assert_eq!(Scalar::<Secp256k1>::from_uint_reduced(&one), Scalar::one());
+    /// ```
+    pub fn from_uint_reduced(uint: &C::Uint) -> Self {
+        use generic_ec_curves::rust_crypto::{elliptic_curve::ops::Reduce, RustCryptoScalar};
+        Self(RustCryptoScalar(Reduce::<C::Uint>::reduce(*uint)))
+    }
+}
+
+#[cfg(feature = "keccak")]
+#[cfg_attr(docsrs, doc(cfg(feature = "keccak")))]
+impl Scalar<crate::curves::Secp256k1> {
+    /// Finalizes Keccak-256 hasher and reduces its output modulo curve order
+    ///
+    /// Digest is interpreted as big-endian integer and reduced modulo $q$, which matches
+    /// `uint256(keccak256(...)) % n` used by Ethereum contracts (e.g. to derive a challenge
+    /// which is then verified via `ecrecover`). Note that it's the original Keccak-256 used in
+    /// Ethereum, not the standardized SHA3-256.
+    ///
+    /// Reducing 32 bytes digest modulo secp256k1 order is slightly biased, although the bias
+    /// is negligible as order is very close to $2^{256}$.
+    ///
+    /// ```rust
+    /// use generic_ec::{Point, Scalar, curves::Secp256k1};
+    /// use sha3::{Digest, Keccak256};
+    ///
+    /// let r = Point::<Secp256k1>::generator() * Scalar::from(42);
+    /// let challenge = Scalar::<Secp256k1>::from_keccak256(
+    ///     Keccak256::new()
+    ///         .chain_update(r.to_bytes(true))
+    ///         .chain_update(b"message"),
+    /// );
+    /// ```
+    pub fn from_keccak256(hasher: sha3::Keccak256) -> Self {
+        use sha3::Digest;
+        Self::from_be_bytes_mod_order(hasher.finalize())
+    }
+
+    /// Computes Keccak-256 of the message and reduces it modulo curve order
+    ///
+    /// Shortcut for [`Scalar::from_keccak256`] when the whole message is available.
+    pub fn keccak256(msg: &[u8]) -> Self {
+        use sha3::Digest;
+        Self::from_keccak256(sha3::Keccak256::new_with_prefix(msg))
+    }
 }
 
 impl<E: Curve> AsRaw for Scalar<E> {
@@ -376,13 +1058,10 @@ macro_rules! impl_from_signed_integer {
     ($($iint:ident),+) => {$(
         impl<E: Curve> From<$iint> for Scalar<E> {
             fn from(i: $iint) -> Self {
-                use subtle::{ConditionallyNegatable, Choice};
                 // TODO: what's a better way to do that check in constant time?
                 let is_neg = Choice::from(u8::from(i.is_negative()));
                 let i = i.unsigned_abs();
-                let mut i = Scalar::from(i);
-                i.conditional_negate(is_neg);
-                i
+                Scalar::from(i).conditional_negate(is_neg)
             }
         }
     )+};
@@ -395,6 +1074,61 @@ impl_from_signed_integer! {
     i8, i16, i32, i64, i128
 }
 
+/// Decodes a scalar from its big-endian bytes representation, same as [`Scalar::from_be_bytes`]
+///
+/// ```rust
+/// use generic_ec::{Scalar, curves::Secp256k1};
+///
+/// let one = Scalar::<Secp256k1>::try_from(&[1u8][..])?;
+/// assert_eq!(one, Scalar::one());
+/// # Ok::<(), generic_ec::errors::InvalidScalar>(())
+/// ```
+impl<E: Curve> TryFrom<&[u8]> for Scalar<E> {
+    type Error = InvalidScalar;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        Self::from_be_bytes(bytes)
+    }
+}
+
+/// Decodes a scalar from its big-endian bytes representation, same as [`Scalar::from_be_bytes`]
+///
+/// ```rust
+/// use generic_ec::{Scalar, curves::Secp256k1};
+///
+/// let mut bytes = [0u8; 32];
+/// bytes[31] = 1;
+/// let one = Scalar::<Secp256k1>::try_from(bytes)?;
+/// assert_eq!(one, Scalar::one());
+/// # Ok::<(), generic_ec::errors::InvalidScalar>(())
+/// ```
+impl<E: Curve, const N: usize> TryFrom<[u8; N]> for Scalar<E> {
+    type Error = InvalidScalar;
+
+    fn try_from(bytes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::from_be_bytes(bytes)
+    }
+}
+
+/// Decodes a scalar from its encoding produced by [`Scalar::to_be_bytes`]
+///
+/// ```rust
+/// use generic_ec::{Scalar, curves::Secp256k1};
+/// # use rand_core::OsRng;
+///
+/// let scalar = Scalar::<Secp256k1>::random(&mut OsRng);
+/// let encoded = scalar.to_be_bytes();
+/// assert_eq!(Scalar::try_from(encoded)?, scalar);
+/// # Ok::<(), generic_ec::errors::InvalidScalar>(())
+/// ```
+impl<E: Curve> TryFrom<EncodedScalar<E>> for Scalar<E> {
+    type Error = InvalidScalar;
+
+    fn try_from(bytes: EncodedScalar<E>) -> Result<Self, Self::Error> {
+        Self::from_be_bytes(&bytes)
+    }
+}
+
 impl<E: Curve> fmt::Debug for Scalar<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut s = f.debug_struct("Scalar");
@@ -412,7 +1146,115 @@ impl<E: Curve> fmt::Debug for Scalar<E> {
     }
 }
 
-#[allow(clippy::derived_hash_with_manual_eq)]
+/// Formats scalar as big-endian hex string
+///
+/// Output is zero-padded to [`Scalar::serialized_len()`] bytes, i.e. it's the hex encoding of
+/// [`Scalar::to_be_bytes()`](Scalar::to_be_bytes). Alternate flag adds `0x` prefix.
+///
+/// ```rust
+/// use generic_ec::{Scalar, curves::Secp256k1};
+///
+/// let s = Scalar::<Secp256k1>::from(255);
+/// assert_eq!(
+///     s.to_string(),
+///     "00000000000000000000000000000000000000000000000000000000000000ff",
+/// );
+/// ```
+impl<E: Curve> fmt::Display for Scalar<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        crate::hex_str::fmt_hex(self.to_be_bytes().as_bytes(), f, false)
+    }
+}
+
+/// Formats scalar as big-endian lower case hex string
+///
+/// Output is the same as of [`Display`](fmt::Display) implementation. Alternate flag adds `0x`
+/// prefix.
+///
+/// ```rust
+/// use generic_ec::{Scalar, curves::Secp256k1};
+///
+/// let s = Scalar::<Secp256k1>::from(0xab);
+/// assert_eq!(format!("{s:x}"), format!("{}ab", "00".repeat(31)));
+/// assert_eq!(format!("{s:#x}"), format!("0x{}ab", "00".repeat(31)));
+/// ```
+impl<E: Curve> fmt::LowerHex for Scalar<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        crate::hex_str::fmt_hex(self.to_be_bytes().as_bytes(), f, false)
+    }
+}
+
+/// Formats scalar as big-endian upper case hex string
+///
+/// Alternate flag adds `0x` prefix.
+///
+/// ```rust
+/// use generic_ec::{Scalar, curves::Secp256k1};
+///
+/// let s = Scalar::<Secp256k1>::from(0xab);
+/// assert_eq!(format!("{s:X}"), format!("{}AB", "00".repeat(31)));
+/// assert_eq!(format!("{s:#X}"), format!("0x{}AB", "00".repeat(31)));
+/// ```
+impl<E: Curve> fmt::UpperHex for Scalar<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        crate::hex_str::fmt_hex(self.to_be_bytes().as_bytes(), f, true)
+    }
+}
+
+/// Parses scalar from big-endian hex string
+///
+/// Both lower and upper case hex digits are accepted, no `0x` prefix is expected. String shorter
+/// than [`Scalar::serialized_len()`] bytes is interpreted as if it was padded with leading zeroes,
+/// same as in [`Scalar::from_be_bytes`].
+///
+/// Returns error if string has odd length, encodes more than [`Scalar::serialized_len()`] bytes,
+/// contains non-hex characters, or encodes an integer that's not less than group order.
+///
+/// ```rust
+/// use generic_ec::{Scalar, curves::Secp256k1};
+///
+/// let s: Scalar<Secp256k1> = "ff".parse()?;
+/// assert_eq!(s, Scalar::from(255));
+/// # Ok::<(), generic_ec::errors::ParseScalarError>(())
+/// ```
+impl<E: Curve> core::str::FromStr for Scalar<E> {
+    type Err = ParseScalarError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.as_bytes();
+        if !s.len().is_multiple_of(2) {
+            return Err(ParseScalarError::new(ParseScalarErrorReason::OddLength));
+        }
+
+        let mut bytes = E::ScalarArray::zeroes();
+        let bytes_len = bytes.as_ref().len();
+        if bytes_len < s.len() / 2 {
+            return Err(ParseScalarError::new(ParseScalarErrorReason::TooLong));
+        }
+
+        crate::hex_str::decode_to_slice(s, &mut bytes.as_mut()[bytes_len - s.len() / 2..])
+            .map_err(|_| ParseScalarError::new(ParseScalarErrorReason::InvalidCharacter))?;
+
+        Scalar::from_be_bytes(&bytes)
+            .map_err(|_| ParseScalarError::new(ParseScalarErrorReason::OutOfRange))
+    }
+}
+
+/// Scalars are compared in constant time via [`ConstantTimeEq`]
+///
+/// Comparison doesn't leak (through timing) whether or where scalars differ, so `==` is safe to
+/// use on secret scalars.
+impl<E: Curve> PartialEq for Scalar<E> {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+/// Hash is computed over canonical big-endian encoding of the scalar
+///
+/// Scalar is hashed by writing [`Scalar::to_be_bytes()`](Scalar::to_be_bytes) into the hasher
+/// via a single [`Hasher::write`](hash::Hasher::write) call. Given the same `Hasher`, the hash
+/// value is stable across crate versions and platforms.
 impl<E: Curve> Hash for Scalar<E> {
     fn hash<H: hash::Hasher>(&self, state: &mut H) {
         state.write(self.to_be_bytes().as_bytes())
@@ -446,6 +1288,23 @@ impl<E: Curve> udigest::Digestable for Scalar<E> {
     }
 }
 
+/// Generates a scalar for fuzzing
+///
+/// Consumes up to [`Scalar::serialized_len`] bytes and reduces them modulo curve order via
+/// [`Scalar::from_be_bytes_mod_order`]. If there's not enough data, the shorter input is
+/// reduced, so generation never fails.
+#[cfg(feature = "arbitrary")]
+impl<'a, E: Curve> arbitrary::Arbitrary<'a> for Scalar<E> {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let len = Self::serialized_len().min(u.len());
+        Ok(Self::from_be_bytes_mod_order(u.bytes(len)?))
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (0, Some(Self::serialized_len()))
+    }
+}
+
 /// Iterator over scalar coefficients in radix 16 representation
 ///
 /// See [`Scalar::as_radix16_be`] and [`Scalar::as_radix16_le`]
@@ -510,6 +1369,54 @@ impl<E: Curve> ExactSizeIterator for Radix16Iter<E> {
     }
 }
 
+/// Fixed scalar prepared for repeated multiplication
+///
+/// Useful when many varying scalars are multiplied by the same constant, e.g. when a fixed
+/// Lagrange coefficient is applied to a stream of shares. The constant is prepared once
+/// when `ConstMul` is constructed, so backends which benefit from precomputation (e.g.
+/// conversion into Montgomery form) don't have to redo it on every multiplication.
+///
+/// Note that all curves supported out of the box already store scalars in a form suitable
+/// for multiplication, so for them `ConstMul` is a thin wrapper and doesn't bring any speedup.
+///
+/// ```rust
+/// use generic_ec::{ConstMul, Scalar, curves::Secp256k1};
+/// use rand::rngs::OsRng;
+///
+/// let c = Scalar::<Secp256k1>::random(&mut OsRng);
+/// let c_mul = ConstMul::new(c);
+///
+/// let s = Scalar::random(&mut OsRng);
+/// assert_eq!(c_mul.mul(&s), c * s);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct ConstMul<E: Curve> {
+    constant: Scalar<E>,
+}
+
+impl<E: Curve> ConstMul<E> {
+    /// Prepares `constant` for repeated multiplication
+    pub fn new(constant: Scalar<E>) -> Self {
+        Self { constant }
+    }
+
+    /// Returns $c \cdot s$ where $c$ is the constant
+    pub fn mul(&self, s: &Scalar<E>) -> Scalar<E> {
+        self.constant * s
+    }
+
+    /// Returns the constant
+    pub fn constant(&self) -> &Scalar<E> {
+        &self.constant
+    }
+}
+
+impl<E: Curve> From<Scalar<E>> for ConstMul<E> {
+    fn from(constant: Scalar<E>) -> Self {
+        Self::new(constant)
+    }
+}
+
 impl<E: Curve, const N: usize> crate::traits::Reduce<N> for Scalar<E>
 where
     E::Scalar: crate::traits::Reduce<N>,
@@ -521,3 +1428,103 @@ where
         Self::from_raw(Reduce::from_le_array_mod_order(bytes))
     }
 }
+
+#[cfg(all(test, feature = "hash-to-scalar"))]
+mod tests {
+    use alloc::collections::BTreeSet;
+
+    use sha2::{Sha256, Sha384, Sha512};
+
+    use crate::{
+        curves::{Ed25519, Ristretto255, Secp256k1, Secp256r1, Secp384r1},
+        Curve, Scalar,
+    };
+
+    /// Values for secp256k1, secp256r1, secp384r1 match `hash_to_scalar` implemented in RustCrypto
+    /// crates
+    #[test]
+    fn hash_to_scalar_known_answers() {
+        fn check<E: Curve, D>(msg: &[u8], expected: &str)
+        where
+            D: digest::Digest + digest::crypto_common::BlockSizeUser,
+        {
+            let scalar = Scalar::<E>::hash_to_scalar::<D>(msg, b"generic-ec-test");
+            assert_eq!(
+                hex::encode(scalar.to_be_bytes()),
+                expected,
+                "{}",
+                E::CURVE_NAME
+            );
+        }
+
+        check::<Secp256k1, Sha256>(
+            b"",
+            "1b53be93ab4a772a4ca122a4f8b569cdc062e16096bf1c8f7673f8bf5e0f51d9",
+        );
+        check::<Secp256k1, Sha256>(
+            b"abc",
+            "e3ef1e609a701179bd5bc9330a14b9f2d990164148c0043f6fe3e48aab1ced09",
+        );
+        check::<Secp256r1, Sha256>(
+            b"",
+            "9d7aa360f87f31ad0ef3c3c39a0ffb06d599ede5a8f9fecb2184a383bcfd8336",
+        );
+        check::<Secp256r1, Sha256>(
+            b"abc",
+            "8c73ea0e6a63460e7711a373f5317243d5eb160276b510cee67cdbb7ddb21fa8",
+        );
+        check::<Secp384r1, Sha384>(
+            b"",
+            "40aca72f183155c3fe111b2ccc1f9aa24a67101fba25666b6089184c21fcf0a0a642f8fc15192c960acb33f8220e9ab8",
+        );
+        check::<Secp384r1, Sha384>(
+            b"abc",
+            "02d7db08b6d12e8b1d9597c56e083ad62c823b320e5893ae3a9821c36c0cab765a7eca0c72aecaee1db44e6f17ce6a1e",
+        );
+        check::<Ed25519, Sha512>(
+            b"",
+            "051ab070c05c74545297bdb2309bfc9de9f07a288701ef5a9d5319ef2bd75592",
+        );
+        check::<Ed25519, Sha512>(
+            b"abc",
+            "0ae9d22d5d62a918e3c3cb57d02df6fb9c67ec3553ac8c972c197f2d7ca8048e",
+        );
+        check::<Ristretto255, Sha512>(
+            b"abc",
+            "0ae9d22d5d62a918e3c3cb57d02df6fb9c67ec3553ac8c972c197f2d7ca8048e",
+        );
+    }
+
+    #[test]
+    fn hash_to_scalar_is_well_distributed() {
+        fn check<E: Curve>() {
+            const N: u32 = 2000;
+            let scalars = (0..N)
+                .map(|i| {
+                    Scalar::<E>::hash_to_scalar::<Sha256>(&i.to_be_bytes(), b"generic-ec-test")
+                })
+                .collect::<alloc::vec::Vec<_>>();
+
+            // All outputs are distinct
+            assert_eq!(scalars.iter().collect::<BTreeSet<_>>().len(), scalars.len());
+
+            // Each of the lowest 8 bits is set in roughly half of outputs. Standard deviation is
+            // ~22, so the bounds are more than 4 standard deviations away from expected value
+            for bit in 0..8 {
+                let set = scalars
+                    .iter()
+                    .filter(|s| s.to_le_bytes()[0] & (1 << bit) != 0)
+                    .count();
+                assert!(
+                    (900..=1100).contains(&set),
+                    "{}: bit {bit} is set {set} times",
+                    E::CURVE_NAME
+                );
+            }
+        }
+
+        check::<Secp256k1>();
+        check::<Secp384r1>();
+        check::<Ed25519>();
+    }
+}
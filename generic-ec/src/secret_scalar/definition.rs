@@ -2,7 +2,7 @@
 #[cfg_attr(docsrs, doc(cfg(all())))]
 mod with_alloc {
     use alloc::sync::Arc;
-    use zeroize::{Zeroize, Zeroizing};
+    use zeroize::{Zeroize, ZeroizeOnDrop, Zeroizing};
 
     use crate::{Curve, Scalar};
 
@@ -31,12 +31,15 @@ mod with_alloc {
             Self(self.0.clone())
         }
     }
+
+    /// Scalar is zeroized when the last clone of secret scalar is dropped
+    impl<E: Curve> ZeroizeOnDrop for SecretScalar<E> {}
 }
 
 #[cfg(not(feature = "alloc"))]
 #[cfg_attr(docsrs, doc(cfg(all())))]
 mod without_alloc {
-    use zeroize::{Zeroize, Zeroizing};
+    use zeroize::{Zeroize, ZeroizeOnDrop, Zeroizing};
 
     use crate::{Curve, Scalar};
 
@@ -63,6 +66,8 @@ mod without_alloc {
             Self(self.0.clone())
         }
     }
+
+    impl<E: Curve> ZeroizeOnDrop for SecretScalar<E> {}
 }
 
 mod secret_scalar {
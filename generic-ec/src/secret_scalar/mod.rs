@@ -4,7 +4,7 @@ use core::iter::{Product, Sum};
 use rand_core::{CryptoRng, RngCore};
 use subtle::{Choice, ConstantTimeEq};
 
-use crate::{errors::InvalidScalar, Curve, Scalar};
+use crate::{errors::InvalidScalar, Curve, EncodedScalar, Scalar};
 
 use self::definition::SecretScalar;
 
@@ -33,6 +33,28 @@ impl<E: Curve> SecretScalar<E> {
         Self::new(&mut scalar)
     }
 
+    /// Generates random secret scalar from a randomness source that's not necessarily
+    /// cryptographically secure
+    ///
+    /// **⚠️ For tests only!** Unlike [`SecretScalar::random`], it doesn't require `R: CryptoRng`,
+    /// which makes it possible to use deterministic non-cryptographic RNGs in tests. Secret scalar
+    /// is only as unpredictable as its randomness source, so never use it in production code.
+    ///
+    /// Requires `insecure-test-rng` feature to be enabled.
+    ///
+    /// ```rust
+    /// use generic_ec::{SecretScalar, curves::Secp256k1};
+    ///
+    /// let mut rng = rand::rngs::mock::StepRng::new(1, 1);
+    /// let secret = SecretScalar::<Secp256k1>::random_from_non_crypto_rng(&mut rng);
+    /// ```
+    #[cfg(feature = "insecure-test-rng")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "insecure-test-rng")))]
+    pub fn random_from_non_crypto_rng<R: RngCore>(rng: &mut R) -> Self {
+        let mut scalar = Scalar::random(rng);
+        Self::new(&mut scalar)
+    }
+
     #[doc = include_str!("../../docs/hash_to_scalar.md")]
     ///
     /// ## Example
@@ -61,6 +83,35 @@ impl<E: Curve> SecretScalar<E> {
         Self::random(&mut rng)
     }
 
+    /// Encodes scalar as bytes in big-endian order
+    ///
+    /// Returned [`EncodedScalar`] is zeroized on drop, so the secret bytes are erased from memory
+    /// once the buffer goes out of scope. However, caller is responsible for not leaking the bytes
+    /// elsewhere: any copy of the bytes (e.g. via `.to_vec()`) is not zeroized.
+    ///
+    /// ```rust
+    /// use generic_ec::{SecretScalar, curves::Secp256k1};
+    /// # use rand::rngs::OsRng;
+    ///
+    /// let secret = SecretScalar::<Secp256k1>::random(&mut OsRng);
+    /// let bytes = secret.to_be_bytes();
+    /// // ... e.g. encrypt `bytes` to export the secret
+    /// let decoded = SecretScalar::<Secp256k1>::from_be_bytes(&bytes)?;
+    /// assert_eq!(decoded.as_ref(), secret.as_ref());
+    /// # Ok::<(), generic_ec::errors::InvalidScalar>(())
+    /// ```
+    pub fn to_be_bytes(&self) -> EncodedScalar<E> {
+        self.as_ref().to_be_bytes()
+    }
+
+    /// Encodes scalar as bytes in little-endian order
+    ///
+    /// Same as [`to_be_bytes`](Self::to_be_bytes), returned buffer is zeroized on drop, and caller
+    /// is responsible for not leaking the bytes elsewhere.
+    pub fn to_le_bytes(&self) -> EncodedScalar<E> {
+        self.as_ref().to_le_bytes()
+    }
+
     /// Decodes scalar from its bytes representation in big-endian order
     pub fn from_be_bytes(bytes: &[u8]) -> Result<Self, InvalidScalar> {
         let mut scalar = Scalar::from_be_bytes(bytes)?;
@@ -72,6 +123,42 @@ impl<E: Curve> SecretScalar<E> {
         let mut scalar = Scalar::from_le_bytes(bytes)?;
         Ok(Self::new(&mut scalar))
     }
+
+    /// Computes $S + X$, keeps the result secret
+    ///
+    /// Unlike `+` operator which outputs [`Scalar<E>`], result of this method is a `SecretScalar`,
+    /// so it's zeroized on drop. Intermediate sum is erased from the stack. `other` can be either
+    /// a [`Scalar<E>`] or another `SecretScalar<E>`.
+    ///
+    /// ```rust
+    /// use generic_ec::{Scalar, SecretScalar, curves::Secp256k1};
+    /// # use rand::rngs::OsRng;
+    ///
+    /// let parent_key = SecretScalar::<Secp256k1>::random(&mut OsRng);
+    /// let tweak = Scalar::<Secp256k1>::random(&mut OsRng);
+    /// let child_key = parent_key.add_secret(&tweak);
+    /// assert_eq!(child_key.as_ref(), &(&parent_key + tweak));
+    /// ```
+    pub fn add_secret(&self, other: &impl AsRef<Scalar<E>>) -> Self {
+        let mut sum = self.as_ref() + other.as_ref();
+        Self::new(&mut sum)
+    }
+
+    /// Computes $S - X$, keeps the result secret
+    ///
+    /// Same as [`add_secret`](Self::add_secret), but subtracts scalars.
+    pub fn sub_secret(&self, other: &impl AsRef<Scalar<E>>) -> Self {
+        let mut diff = self.as_ref() - other.as_ref();
+        Self::new(&mut diff)
+    }
+
+    /// Computes $S \cdot X$, keeps the result secret
+    ///
+    /// Same as [`add_secret`](Self::add_secret), but multiplies scalars.
+    pub fn mul_secret(&self, other: &impl AsRef<Scalar<E>>) -> Self {
+        let mut prod = self.as_ref() * other.as_ref();
+        Self::new(&mut prod)
+    }
 }
 
 impl<E: Curve> ConstantTimeEq for SecretScalar<E> {
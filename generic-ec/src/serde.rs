@@ -73,6 +73,69 @@
 //! }"#);
 //! # Ok(()) }
 //! ```
+//!
+//! ## Compressed points with curve name
+//!
+//! [`CompressedTagged`] sits in between: points are serialized in compressed form, but still
+//! as `{curve, point}` structs, so deserializing a point from another curve is still an error.
+//! Scalars are serialized the same way as in default format.
+//!
+//! ## Migrating to compact serialization format
+//!
+//! If some data was already serialized in default format, switching to [`Compact`] makes it
+//! impossible to deserialize. [`PreferCompact`] helps with migration: it serializes points and
+//! scalars in compact format, but deserializes them from both compact and default formats.
+//!
+//! ```rust
+//! # fn main() -> Result<(), serde_json::Error> {
+//! use generic_ec::{Curve, Point, curves::Secp256k1};
+//! use serde::{Serialize, Deserialize};
+//! use serde_with::serde_as;
+//!
+//! #[serde_as]
+//! #[derive(Serialize, Deserialize)]
+//! #[serde(bound = "")]
+//! pub struct PublicKey<E: Curve> {
+//!     #[serde_as(as = "generic_ec::serde::PreferCompact")]
+//!     point: Point<E>,
+//! }
+//!
+//! let g = Point::<Secp256k1>::generator().to_point();
+//!
+//! // Data serialized in default format is still accepted
+//! let old = r#"{"point": {
+//!     "curve": "secp256k1",
+//!     "point": "0479be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8"
+//! }}"#;
+//! let pk: PublicKey<Secp256k1> = serde_json::from_str(old)?;
+//! assert_eq!(pk.point, g);
+//!
+//! // ... but it's serialized in compact format
+//! let new = serde_json::to_string(&pk)?;
+//! assert_eq!(new, r#"{"point":"0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798"}"#);
+//! let pk: PublicKey<Secp256k1> = serde_json::from_str(&new)?;
+//! assert_eq!(pk.point, g);
+//! # Ok(()) }
+//! ```
+//!
+//! `PreferCompact` needs to tell apart two formats, so it doesn't work with `serde` backends
+//! that serialize structs as sequences (such as `bincode`): data in compact format can be
+//! deserialized, but deserializing default format returns an error, as sequence-encoded
+//! input is ambiguous.
+//!
+//! ## Fixed-size binary format
+//!
+//! [`Fixed`] is similar to [`Compact`], but it's tailored for binary formats with fixed layout,
+//! such as `bincode`: points and scalars are serialized as byte arrays of fixed length without
+//! length prefix, so a scalar takes exactly [`Scalar::serialized_len()`](crate::Scalar::serialized_len)
+//! bytes, and a point takes exactly as much as its compressed encoding. Note that the data
+//! can't be deserialized back unless the curve is known in advance.
+//!
+//! ## Points on a curve unknown in advance
+//!
+//! When the curve is only known at runtime, a point serialized in default format can be
+//! deserialized into [`AnyCurvePoint`]: it reads the "curve" field and decodes the point on
+//! the corresponding curve. Only curves enabled via crate features are supported.
 
 use phantom_type::PhantomType;
 
@@ -445,6 +508,281 @@ mod optional {
         }
     }
 
+    /// Fixed-size binary serialization format
+    ///
+    /// Points and scalars are serialized as plain byte arrays of fixed length without curve
+    /// name. Points are serialized in compressed form. Unlike [`Compact`], byte arrays are
+    /// serialized as tuples, so binary formats such as `bincode` don't prepend them with their
+    /// length: a scalar takes exactly [`Scalar::serialized_len()`] bytes (32 bytes for secp256k1),
+    /// and a point takes exactly length of its compressed encoding (33 bytes for secp256k1).
+    ///
+    /// Human-readable formats (such as JSON) serialize points and scalars as hex strings, same
+    /// as [`Compact`] does.
+    ///
+    /// ```rust
+    /// use generic_ec::{Point, Scalar, curves::Secp256k1};
+    /// use serde::{Serialize, Deserialize};
+    /// use serde_with::serde_as;
+    ///
+    /// #[serde_as]
+    /// #[derive(Serialize, Deserialize)]
+    /// pub struct Signature {
+    ///     #[serde_as(as = "generic_ec::serde::Fixed")]
+    ///     r: Point<Secp256k1>,
+    ///     #[serde_as(as = "generic_ec::serde::Fixed")]
+    ///     s: Scalar<Secp256k1>,
+    /// }
+    ///
+    /// let sig = Signature {
+    ///     r: Point::generator().to_point(),
+    ///     s: Scalar::one(),
+    /// };
+    /// let bytes = bincode::serialize(&sig)?;
+    /// assert_eq!(bytes.len(), 33 + 32);
+    /// assert_eq!(bytes[..33], sig.r.to_bytes(true)[..]);
+    /// assert_eq!(bytes[33..], sig.s.to_be_bytes()[..]);
+    ///
+    /// let decoded: Signature = bincode::deserialize(&bytes)?;
+    /// assert_eq!(decoded.r, sig.r);
+    /// assert_eq!(decoded.s, sig.s);
+    /// # Ok::<(), bincode::Error>(())
+    /// ```
+    pub struct Fixed;
+
+    impl<E: Curve> serde_with::SerializeAs<Point<E>> for Fixed {
+        fn serialize_as<S>(source: &Point<E>, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+        {
+            use serde::Serialize;
+            models::PointFixed::from(source).serialize(serializer)
+        }
+    }
+
+    impl<'de, E: Curve> serde_with::DeserializeAs<'de, Point<E>> for Fixed {
+        fn deserialize_as<D>(deserializer: D) -> Result<Point<E>, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            use serde::Deserialize;
+            models::PointFixed::deserialize(deserializer)?
+                .try_into()
+                .map_err(<D::Error as serde::de::Error>::custom)
+        }
+    }
+
+    impl<E: Curve> serde_with::SerializeAs<Scalar<E>> for Fixed {
+        fn serialize_as<S>(source: &Scalar<E>, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+        {
+            use serde::Serialize;
+            models::ScalarFixed::from(source).serialize(serializer)
+        }
+    }
+
+    impl<'de, E: Curve> serde_with::DeserializeAs<'de, Scalar<E>> for Fixed {
+        fn deserialize_as<D>(deserializer: D) -> Result<Scalar<E>, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            use serde::Deserialize;
+            models::ScalarFixed::deserialize(deserializer)?
+                .try_into()
+                .map_err(<D::Error as serde::de::Error>::custom)
+        }
+    }
+
+    impl<E: Curve> serde_with::SerializeAs<SecretScalar<E>> for Fixed {
+        fn serialize_as<S>(source: &SecretScalar<E>, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+        {
+            use serde::Serialize;
+            models::ScalarFixed::from(source.as_ref()).serialize(serializer)
+        }
+    }
+
+    impl<'de, E: Curve> serde_with::DeserializeAs<'de, SecretScalar<E>> for Fixed {
+        fn deserialize_as<D>(deserializer: D) -> Result<SecretScalar<E>, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            let mut scalar =
+                <Fixed as serde_with::DeserializeAs<'de, Scalar<E>>>::deserialize_as(deserializer)?;
+            Ok(SecretScalar::new(&mut scalar))
+        }
+    }
+
+    impl<T> serde_with::SerializeAs<crate::NonZero<T>> for Fixed
+    where
+        Fixed: serde_with::SerializeAs<T>,
+    {
+        fn serialize_as<S>(source: &crate::NonZero<T>, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+        {
+            Fixed::serialize_as(source.as_ref(), serializer)
+        }
+    }
+
+    impl<'de, T> serde_with::DeserializeAs<'de, crate::NonZero<T>> for Fixed
+    where
+        Fixed: serde_with::DeserializeAs<'de, T>,
+        crate::NonZero<T>: TryFrom<T>,
+        <crate::NonZero<T> as TryFrom<T>>::Error: core::fmt::Display,
+    {
+        fn deserialize_as<D>(deserializer: D) -> Result<crate::NonZero<T>, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            let value = Fixed::deserialize_as(deserializer)?;
+            crate::NonZero::try_from(value).map_err(<D::Error as serde::de::Error>::custom)
+        }
+    }
+
+    impl<'a, T> serde_with::SerializeAs<&'a T> for Fixed
+    where
+        Fixed: serde_with::SerializeAs<T>,
+    {
+        fn serialize_as<S>(source: &&'a T, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+        {
+            Fixed::serialize_as(*source, serializer)
+        }
+    }
+
+    /// Default serialization format with compressed points
+    ///
+    /// Same as default format, points are serialized as `{curve, point}` structs, so the curve
+    /// name is still checked at deserialization, but points are serialized in compressed form,
+    /// which is about twice as short. Scalars are serialized exactly as in default format.
+    ///
+    /// Unlike [`Compact`], it doesn't drop the "curve" field. Note that points serialized in
+    /// default (uncompressed) format can't be deserialized via `CompressedTagged`, and vice versa.
+    ///
+    /// ```rust
+    /// use generic_ec::{Point, curves::{Secp256k1, Secp256r1}};
+    /// use serde::{Serialize, Deserialize};
+    /// use serde_with::serde_as;
+    ///
+    /// #[serde_as]
+    /// #[derive(Serialize, Deserialize)]
+    /// pub struct Config {
+    ///     #[serde_as(as = "generic_ec::serde::CompressedTagged")]
+    ///     public_key: Point<Secp256k1>,
+    /// }
+    ///
+    /// let config = Config { public_key: Point::generator().to_point() };
+    /// let json = serde_json::to_string(&config)?;
+    /// assert_eq!(
+    ///     json,
+    ///     r#"{"public_key":{"curve":"secp256k1","point":"0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798"}}"#,
+    /// );
+    /// let decoded: Config = serde_json::from_str(&json)?;
+    /// assert_eq!(decoded.public_key, config.public_key);
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    pub struct CompressedTagged;
+
+    impl<E: Curve> serde_with::SerializeAs<Point<E>> for CompressedTagged {
+        fn serialize_as<S>(source: &Point<E>, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+        {
+            use serde::Serialize;
+            models::PointCompressedTagged::from(source).serialize(serializer)
+        }
+    }
+
+    impl<'de, E: Curve> serde_with::DeserializeAs<'de, Point<E>> for CompressedTagged {
+        fn deserialize_as<D>(deserializer: D) -> Result<Point<E>, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            use serde::Deserialize;
+            models::PointCompressedTagged::deserialize(deserializer)?
+                .try_into()
+                .map_err(<D::Error as serde::de::Error>::custom)
+        }
+    }
+
+    impl<E: Curve> serde_with::SerializeAs<Scalar<E>> for CompressedTagged {
+        fn serialize_as<S>(source: &Scalar<E>, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+        {
+            serde::Serialize::serialize(source, serializer)
+        }
+    }
+
+    impl<'de, E: Curve> serde_with::DeserializeAs<'de, Scalar<E>> for CompressedTagged {
+        fn deserialize_as<D>(deserializer: D) -> Result<Scalar<E>, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            serde::Deserialize::deserialize(deserializer)
+        }
+    }
+
+    impl<E: Curve> serde_with::SerializeAs<SecretScalar<E>> for CompressedTagged {
+        fn serialize_as<S>(source: &SecretScalar<E>, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+        {
+            serde::Serialize::serialize(source, serializer)
+        }
+    }
+
+    impl<'de, E: Curve> serde_with::DeserializeAs<'de, SecretScalar<E>> for CompressedTagged {
+        fn deserialize_as<D>(deserializer: D) -> Result<SecretScalar<E>, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            serde::Deserialize::deserialize(deserializer)
+        }
+    }
+
+    impl<T> serde_with::SerializeAs<crate::NonZero<T>> for CompressedTagged
+    where
+        CompressedTagged: serde_with::SerializeAs<T>,
+    {
+        fn serialize_as<S>(source: &crate::NonZero<T>, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+        {
+            CompressedTagged::serialize_as(source.as_ref(), serializer)
+        }
+    }
+
+    impl<'de, T> serde_with::DeserializeAs<'de, crate::NonZero<T>> for CompressedTagged
+    where
+        CompressedTagged: serde_with::DeserializeAs<'de, T>,
+        crate::NonZero<T>: TryFrom<T>,
+        <crate::NonZero<T> as TryFrom<T>>::Error: core::fmt::Display,
+    {
+        fn deserialize_as<D>(deserializer: D) -> Result<crate::NonZero<T>, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            let value = CompressedTagged::deserialize_as(deserializer)?;
+            crate::NonZero::try_from(value).map_err(<D::Error as serde::de::Error>::custom)
+        }
+    }
+
+    impl<'a, T> serde_with::SerializeAs<&'a T> for CompressedTagged
+    where
+        CompressedTagged: serde_with::SerializeAs<T>,
+    {
+        fn serialize_as<S>(source: &&'a T, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+        {
+            CompressedTagged::serialize_as(*source, serializer)
+        }
+    }
+
     /// Wraps a [`serde::Deserializer`] and overrides `fn is_human_readable()`
     struct OverrideHumanReadable<D> {
         is_human_readable: bool,
@@ -504,6 +842,321 @@ mod optional {
         }
     }
 
+    #[cfg(feature = "curves")]
+    pub use self::any_curve::AnyCurvePoint;
+
+    #[cfg(feature = "curves")]
+    mod any_curve {
+        use core::fmt;
+
+        use serde::de::{self, DeserializeSeed, Visitor};
+
+        use crate::{
+            core::{ByteArray, Curve},
+            errors::{CurveMismatch, InvalidPoint},
+            Point,
+        };
+
+        /// Largest uncompressed point encoding among supported curves (BLS12-381 G2 point)
+        const MAX_UNCOMPRESSED_LEN: usize = 192;
+
+        macro_rules! any_curve_point {
+            ($($(#[$attr:meta])* $variant:ident => $curve:ty),+ $(,)?) => {
+                /// Point on any of the enabled curves
+                ///
+                /// It's serialized in the same format as [`Point<E>`]. When deserialized, it reads
+                /// the "curve" field and decodes the point on the corresponding curve. It's useful
+                /// when the curve isn't known in advance, e.g. when a system supports several curves
+                /// at runtime.
+                ///
+                /// Set of variants depends on enabled curves.
+                ///
+                /// ## Example
+                /// ```rust
+                /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+                /// use generic_ec::{Point, curves::{Secp256k1, Secp256r1}, serde::AnyCurvePoint};
+                ///
+                /// let g = Point::<Secp256k1>::generator().to_point();
+                /// let serialized = serde_json::to_string(&g)?;
+                ///
+                /// let point: AnyCurvePoint = serde_json::from_str(&serialized)?;
+                /// assert_eq!(point, AnyCurvePoint::Secp256k1(g));
+                /// assert_eq!(point.curve_name(), "secp256k1");
+                ///
+                /// let g_: Point<Secp256k1> = point.try_into()?;
+                /// assert_eq!(g, g_);
+                /// assert!(Point::<Secp256r1>::try_from(point).is_err());
+                /// # Ok(()) }
+                /// ```
+                #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+                #[non_exhaustive]
+                pub enum AnyCurvePoint {
+                    $(
+                        $(#[$attr])*
+                        #[doc = concat!("Point on [`", stringify!($variant), "`](", stringify!($curve), ") curve")]
+                        $variant(Point<$curve>),
+                    )+
+                }
+
+                impl AnyCurvePoint {
+                    /// Name of the curve the point belongs to
+                    ///
+                    /// Matches [`Curve::CURVE_NAME`]
+                    pub fn curve_name(&self) -> &'static str {
+                        match *self {
+                            $(
+                                $(#[$attr])*
+                                Self::$variant(_) => <$curve as Curve>::CURVE_NAME,
+                            )+
+                        }
+                    }
+                }
+
+                $(
+                    $(#[$attr])*
+                    impl From<Point<$curve>> for AnyCurvePoint {
+                        fn from(point: Point<$curve>) -> Self {
+                            Self::$variant(point)
+                        }
+                    }
+
+                    $(#[$attr])*
+                    impl TryFrom<AnyCurvePoint> for Point<$curve> {
+                        type Error = CurveMismatch;
+                        #[allow(unreachable_patterns)]
+                        fn try_from(point: AnyCurvePoint) -> Result<Self, Self::Error> {
+                            match point {
+                                AnyCurvePoint::$variant(point) => Ok(point),
+                                point => Err(CurveMismatch::new(
+                                    <$curve as Curve>::CURVE_NAME,
+                                    point.curve_name(),
+                                )),
+                            }
+                        }
+                    }
+                )+
+
+                impl serde::Serialize for AnyCurvePoint {
+                    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                    where
+                        S: serde::Serializer,
+                    {
+                        match self {
+                            $(
+                                $(#[$attr])*
+                                Self::$variant(point) => point.serialize(serializer),
+                            )+
+                        }
+                    }
+                }
+
+                /// Curve which point is being deserialized
+                #[derive(Clone, Copy)]
+                enum CurveTag {
+                    $(
+                        $(#[$attr])*
+                        $variant,
+                    )+
+                }
+
+                impl CurveTag {
+                    const NAMES: &'static [&'static str] = &[
+                        $(
+                            $(#[$attr])*
+                            <$curve as Curve>::CURVE_NAME,
+                        )+
+                    ];
+                    const UNCOMPRESSED_LENS: &'static [usize] = &[
+                        $(
+                            $(#[$attr])*
+                            <<$curve as Curve>::UncompressedPointArray as ByteArray>::LEN,
+                        )+
+                    ];
+
+                    fn from_name(name: &str) -> Option<Self> {
+                        $(
+                            $(#[$attr])*
+                            if name == <$curve as Curve>::CURVE_NAME {
+                                return Some(Self::$variant);
+                            }
+                        )+
+                        None
+                    }
+
+                    fn uncompressed_len(self) -> usize {
+                        match self {
+                            $(
+                                $(#[$attr])*
+                                Self::$variant => {
+                                    <<$curve as Curve>::UncompressedPointArray as ByteArray>::LEN
+                                }
+                            )+
+                        }
+                    }
+
+                    fn decode(self, bytes: &[u8]) -> Result<AnyCurvePoint, InvalidPoint> {
+                        match self {
+                            $(
+                                $(#[$attr])*
+                                Self::$variant => {
+                                    Point::<$curve>::from_bytes(bytes).map(AnyCurvePoint::$variant)
+                                }
+                            )+
+                        }
+                    }
+                }
+
+                const _: () = {
+                    $(
+                        $(#[$attr])*
+                        assert!(
+                            <<$curve as Curve>::UncompressedPointArray as ByteArray>::LEN
+                                <= MAX_UNCOMPRESSED_LEN
+                        );
+                    )+
+                };
+            };
+        }
+
+        any_curve_point! {
+            #[cfg(feature = "curve-secp256k1")]
+            Secp256k1 => crate::curves::Secp256k1,
+            #[cfg(feature = "curve-secp256r1")]
+            Secp256r1 => crate::curves::Secp256r1,
+            #[cfg(feature = "curve-secp384r1")]
+            Secp384r1 => crate::curves::Secp384r1,
+            #[cfg(feature = "curve-sm2")]
+            Sm2 => crate::curves::Sm2,
+            #[cfg(feature = "curve-stark")]
+            Stark => crate::curves::Stark,
+            #[cfg(feature = "curve-ed25519")]
+            Ed25519 => crate::curves::Ed25519,
+            #[cfg(feature = "curve-ristretto255")]
+            Ristretto255 => crate::curves::Ristretto255,
+            #[cfg(feature = "curve-bls12-381")]
+            Bls12_381G1 => crate::curves::Bls12_381G1,
+            #[cfg(feature = "curve-bls12-381")]
+            Bls12_381G2 => crate::curves::Bls12_381G2,
+        }
+
+        impl<'de> serde::Deserialize<'de> for CurveTag {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct CurveTagVisitor;
+                impl Visitor<'_> for CurveTagVisitor {
+                    type Value = CurveTag;
+                    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                        f.write_str("name of supported curve")
+                    }
+                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+                    where
+                        E: de::Error,
+                    {
+                        CurveTag::from_name(v).ok_or_else(|| E::unknown_variant(v, CurveTag::NAMES))
+                    }
+                }
+                deserializer.deserialize_str(CurveTagVisitor)
+            }
+        }
+
+        /// Fields of serialized point, see [`super::models::PointUncompressed`]
+        #[derive(serde::Deserialize)]
+        #[serde(field_identifier, rename_all = "lowercase")]
+        enum Field {
+            Curve,
+            Point,
+            #[serde(other)]
+            Other,
+        }
+
+        /// Deserializes uncompressed point encoding which length is one of specified
+        struct PointBytes<'l>(&'l [usize]);
+
+        impl<'de> DeserializeSeed<'de> for PointBytes<'_> {
+            type Value = ([u8; MAX_UNCOMPRESSED_LEN], usize);
+            fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                super::utils::deserialize_bytes_of_len(deserializer, self.0)
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for AnyCurvePoint {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct AnyCurvePointVisitor;
+                impl<'de> Visitor<'de> for AnyCurvePointVisitor {
+                    type Value = AnyCurvePoint;
+                    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                        f.write_str("point on any supported curve")
+                    }
+                    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+                    where
+                        A: de::SeqAccess<'de>,
+                    {
+                        let curve: CurveTag = seq
+                            .next_element()?
+                            .ok_or_else(|| <A::Error as de::Error>::invalid_length(0, &self))?;
+                        let (bytes, len) = seq
+                            .next_element_seed(PointBytes(&[curve.uncompressed_len()]))?
+                            .ok_or_else(|| <A::Error as de::Error>::invalid_length(1, &self))?;
+                        curve
+                            .decode(&bytes[..len])
+                            .map_err(<A::Error as de::Error>::custom)
+                    }
+                    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+                    where
+                        A: de::MapAccess<'de>,
+                    {
+                        let mut curve: Option<CurveTag> = None;
+                        let mut point = None;
+                        while let Some(field) = map.next_key()? {
+                            match field {
+                                Field::Curve if curve.is_some() => {
+                                    return Err(de::Error::duplicate_field("curve"))
+                                }
+                                Field::Curve => curve = Some(map.next_value()?),
+                                Field::Point if point.is_some() => {
+                                    return Err(de::Error::duplicate_field("point"))
+                                }
+                                Field::Point => {
+                                    // If curve is not known yet, we accept encoding of any length
+                                    // and check that it matches the curve later
+                                    let expected_len;
+                                    let expected_lens = match curve {
+                                        Some(curve) => {
+                                            expected_len = [curve.uncompressed_len()];
+                                            &expected_len[..]
+                                        }
+                                        None => CurveTag::UNCOMPRESSED_LENS,
+                                    };
+                                    point = Some(map.next_value_seed(PointBytes(expected_lens))?)
+                                }
+                                Field::Other => {
+                                    map.next_value::<de::IgnoredAny>()?;
+                                }
+                            }
+                        }
+                        let curve = curve.ok_or_else(|| de::Error::missing_field("curve"))?;
+                        let (bytes, len) =
+                            point.ok_or_else(|| de::Error::missing_field("point"))?;
+                        curve.decode(&bytes[..len]).map_err(de::Error::custom)
+                    }
+                }
+                deserializer.deserialize_struct(
+                    "PointUncompressed",
+                    &["curve", "point"],
+                    AnyCurvePointVisitor,
+                )
+            }
+        }
+    }
+
     mod models {
         use core::convert::TryFrom;
 
@@ -542,6 +1195,30 @@ mod optional {
             }
         }
 
+        #[serde_as]
+        #[derive(Serialize, Deserialize)]
+        #[serde(bound = "")]
+        pub struct PointCompressedTagged<E: Curve> {
+            curve: CurveName<E>,
+            #[serde_as(as = "super::utils::Bytes")]
+            point: E::CompressedPointArray,
+        }
+        impl<E: Curve> From<&Point<E>> for PointCompressedTagged<E> {
+            fn from(p: &Point<E>) -> Self {
+                let bytes = p.as_raw().to_bytes_compressed();
+                Self {
+                    curve: CurveName::new(),
+                    point: bytes,
+                }
+            }
+        }
+        impl<E: Curve> TryFrom<PointCompressedTagged<E>> for Point<E> {
+            type Error = InvalidPoint;
+            fn try_from(value: PointCompressedTagged<E>) -> Result<Self, Self::Error> {
+                Point::from_bytes(value.point).or(Err(InvalidPoint))
+            }
+        }
+
         #[serde_as]
         #[derive(Serialize, Deserialize)]
         #[serde(bound = "")]
@@ -601,9 +1278,46 @@ mod optional {
                 Scalar::from_be_bytes(&value.0).or(Err(InvalidScalar))
             }
         }
+        #[serde_as]
+        #[derive(Serialize, Deserialize)]
+        #[serde(bound = "")]
+        pub struct PointFixed<E: Curve>(
+            #[serde_as(as = "super::utils::FixedBytes")] E::CompressedPointArray,
+        );
+        impl<E: Curve> From<&Point<E>> for PointFixed<E> {
+            fn from(p: &Point<E>) -> Self {
+                let bytes = p.as_raw().to_bytes_compressed();
+                Self(bytes)
+            }
+        }
+        impl<E: Curve> TryFrom<PointFixed<E>> for Point<E> {
+            type Error = InvalidPoint;
+            fn try_from(value: PointFixed<E>) -> Result<Self, Self::Error> {
+                Point::from_bytes(value.0).or(Err(InvalidPoint))
+            }
+        }
+
+        #[serde_as]
+        #[derive(Serialize, Deserialize)]
+        #[serde(bound = "")]
+        pub struct ScalarFixed<E: Curve>(
+            #[serde_as(as = "super::utils::FixedBytes")] E::ScalarArray,
+        );
+        impl<E: Curve> From<&Scalar<E>> for ScalarFixed<E> {
+            fn from(s: &Scalar<E>) -> Self {
+                let bytes = s.as_raw().to_be_bytes();
+                Self(bytes)
+            }
+        }
+        impl<E: Curve> TryFrom<ScalarFixed<E>> for Scalar<E> {
+            type Error = InvalidScalar;
+            fn try_from(value: ScalarFixed<E>) -> Result<Self, Self::Error> {
+                Scalar::from_be_bytes(&value.0).or(Err(InvalidScalar))
+            }
+        }
     }
 
-    mod utils {
+    pub(crate) mod utils {
         use core::fmt;
 
         use serde::de::{self, Visitor};
@@ -622,9 +1336,10 @@ mod optional {
                 S: serde::Serializer,
             {
                 if serializer.is_human_readable() {
-                    // We only support serialization of byte arrays up to 128 bytes. It can be generalized when
+                    // We only support serialization of byte arrays up to 256 bytes, which fits the largest
+                    // point encoding (uncompressed BLS12-381 G2 point). It can be generalized when
                     // Rust has better support of const generics
-                    let mut buf = [0u8; 256];
+                    let mut buf = [0u8; 512];
 
                     if source.as_ref().len() * 2 > buf.len() {
                         return Err(<S::Error as serde::ser::Error>::custom(
@@ -722,6 +1437,162 @@ mod optional {
                 }
             }
         }
+
+        /// Same as [`Bytes`], but in non human-readable formats serializes byte array as a tuple
+        /// instead of bytes, so its length is not written
+        pub struct FixedBytes;
+
+        impl<T> SerializeAs<T> for FixedBytes
+        where
+            T: AsRef<[u8]>,
+        {
+            fn serialize_as<S>(source: &T, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                use serde::ser::SerializeTuple;
+
+                if serializer.is_human_readable() {
+                    return Bytes::serialize_as(source, serializer);
+                }
+                let bytes = source.as_ref();
+                let mut tuple = serializer.serialize_tuple(bytes.len())?;
+                for byte in bytes {
+                    tuple.serialize_element(byte)?;
+                }
+                tuple.end()
+            }
+        }
+
+        impl<'de, T> DeserializeAs<'de, T> for FixedBytes
+        where
+            T: ByteArray,
+        {
+            fn deserialize_as<D>(deserializer: D) -> Result<T, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct TupleVisitor<T>(T);
+                impl<'de, T: AsMut<[u8]>> Visitor<'de> for TupleVisitor<T> {
+                    type Value = T;
+                    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                        write!(f, "tuple of bytes")
+                    }
+                    fn visit_seq<A>(mut self, mut seq: A) -> Result<Self::Value, A::Error>
+                    where
+                        A: serde::de::SeqAccess<'de>,
+                    {
+                        let expected_len = self.0.as_mut().len();
+                        for (i, byte_i) in self.0.as_mut().iter_mut().enumerate() {
+                            *byte_i = seq.next_element()?.ok_or_else(|| {
+                                <A::Error as de::Error>::invalid_length(
+                                    i,
+                                    &super::error_msg::ExpectedLen(expected_len),
+                                )
+                            })?;
+                        }
+                        Ok(self.0)
+                    }
+                }
+
+                if deserializer.is_human_readable() {
+                    Bytes::deserialize_as(deserializer)
+                } else {
+                    deserializer.deserialize_tuple(T::LEN, TupleVisitor(T::zeroes()))
+                }
+            }
+        }
+
+        /// Deserializes bytes which length is one of `expected_lens`
+        ///
+        /// Bytes are deserialized in the same format as [`Bytes`] produces. They're written into
+        /// the beginning of `T`, returns `T` along with number of bytes written. `T` must be large
+        /// enough to fit any of expected lengths.
+        pub fn deserialize_bytes_of_len<'de, D, T>(
+            deserializer: D,
+            expected_lens: &[usize],
+        ) -> Result<(T, usize), D::Error>
+        where
+            D: serde::Deserializer<'de>,
+            T: ByteArray,
+        {
+            struct BytesVisitor<'l, T> {
+                buf: T,
+                expected_lens: &'l [usize],
+            }
+            impl<T> BytesVisitor<'_, T> {
+                fn check_len<E: de::Error>(&self, len: usize) -> Result<(), E> {
+                    if self.expected_lens.contains(&len) {
+                        Ok(())
+                    } else {
+                        Err(E::invalid_length(
+                            len,
+                            &super::error_msg::ExpectedLens(self.expected_lens),
+                        ))
+                    }
+                }
+            }
+            impl<'de, T: AsMut<[u8]>> Visitor<'de> for BytesVisitor<'_, T> {
+                type Value = (T, usize);
+                fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    write!(f, "bytes")
+                }
+                fn visit_str<E>(mut self, v: &str) -> Result<Self::Value, E>
+                where
+                    E: serde::de::Error,
+                {
+                    let len = v.len() / 2;
+                    self.check_len(len)?;
+                    let buf = self
+                        .buf
+                        .as_mut()
+                        .get_mut(..len)
+                        .ok_or_else(|| E::invalid_length(len, &"bytes fitting the buffer"))?;
+                    hex::decode_to_slice(v, buf).map_err(E::custom)?;
+                    Ok((self.buf, len))
+                }
+                fn visit_bytes<E>(mut self, v: &[u8]) -> Result<Self::Value, E>
+                where
+                    E: serde::de::Error,
+                {
+                    self.check_len(v.len())?;
+                    self.buf
+                        .as_mut()
+                        .get_mut(..v.len())
+                        .ok_or_else(|| E::invalid_length(v.len(), &"bytes fitting the buffer"))?
+                        .copy_from_slice(v);
+                    Ok((self.buf, v.len()))
+                }
+                fn visit_seq<A>(mut self, mut seq: A) -> Result<Self::Value, A::Error>
+                where
+                    A: serde::de::SeqAccess<'de>,
+                {
+                    let mut len = 0;
+                    while let Some(byte) = seq.next_element::<u8>()? {
+                        let byte_i = self.buf.as_mut().get_mut(len).ok_or_else(|| {
+                            <A::Error as de::Error>::invalid_length(
+                                len + 1,
+                                &super::error_msg::ExpectedLens(self.expected_lens),
+                            )
+                        })?;
+                        *byte_i = byte;
+                        len += 1;
+                    }
+                    self.check_len(len)?;
+                    Ok((self.buf, len))
+                }
+            }
+
+            let visitor = BytesVisitor {
+                buf: T::zeroes(),
+                expected_lens,
+            };
+            if deserializer.is_human_readable() {
+                deserializer.deserialize_str(visitor)
+            } else {
+                deserializer.deserialize_bytes(visitor)
+            }
+        }
     }
 
     pub(super) mod error_msg {
@@ -753,6 +1624,20 @@ mod optional {
             }
         }
 
+        pub struct ExpectedLens<'l>(pub &'l [usize]);
+
+        impl Expected for ExpectedLens<'_> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                for (i, len) in self.0.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(" or ")?;
+                    }
+                    write!(f, "{len}")?;
+                }
+                f.write_str(" bytes")
+            }
+        }
+
         pub struct InvalidPoint;
         impl fmt::Display for InvalidPoint {
             fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -0,0 +1,110 @@
+//! Reproducible test data
+//!
+//! Provides [`DeterministicSequence`] which derives a sequence of scalars and points from
+//! a seed, and maintains a running hash of everything it has produced. Two runs with the same
+//! seed, curve and hash function produce the same sequence, so tests can store a
+//! [transcript hash](DeterministicSequence::transcript_hash) and detect when generated data
+//! changes.
+//!
+//! Elements are derived via [`Scalar::from_hash`], which relies on [`udigest`] encoding and
+//! [`HashRng`](rand_hash::HashRng). Neither is specified outside of Rust crates, so the sequence
+//! is only reproducible by this library: it's not suitable for test vectors shared with
+//! implementations in other languages.
+//!
+//! Generated values are not secret, and the sequence must not be used for anything other than
+//! testing.
+//!
+//! ## Example
+//! ```rust
+//! use generic_ec::{curves::Secp256k1, test_harness::DeterministicSequence};
+//! use sha2::Sha256;
+//!
+//! let mut run1 = DeterministicSequence::<Secp256k1, Sha256>::new(b"test vectors");
+//! let mut run2 = DeterministicSequence::<Secp256k1, Sha256>::new(b"test vectors");
+//!
+//! for _ in 0..10 {
+//!     assert_eq!(run1.next_scalar(), run2.next_scalar());
+//!     assert_eq!(run1.next_point(), run2.next_point());
+//!     assert_eq!(run1.transcript_hash(), run2.transcript_hash());
+//! }
+//!
+//! // Different seed gives a different sequence
+//! let mut run3 = DeterministicSequence::<Secp256k1, Sha256>::new(b"other test vectors");
+//! assert_ne!(run1.next_scalar(), run3.next_scalar());
+//! ```
+
+use core::marker::PhantomData;
+
+use digest::Digest;
+
+use crate::{Curve, Point, Scalar};
+
+/// Deterministic sequence of scalars and points derived from a seed
+///
+/// See [module-level docs](self) for details.
+pub struct DeterministicSequence<E: Curve, D: Digest> {
+    seed: digest::Output<D>,
+    counter: u64,
+    transcript: D,
+    _curve: PhantomData<E>,
+}
+
+/// Data hashed to derive $i$-th element of the sequence
+#[derive(udigest::Digestable)]
+struct Step<'a> {
+    curve: &'a str,
+    seed: &'a [u8],
+    counter: u64,
+}
+
+impl<E: Curve, D: Digest> DeterministicSequence<E, D> {
+    /// Constructs a sequence from the seed
+    pub fn new(seed: &[u8]) -> Self {
+        Self {
+            seed: D::digest(seed),
+            counter: 0,
+            transcript: D::new(),
+            _curve: PhantomData,
+        }
+    }
+
+    /// Returns the next scalar of the sequence
+    ///
+    /// Scalar is derived via [`Scalar::from_hash`], i.e. using
+    /// [`HashRng`](rand_hash::HashRng) seeded by the sequence seed and the step number.
+    pub fn next_scalar(&mut self) -> Scalar<E> {
+        let scalar = self.derive_scalar();
+        self.transcript.update(scalar.to_be_bytes());
+        scalar
+    }
+
+    /// Returns the next point of the sequence
+    ///
+    /// Point is obtained by multiplying the curve generator at the scalar derived in the
+    /// same way as in [`.next_scalar()`](Self::next_scalar).
+    pub fn next_point(&mut self) -> Point<E> {
+        let point = Point::generator() * self.derive_scalar();
+        self.transcript.update(point.to_bytes(true));
+        point
+    }
+
+    /// Returns hash of all the scalars and points produced so far
+    ///
+    /// Scalars are hashed in big-endian encoding, points are hashed in compressed form.
+    pub fn transcript_hash(&self) -> digest::Output<D>
+    where
+        D: Clone,
+    {
+        self.transcript.clone().finalize()
+    }
+
+    fn derive_scalar(&mut self) -> Scalar<E> {
+        let scalar = Scalar::from_hash::<D>(&Step {
+            curve: E::CURVE_NAME,
+            seed: &self.seed,
+            counter: self.counter,
+        });
+        self.counter += 1;
+        scalar
+    }
+}
@@ -0,0 +1,86 @@
+use generic_ec::{curves, Curve, NonZero, Point, PrecomputedBase, Scalar};
+use rand::{CryptoRng, RngCore};
+
+criterion::criterion_main!(benches);
+criterion::criterion_group!(benches, generator_table);
+
+fn generator_table(c: &mut criterion::Criterion) {
+    let mut rng = rand_dev::DevRng::new();
+
+    generator_table_for_curve::<curves::Secp256k1>(c, &mut rng, "secp256k1");
+    generator_table_for_curve::<curves::Secp256r1>(c, &mut rng, "secp256r1");
+    generator_table_for_curve::<curves::Stark>(c, &mut rng, "stark");
+    generator_table_for_curve::<curves::Ed25519>(c, &mut rng, "ed25519");
+}
+
+/// Compares generator multiplication with and without precomputed table
+fn generator_table_for_curve<E: Curve>(
+    c: &mut criterion::Criterion,
+    rng: &mut (impl RngCore + CryptoRng),
+    curve_name: &str,
+) {
+    let mut g = c.benchmark_group(format!("generator_mul/{curve_name}"));
+
+    g.bench_function("generator", |b| {
+        b.iter_batched(
+            || Scalar::<E>::random(rng),
+            |s| Point::generator() * s,
+            criterion::BatchSize::SmallInput,
+        )
+    });
+
+    let table = Point::<E>::generator_table();
+    g.bench_function("table", |b| {
+        b.iter_batched(
+            || Scalar::<E>::random(rng),
+            |s| table.mul(&s),
+            criterion::BatchSize::SmallInput,
+        )
+    });
+
+    g.bench_function("table_precomputation", |b| {
+        b.iter(Point::<E>::generator_table)
+    });
+    g.finish();
+
+    let mut g = c.benchmark_group(format!("fixed_base_mul/{curve_name}"));
+
+    let base = Point::generator() * NonZero::<Scalar<E>>::random(rng);
+    g.bench_function("point", |b| {
+        b.iter_batched(
+            || Scalar::<E>::random(rng),
+            |s| base * s,
+            criterion::BatchSize::SmallInput,
+        )
+    });
+
+    let table = PrecomputedBase::new(base);
+    g.bench_function("table", |b| {
+        b.iter_batched(
+            || Scalar::<E>::random(rng),
+            |s| table.mul(&s),
+            criterion::BatchSize::SmallInput,
+        )
+    });
+    g.finish();
+
+    let mut g = c.benchmark_group(format!("mul_base_add/{curve_name}"));
+
+    let p = Point::generator() * Scalar::<E>::random(rng);
+    let generator = Point::<E>::generator().to_point();
+    g.bench_function("naive", |b| {
+        b.iter_batched(
+            || Scalar::<E>::random(rng),
+            |s| generator * s + p,
+            criterion::BatchSize::SmallInput,
+        )
+    });
+    g.bench_function("mul_base_add", |b| {
+        b.iter_batched(
+            || Scalar::<E>::random(rng),
+            |s| Point::mul_base_add(&s, &p),
+            criterion::BatchSize::SmallInput,
+        )
+    });
+    g.finish();
+}
@@ -0,0 +1,77 @@
+use core::iter;
+
+use generic_ec::{
+    curves,
+    multiscalar::{self, MultiscalarMul},
+    Curve, Point, Scalar,
+};
+use rand::{CryptoRng, RngCore};
+use rayon::prelude::*;
+
+criterion::criterion_main!(benches);
+criterion::criterion_group!(benches, multiscalar_parallel);
+
+fn multiscalar_parallel(c: &mut criterion::Criterion) {
+    let mut rng = rand_dev::DevRng::new();
+
+    multiscalar_parallel_for_curve::<curves::Secp256k1>(c, &mut rng, "secp256k1");
+    multiscalar_parallel_for_curve::<curves::Ed25519>(c, &mut rng, "ed25519");
+}
+
+/// Measures how [`multiscalar::Parallel`] scales with number of available threads
+fn multiscalar_parallel_for_curve<E: Curve>(
+    c: &mut criterion::Criterion,
+    rng: &mut (impl RngCore + CryptoRng),
+    curve_name: &str,
+) {
+    let max_threads = std::thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(1);
+    let threads = iter::successors(Some(1), |n| Some(n * 2))
+        .take_while(|n| *n < max_threads)
+        .chain(iter::once(max_threads));
+
+    for n in [1_000, 5_000, 20_000] {
+        let scalar_points = iter::repeat_with(|| {
+            (
+                Scalar::<E>::random(rng),
+                Point::generator() * Scalar::<E>::random(rng),
+            )
+        })
+        .take(n)
+        .collect::<Vec<_>>();
+
+        c.bench_function(&format!("multiscalar_mul/straus/{curve_name}/n{n}"), |b| {
+            b.iter(|| multiscalar::Straus::multiscalar_mul(scalar_points.iter().copied()))
+        });
+
+        for num_threads in threads.clone() {
+            let pool = rayon::ThreadPoolBuilder::new()
+                .num_threads(num_threads)
+                .build()
+                .unwrap();
+            c.bench_function(
+                &format!("multiscalar_mul/parallel/{curve_name}/n{n}/threads{num_threads}"),
+                |b| {
+                    b.iter(|| {
+                        pool.install(|| {
+                            multiscalar::Parallel::multiscalar_mul(scalar_points.iter().copied())
+                        })
+                    })
+                },
+            );
+            c.bench_function(
+                &format!("multiscalar_mul/par_iter/{curve_name}/n{n}/threads{num_threads}"),
+                |b| {
+                    b.iter(|| {
+                        pool.install(|| {
+                            multiscalar::parallel_multiscalar_mul(
+                                scalar_points.par_iter().map(|(s, p)| (s, p)),
+                            )
+                        })
+                    })
+                },
+            );
+        }
+    }
+}
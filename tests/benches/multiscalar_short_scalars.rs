@@ -0,0 +1,49 @@
+use core::iter;
+
+use generic_ec::{
+    curves,
+    multiscalar::{self, MultiscalarMul},
+    Curve, Point, Scalar,
+};
+use rand::{CryptoRng, RngCore};
+
+criterion::criterion_main!(benches);
+criterion::criterion_group!(benches, multiscalar_short_scalars);
+
+fn multiscalar_short_scalars(c: &mut criterion::Criterion) {
+    let mut rng = rand_dev::DevRng::new();
+
+    multiscalar_short_scalars_for_curve::<curves::Secp256k1>(c, &mut rng, "secp256k1");
+    multiscalar_short_scalars_for_curve::<curves::Ed25519>(c, &mut rng, "ed25519");
+}
+
+/// Compares [`multiscalar::Straus`] performance on full-size scalars and on 128 bits scalars
+/// (e.g. random coefficients used in batch verification)
+fn multiscalar_short_scalars_for_curve<E: Curve>(
+    c: &mut criterion::Criterion,
+    rng: &mut (impl RngCore + CryptoRng),
+    curve_name: &str,
+) {
+    for n in [10, 25, 50, 100] {
+        let points = iter::repeat_with(|| Point::generator() * Scalar::<E>::random(rng))
+            .take(n)
+            .collect::<Vec<_>>();
+        let full_scalars = iter::repeat_with(|| Scalar::<E>::random(rng))
+            .take(n)
+            .collect::<Vec<_>>();
+        let short_scalars = iter::repeat_with(|| {
+            let mut bytes = [0u8; 16];
+            rng.fill_bytes(&mut bytes);
+            Scalar::<E>::from_be_bytes_mod_order(bytes)
+        })
+        .take(n)
+        .collect::<Vec<_>>();
+
+        for (scalars, bits) in [(&full_scalars, "full"), (&short_scalars, "128bits")] {
+            c.bench_function(
+                &format!("multiscalar_mul/straus/{curve_name}/{bits}/n{n}"),
+                |b| b.iter(|| multiscalar::Straus::multiscalar_mul(scalars.iter().zip(&points))),
+            );
+        }
+    }
+}
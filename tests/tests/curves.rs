@@ -2,9 +2,12 @@
 
 #[generic_tests::define]
 mod tests {
-    use generic_ec::{curves::*, Curve, EncodedScalar, Point, Scalar};
+    use core::iter;
+
+    use generic_ec::{curves::*, Curve, EncodedScalar, NonZero, Point, Scalar, SecretScalar};
     use rand::{Rng, RngCore};
     use rand_dev::DevRng;
+    use subtle::Choice;
 
     #[test]
     fn scalar_bytes<E: Curve>() {
@@ -25,6 +28,415 @@ mod tests {
         assert_eq!(be_bytes_rev, scalar_bytes_le);
     }
 
+    #[test]
+    fn scalar_hex_round_trip<E: Curve>() {
+        use generic_ec::errors::ParseScalarErrorReason;
+
+        let mut rng = DevRng::new();
+        for scalar in [Scalar::<E>::zero(), Scalar::one(), Scalar::random(&mut rng)] {
+            let hex = scalar.to_string();
+            assert_eq!(hex.len(), 2 * Scalar::<E>::serialized_len());
+            assert_eq!(hex, hex::encode(scalar.to_be_bytes()));
+            assert_eq!(hex.parse::<Scalar<E>>().unwrap(), scalar);
+            assert_eq!(hex.to_uppercase().parse::<Scalar<E>>().unwrap(), scalar);
+
+            assert_eq!(format!("{scalar:x}"), hex);
+            assert_eq!(format!("{scalar:#x}"), format!("0x{hex}"));
+            assert_eq!(format!("{scalar:X}"), hex.to_uppercase());
+            assert_eq!(format!("{scalar:#X}"), format!("0x{}", hex.to_uppercase()));
+        }
+
+        assert_eq!("01".parse::<Scalar<E>>().unwrap(), Scalar::one());
+
+        let reason = |s: &str| s.parse::<Scalar<E>>().unwrap_err().reason();
+        assert_eq!(reason("1"), ParseScalarErrorReason::OddLength);
+        assert_eq!(reason("0g"), ParseScalarErrorReason::InvalidCharacter);
+        let too_long = "00".repeat(Scalar::<E>::serialized_len() + 1);
+        assert_eq!(reason(&too_long), ParseScalarErrorReason::TooLong);
+        let max = "ff".repeat(Scalar::<E>::serialized_len());
+        assert_eq!(reason(&max), ParseScalarErrorReason::OutOfRange);
+    }
+
+    #[test]
+    fn scalar_serialized_len<E: Curve>() {
+        let mut rng = DevRng::new();
+        let scalar = Scalar::<E>::random(&mut rng);
+
+        assert_eq!(Scalar::<E>::SERIALIZED_LEN, Scalar::<E>::serialized_len());
+        assert_eq!(Scalar::<E>::SERIALIZED_LEN, scalar.to_be_bytes().len());
+        assert_eq!(Scalar::<E>::SERIALIZED_LEN, scalar.to_le_bytes().len());
+    }
+
+    #[test]
+    fn validate_public_key<E: Curve>() {
+        use generic_ec::errors::PublicKeyError;
+
+        let mut rng = DevRng::new();
+        let pk = Point::<E>::generator() * NonZero::<Scalar<E>>::random(&mut rng);
+
+        for compressed in [true, false] {
+            let decoded = Point::<E>::validate_public_key(pk.to_bytes(compressed)).unwrap();
+            assert_eq!(decoded, pk);
+
+            let identity = Point::<E>::zero().to_bytes(compressed);
+            assert!(matches!(
+                Point::<E>::validate_public_key(&identity),
+                Err(PublicKeyError::Identity)
+            ));
+        }
+
+        let mut truncated = pk.to_bytes(true).to_vec();
+        truncated.pop();
+        assert!(matches!(
+            Point::<E>::validate_public_key(&truncated),
+            Err(PublicKeyError::Malformed(_))
+        ));
+    }
+
+    #[test]
+    fn non_zero_from_bytes<E: Curve>() {
+        use generic_ec::errors::{InvalidNonZeroScalar, InvalidPointReason};
+
+        let mut rng = DevRng::new();
+
+        let point = Point::<E>::generator() * NonZero::<Scalar<E>>::random(&mut rng);
+        for compressed in [true, false] {
+            let decoded = NonZero::<Point<E>>::from_bytes(point.to_bytes(compressed)).unwrap();
+            assert_eq!(decoded, point);
+
+            let identity = Point::<E>::zero().to_bytes(compressed);
+            let err = NonZero::<Point<E>>::from_bytes(&identity).unwrap_err();
+            assert_eq!(err.reason(), InvalidPointReason::Identity);
+        }
+        let err = NonZero::<Point<E>>::from_bytes([0xff]).unwrap_err();
+        assert_ne!(err.reason(), InvalidPointReason::Identity);
+
+        let scalar = NonZero::<Scalar<E>>::random(&mut rng);
+        let decoded = NonZero::<Scalar<E>>::from_be_bytes(scalar.to_be_bytes()).unwrap();
+        assert_eq!(decoded, scalar);
+        let decoded = NonZero::<Scalar<E>>::from_le_bytes(scalar.to_le_bytes()).unwrap();
+        assert_eq!(decoded, scalar);
+
+        let zero = Scalar::<E>::zero();
+        assert!(matches!(
+            NonZero::<Scalar<E>>::from_be_bytes(zero.to_be_bytes()),
+            Err(InvalidNonZeroScalar::Zero)
+        ));
+        assert!(matches!(
+            NonZero::<Scalar<E>>::from_le_bytes(zero.to_le_bytes()),
+            Err(InvalidNonZeroScalar::Zero)
+        ));
+        let too_long = [0xff; 100];
+        assert!(matches!(
+            NonZero::<Scalar<E>>::from_be_bytes(too_long),
+            Err(InvalidNonZeroScalar::Invalid(_))
+        ));
+    }
+
+    #[test]
+    fn non_zero_scalar_as_map_key<E: Curve>() {
+        let mut rng = DevRng::new();
+
+        let keys = iter::repeat_with(|| NonZero::<Scalar<E>>::random(&mut rng))
+            .take(20)
+            .collect::<Vec<_>>();
+        let shares = keys
+            .iter()
+            .enumerate()
+            .map(|(i, k)| (*k, i))
+            .collect::<std::collections::BTreeMap<_, _>>();
+
+        assert_eq!(shares.len(), keys.len());
+        for (i, k) in keys.iter().enumerate() {
+            assert_eq!(shares[k], i);
+        }
+
+        // Ordering of `NonZero` is consistent with ordering of inner scalars
+        let mut sorted = keys.clone();
+        sorted.sort();
+        assert!(sorted.iter().copied().eq(shares.keys().copied()));
+        let mut sorted_inner = keys.iter().map(|k| k.into_inner()).collect::<Vec<_>>();
+        sorted_inner.sort();
+        assert!(sorted.iter().map(|k| k.into_inner()).eq(sorted_inner));
+    }
+
+    #[test]
+    fn non_zero_point_checked_add<E: Curve>() {
+        let mut rng = DevRng::new();
+
+        let p = Point::<E>::generator() * NonZero::<Scalar<E>>::random(&mut rng);
+        let q = Point::<E>::generator() * NonZero::<Scalar<E>>::random(&mut rng);
+
+        assert_eq!(p.checked_add(&q).unwrap(), *p + *q);
+        assert_eq!(p.checked_sub(&q).unwrap(), *p - *q);
+        assert_eq!(p.checked_add(&-p), None);
+        assert_eq!(p.checked_sub(&p), None);
+        assert_eq!(p.checked_add(&Point::zero()), Some(p));
+    }
+
+    #[test]
+    fn arbitrary_scalar_and_point<E: Curve>() {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        let mut rng = DevRng::new();
+        let mut data = [0u8; 1024];
+        rng.fill_bytes(&mut data);
+
+        let mut u = Unstructured::new(&data);
+        while !u.is_empty() {
+            let scalar = Scalar::<E>::arbitrary(&mut u).unwrap();
+            let point = Point::<E>::arbitrary(&mut u).unwrap();
+            // Generated scalar and point must round-trip through their encodings
+            assert_eq!(
+                Scalar::<E>::from_be_bytes(scalar.to_be_bytes()).unwrap(),
+                scalar
+            );
+            assert_eq!(Point::<E>::from_bytes(point.to_bytes(true)).unwrap(), point);
+        }
+
+        // Generation doesn't fail when data is exhausted
+        let mut u = Unstructured::new(&[]);
+        assert_eq!(Scalar::<E>::arbitrary(&mut u).unwrap(), Scalar::zero());
+        assert!(Point::<E>::arbitrary(&mut u).unwrap().is_zero());
+
+        // Scalar is a big-endian integer reduced modulo curve order
+        let bytes = [0xffu8; 8];
+        let mut u = Unstructured::new(&bytes);
+        assert_eq!(
+            Scalar::<E>::arbitrary(&mut u).unwrap(),
+            Scalar::from(u64::MAX)
+        );
+    }
+
+    #[test]
+    fn from_digest_output<E: Curve>() {
+        use sha2::{Digest, Sha256, Sha384, Sha512};
+
+        /// Checks `from_digest_output` against naive reduction. Output lengths of SHA-256,
+        /// SHA-384 and SHA-512 cover both `Reduce<N>` fast path and chunk-by-chunk reduction
+        /// for every curve.
+        fn check<E: Curve, D: Digest>(data: &[u8]) {
+            let hash = D::digest(data);
+            let expected = super::naive_scalar_from_be_bytes_mod_order::<E>(&hash);
+            assert_eq!(Scalar::<E>::from_digest_output::<D>(hash.clone()), expected);
+            assert_eq!(Scalar::<E>::from_be_bytes_mod_order(hash), expected);
+        }
+
+        let mut rng = DevRng::new();
+        let mut data = [0u8; 100];
+        rng.fill_bytes(&mut data);
+
+        check::<E, Sha256>(&data);
+        check::<E, Sha384>(&data);
+        check::<E, Sha512>(&data);
+    }
+
+    #[test]
+    fn scalar_bits<E: Curve>() {
+        let mut rng = DevRng::new();
+
+        let bits = Scalar::<E>::bits();
+        // Group order takes exactly `bits` bits
+        let order = Scalar::<E>::group_order_be_bytes();
+        let leading_zeroes = order.iter().take_while(|b| **b == 0).count();
+        assert_eq!(
+            bits,
+            8 * (order.len() - leading_zeroes) - order[leading_zeroes].leading_zeros() as usize
+        );
+
+        for s in [
+            Scalar::<E>::zero(),
+            Scalar::one(),
+            -Scalar::one(),
+            Scalar::random(&mut rng),
+            Scalar::random(&mut rng),
+        ] {
+            let two = Scalar::<E>::from(2);
+            let reconstructed = (0..bits).rev().fold(Scalar::<E>::zero(), |acc, i| {
+                acc * two + Scalar::from(s.bit(i).unwrap_u8())
+            });
+            assert_eq!(reconstructed, s);
+
+            // Out of range bits are zero
+            for i in [bits, 8 * Scalar::<E>::serialized_len(), usize::MAX] {
+                assert_eq!(s.bit(i).unwrap_u8(), 0);
+            }
+        }
+
+        // -1 = q - 1 has the most significant bit set
+        assert_eq!((-Scalar::<E>::one()).bit(bits - 1).unwrap_u8(), 1);
+    }
+
+    #[test]
+    fn random_point<E: Curve>() {
+        use generic_ec::traits::Samplable;
+
+        let mut rng = DevRng::new();
+
+        let points = iter::repeat_with(|| Point::<E>::random(&mut rng))
+            .take(20)
+            .collect::<Vec<_>>();
+        for (i, p) in points.iter().enumerate() {
+            assert!(!p.is_zero());
+            // Point belongs to the prime-order subgroup
+            assert_eq!(Point::<E>::from_bytes(p.to_bytes(true)).unwrap(), *p);
+            assert!(points[..i].iter().all(|q| q != p));
+        }
+
+        // Same randomness gives the same point
+        let seed = rng.gen();
+        let rng = || <rand::rngs::StdRng as rand::SeedableRng>::seed_from_u64(seed);
+        let p1 = Point::<E>::random(&mut rng());
+        let p2 = <Point<E> as Samplable>::random(&mut rng());
+        let s = Scalar::<E>::random(&mut rng());
+        assert_eq!(p1, p2);
+        assert_eq!(p1, Point::generator() * s);
+    }
+
+    #[test]
+    fn random_non_zero_point<E: Curve>() {
+        use generic_ec::traits::Samplable;
+
+        let mut rng = DevRng::new();
+
+        for _ in 0..100 {
+            let p = NonZero::<Point<E>>::random(&mut rng);
+            assert_ne!(*p, Point::zero());
+            let p = <NonZero<Point<E>> as Samplable>::random(&mut rng);
+            assert_ne!(*p, Point::zero());
+        }
+    }
+
+    #[test]
+    fn mul_base_add<E: Curve>() {
+        let mut rng = DevRng::new();
+
+        let s = Scalar::<E>::random(&mut rng);
+        let p = Point::<E>::generator() * Scalar::random(&mut rng);
+        let g = Point::<E>::generator().to_point();
+
+        assert_eq!(Point::mul_base_add(&s, &p), g * s + p);
+        assert_eq!(Point::mul_base_add(&s, &Point::zero()), g * s);
+        assert_eq!(Point::mul_base_add(&Scalar::zero(), &p), p);
+        assert!(Point::mul_base_add(&-Scalar::one(), &g).is_zero());
+    }
+
+    #[test]
+    fn conditional_select_from_slice<E: Curve>() {
+        let mut rng = DevRng::new();
+
+        let points: [Point<E>; 7] =
+            core::array::from_fn(|_| Point::generator() * Scalar::random(&mut rng));
+        for (i, point) in points.iter().enumerate() {
+            let selected = Point::conditional_select_from_slice(&points, i as u8);
+            assert_eq!(selected, *point);
+        }
+
+        let out_of_bounds = Point::conditional_select_from_slice(&points, points.len() as u8);
+        assert!(out_of_bounds.is_zero());
+        assert!(Point::<E>::conditional_select_from_slice(&[], 0).is_zero());
+    }
+
+    #[test]
+    fn secret_scalar_bytes_round_trip<E: Curve>() {
+        let mut rng = DevRng::new();
+
+        for secret in [
+            SecretScalar::<E>::zero(),
+            SecretScalar::one(),
+            SecretScalar::random(&mut rng),
+        ] {
+            let be_bytes = secret.to_be_bytes();
+            let le_bytes = secret.to_le_bytes();
+            assert_eq!(be_bytes, secret.as_ref().to_be_bytes());
+            assert_eq!(le_bytes, secret.as_ref().to_le_bytes());
+
+            let decoded = SecretScalar::<E>::from_be_bytes(&be_bytes).unwrap();
+            assert_eq!(decoded.as_ref(), secret.as_ref());
+            let decoded = SecretScalar::<E>::from_le_bytes(&le_bytes).unwrap();
+            assert_eq!(decoded.as_ref(), secret.as_ref());
+        }
+    }
+
+    #[test]
+    fn encoded_scalar_zeroize<E: Curve>() {
+        use zeroize::{Zeroize, ZeroizeOnDrop};
+
+        fn assert_zeroize_on_drop<T: ZeroizeOnDrop>(_: &T) {}
+
+        let mut rng = DevRng::new();
+        let secret = SecretScalar::<E>::random(&mut rng);
+
+        let mut encoded = secret.to_be_bytes();
+        assert_zeroize_on_drop(&encoded);
+        assert_eq!(encoded, secret.as_ref().to_be_bytes());
+
+        encoded.zeroize();
+        assert!(encoded.iter().all(|b| *b == 0));
+    }
+
+    #[test]
+    fn ct_eq<E: Curve>() {
+        let mut rng = DevRng::new();
+
+        let a = Scalar::<E>::random(&mut rng);
+        let b = a + Scalar::one();
+        assert!(bool::from(a.ct_eq(&a)));
+        assert!(!bool::from(a.ct_eq(&b)));
+
+        let p = Point::generator() * a;
+        let q = Point::generator() * b;
+        assert!(bool::from(p.ct_eq(&p)));
+        assert!(!bool::from(p.ct_eq(&q)));
+        assert!(bool::from(Point::<E>::zero().ct_eq(&Point::zero())));
+        assert!(!bool::from(p.ct_eq(&Point::zero())));
+    }
+
+    #[test]
+    fn lincomb<E: Curve>() {
+        let mut rng = DevRng::new();
+
+        for _ in 0..10 {
+            let a = Scalar::<E>::random(&mut rng);
+            let b = Scalar::<E>::random(&mut rng);
+            let p = Point::generator() * Scalar::random(&mut rng);
+            let q = Point::generator() * Scalar::random(&mut rng);
+
+            assert_eq!(Point::lincomb(a, p, b, q), a * p + b * q);
+            assert_eq!(Point::lincomb(a, p, b, p), (a + b) * p);
+            assert_eq!(Point::lincomb(a, p, b, Point::zero()), a * p);
+            assert_eq!(Point::lincomb(Scalar::zero(), p, b, q), b * q);
+        }
+    }
+
+    #[test]
+    fn scalar_eq_in_collections<E: Curve>() {
+        use std::collections::{BTreeSet, HashMap};
+
+        let mut rng = DevRng::new();
+        let scalars = iter::repeat_with(|| Scalar::<E>::random(&mut rng))
+            .take(20)
+            .collect::<Vec<_>>();
+
+        let map = scalars
+            .iter()
+            .enumerate()
+            .map(|(i, s)| (*s, i))
+            .collect::<HashMap<_, _>>();
+        assert_eq!(map.len(), scalars.len());
+        for (i, s) in scalars.iter().enumerate() {
+            // Scalar equal to `s` but computed differently
+            let s_copy = (*s + Scalar::one()) - Scalar::one();
+            assert_eq!(s, &s_copy);
+            assert_eq!(map.get(&s_copy), Some(&i));
+        }
+        assert_eq!(map.get(&(scalars[0] + Scalar::one())), None);
+
+        let set = scalars.iter().chain(&scalars).collect::<BTreeSet<_>>();
+        assert_eq!(set.len(), scalars.len());
+        assert_ne!(scalars[0], scalars[1]);
+    }
+
     #[test]
     fn scalar_zero<E: Curve>() {
         let mut rng = DevRng::new();
@@ -86,128 +498,702 @@ mod tests {
     }
 
     #[test]
-    fn point_zero<E: Curve>() {
+    fn scalar_invert_or_zero<E: Curve>() {
         let mut rng = DevRng::new();
 
-        let s = Scalar::<E>::random(&mut rng);
+        let scalars = [Scalar::<E>::one(), -Scalar::one()]
+            .into_iter()
+            .chain(iter::repeat_with(|| Scalar::random(&mut rng)).take(20));
+        for s in scalars {
+            assert_eq!(s * s.invert_or_zero(), Scalar::one());
+            assert_eq!(s.invert_or_zero(), s.invert().unwrap());
+        }
+
+        let zero = Scalar::<E>::zero();
+        assert_eq!(zero.invert_or_zero(), Scalar::zero());
+        assert_eq!(zero * zero.invert_or_zero(), Scalar::zero());
+    }
+
+    #[test]
+    fn group_order<E: Curve>() {
+        let order = Scalar::<E>::group_order_be_bytes();
+        assert_eq!(order.len(), Scalar::<E>::serialized_len());
+        Scalar::<E>::from_be_bytes(&order).unwrap_err();
+        assert_eq!(Scalar::<E>::from_be_bytes_mod_order(&order), Scalar::zero());
+
+        let mut order_minus_one = order;
+        *order_minus_one.as_mut().last_mut().unwrap() -= 1;
         assert_eq!(
-            Point::generator() * s + Point::zero(),
-            Point::generator() * s
+            Scalar::<E>::from_be_bytes(&order_minus_one).unwrap(),
+            -Scalar::one()
         );
-        assert_eq!(s * Point::zero(), Point::zero());
+    }
 
-        assert!(Point::<E>::zero().is_zero());
-        assert!(bool::from(Point::<E>::zero().ct_is_zero()))
+    #[test]
+    fn scalar_pow<E: Curve>() {
+        let mut rng = DevRng::new();
+
+        let s = Scalar::<E>::random(&mut rng);
+        for base in [Scalar::zero(), Scalar::one(), s] {
+            let mut expected = Scalar::<E>::one();
+            for k in 0..50_u64 {
+                assert_eq!(base.pow_u64(k), expected);
+                assert_eq!(base.pow(&Scalar::from(k)), expected);
+                expected *= base;
+            }
+
+            let exp = Scalar::<E>::random(&mut rng);
+            assert_eq!(base.pow(&exp), super::naive_pow(&base, &exp));
+        }
+
+        // Fermat's little theorem: s^(q-1) = 1
+        assert_eq!(s.pow(&-Scalar::one()), Scalar::one());
+        assert_eq!(Scalar::<E>::zero().pow(&Scalar::zero()), Scalar::one());
     }
 
     #[test]
-    fn point_bytes<E: Curve>() {
+    fn scalar_sqrt<E: Curve>()
+    where
+        E::Scalar: generic_ec::core::Sqrt,
+    {
         let mut rng = DevRng::new();
 
-        let random_point = Point::generator() * Scalar::<E>::random(&mut rng);
+        assert_eq!(Scalar::<E>::zero().sqrt(), Some(Scalar::zero()));
+        for _ in 0..20 {
+            let x = Scalar::<E>::random(&mut rng);
+            let r = (x * x).sqrt().unwrap();
+            assert_eq!(r * r, x * x);
+            assert!(r == x || r == -x);
+        }
 
-        for point in [Point::zero(), Point::generator().into(), random_point] {
-            let bytes_compressed = point.to_bytes(true);
-            let bytes_uncompressed = point.to_bytes(false);
-            assert!(bytes_compressed.len() <= bytes_uncompressed.len());
-            assert_eq!(bytes_compressed.len(), Point::<E>::serialized_len(true));
-            assert_eq!(bytes_uncompressed.len(), Point::<E>::serialized_len(false));
+        // Euler's criterion: `s` is a non-residue iff s^((q-1)/2) = -1
+        let exp = -Scalar::<E>::from(2).invert().unwrap();
+        let mut non_residues = 0;
+        for _ in 0..20 {
+            let s = Scalar::<E>::random(&mut rng);
+            let is_residue = s.pow(&exp) != -Scalar::one();
+            assert_eq!(s.sqrt().is_some(), is_residue);
+            assert_eq!(bool::from(s.ct_sqrt().is_some()), is_residue);
+            if !is_residue {
+                non_residues += 1;
+            }
+        }
+        assert!(non_residues > 0);
+    }
 
-            let p1 = Point::<E>::from_bytes(&bytes_compressed).unwrap();
-            let p2 = Point::<E>::from_bytes(&bytes_uncompressed).unwrap();
+    #[test]
+    fn const_mul<E: Curve>() {
+        let mut rng = DevRng::new();
 
-            assert_eq!(point, p1);
-            assert_eq!(point, p2);
+        let c = Scalar::<E>::random(&mut rng);
+        let c_mul = generic_ec::ConstMul::new(c);
+        assert_eq!(*c_mul.constant(), c);
+
+        let scalars = [Scalar::<E>::zero(), Scalar::one(), -Scalar::one()]
+            .into_iter()
+            .chain(iter::repeat_with(|| Scalar::random(&mut rng)).take(20));
+        for s in scalars {
+            assert_eq!(c_mul.mul(&s), c * s);
         }
     }
 
     #[test]
-    fn point_at_scalar<E: Curve>() {
+    fn scalar_mul_generator<E: Curve>() {
         let mut rng = DevRng::new();
+        let g = Point::<E>::generator().to_point();
 
-        let s1 = Scalar::<E>::random(&mut rng);
-        let s2 = Scalar::<E>::random(&mut rng);
+        assert!(Scalar::<E>::zero().mul_generator().is_zero());
+        assert_eq!(Scalar::<E>::one().mul_generator(), g);
 
-        let p = Point::generator() * s1;
-        assert_ne!(p, Point::zero());
-        assert_ne!(p * s2, Point::zero());
+        let s = NonZero::<Scalar<E>>::random(&mut rng);
+        let p: NonZero<Point<E>> = s.mul_generator();
+        assert_eq!(p, g * s);
+        assert_eq!(s.as_ref().mul_generator(), g * s);
+    }
 
-        assert_ne!(p * Scalar::from(-1), Point::zero());
-        assert_eq!(p + p * Scalar::from(-1), Point::zero());
+    #[test]
+    fn point_mul_by_generator<E: Curve>() {
+        let mut rng = DevRng::new();
+
+        let scalars = [Scalar::<E>::zero(), Scalar::one(), -Scalar::one()]
+            .into_iter()
+            .chain(iter::repeat_with(|| Scalar::random(&mut rng)).take(20));
+        for s in scalars {
+            assert_eq!(Point::mul_by_generator(&s), Point::generator() * s);
+            assert_eq!(
+                Point::mul_by_generator(&s),
+                Point::generator().to_point() * s
+            );
+        }
     }
 
     #[test]
-    fn scalar_0xFF_not_valid<E: Curve>() {
-        let mut encoded_scalar = EncodedScalar::<E>::default();
-        encoded_scalar.as_mut().fill(0xFF);
-        Scalar::<E>::from_be_bytes(&encoded_scalar).unwrap_err();
-        Scalar::<E>::from_le_bytes(&encoded_scalar).unwrap_err();
+    fn point_sum_iter_and_weighted_sum<E: Curve>() {
+        let mut rng = DevRng::new();
+
+        for len in [0, 1, 2, 10] {
+            let scalar_points = iter::repeat_with(|| {
+                (
+                    Scalar::<E>::random(&mut rng),
+                    Point::generator() * Scalar::random(&mut rng),
+                )
+            })
+            .take(len)
+            .collect::<Vec<_>>();
+            let points = scalar_points.iter().map(|(_, p)| *p).collect::<Vec<_>>();
+
+            assert_eq!(Point::sum_iter(&points), points.iter().sum::<Point<E>>());
+            assert_eq!(
+                Point::weighted_sum(scalar_points.iter().copied()),
+                scalar_points.iter().map(|(s, p)| s * p).sum::<Point<E>>()
+            );
+        }
     }
 
     #[test]
-    fn scalar_0xFF_valid_mod_order<E: Curve>() {
-        let mut encoded_0xFF_scalar = EncodedScalar::<E>::default();
-        encoded_0xFF_scalar.as_mut().fill(0xFF);
-        let decoded_scalar = Scalar::<E>::from_be_bytes_mod_order(&encoded_0xFF_scalar);
+    fn nonzero_scalar_div<E: Curve>() {
+        let mut rng = DevRng::new();
 
-        let scalar_0xFF = Scalar::from(0xFF_u8);
-        let scalar_0x100 = scalar_0xFF + Scalar::one();
-        let scalar_should_be = (0..encoded_0xFF_scalar.len())
-            .fold(Scalar::<E>::zero(), |scalar, _| {
-                scalar * scalar_0x100 + scalar_0xFF
-            });
+        let a = NonZero::<Scalar<E>>::random(&mut rng);
+        let b = NonZero::<Scalar<E>>::random(&mut rng);
 
-        assert_eq!(decoded_scalar, scalar_should_be);
+        let c = a / b;
+        assert_eq!(c * b, a);
+        assert_eq!(c, a * b.invert());
+
+        let mut a_div_assign = a;
+        a_div_assign /= b;
+        assert_eq!(a_div_assign, c);
     }
 
     #[test]
-    fn scalar_from_bytes_mod_order<E: Curve>() {
+    fn scalar_try_random<E: Curve>() {
+        /// Randomness source that only outputs zeroes
+        struct ZeroRng;
+        impl RngCore for ZeroRng {
+            fn next_u32(&mut self) -> u32 {
+                0
+            }
+            fn next_u64(&mut self) -> u64 {
+                0
+            }
+            fn fill_bytes(&mut self, dest: &mut [u8]) {
+                dest.fill(0)
+            }
+            fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+                dest.fill(0);
+                Ok(())
+            }
+        }
+
         let mut rng = DevRng::new();
+        assert!(!Scalar::<E>::try_random(&mut rng).unwrap().is_zero());
+        let _ = NonZero::<Scalar<E>>::try_random(&mut rng).unwrap();
 
-        let fixed_len = [8, 16, 32, 48, 64, 80, 96, 128, 256, 512, 513];
-        let random_len = core::iter::repeat_with(|| rng.gen_range(1..=200)).take(20);
-        let lenghts = fixed_len.into_iter().chain(random_len).collect::<Vec<_>>();
+        assert!(Scalar::<E>::try_random(&mut ZeroRng).is_err());
+        assert!(NonZero::<Scalar<E>>::try_random(&mut ZeroRng).is_err());
+    }
 
-        for len in lenghts {
-            let mut bytes = vec![0u8; len];
-            rng.fill_bytes(&mut bytes);
+    #[test]
+    fn scalar_random_bits<E: Curve>() {
+        let mut rng = DevRng::new();
 
-            eprintln!("len = {len}");
-            eprintln!("bytes = {}", hex::encode(&bytes));
+        for bits in [0, 1, 7, 8, 9, 64, 127, 200] {
+            for _ in 0..20 {
+                let s = Scalar::<E>::random_bits(&mut rng, bits);
+                let bytes = s.to_le_bytes();
+                for (i, byte) in bytes.iter().enumerate() {
+                    let bits_below = i as u32 * 8;
+                    if bits_below >= bits {
+                        assert_eq!(*byte, 0);
+                    } else if bits - bits_below < 8 {
+                        assert_eq!(byte >> (bits - bits_below), 0);
+                    }
+                }
+            }
+        }
+        assert_eq!(Scalar::<E>::random_bits(&mut rng, 0), Scalar::zero());
 
-            let expected = super::naive_scalar_from_be_bytes_mod_order::<E>(&bytes);
-            let actual = Scalar::from_be_bytes_mod_order(&bytes);
-            assert_eq!(expected, actual);
+        // Large enough `bits` still give a valid scalar
+        let _ = Scalar::<E>::random_bits(&mut rng, u32::MAX);
+    }
 
-            let expected = super::naive_scalar_from_le_bytes_mod_order::<E>(&bytes);
-            let actual = Scalar::from_le_bytes_mod_order(bytes);
-            assert_eq!(expected, actual);
+    #[test]
+    fn generator_table<E: Curve>() {
+        let mut rng = DevRng::new();
+        let table = Point::<E>::generator_table();
+
+        let scalars = [Scalar::<E>::zero(), Scalar::one(), -Scalar::one()]
+            .into_iter()
+            .chain(iter::repeat_with(|| Scalar::random(&mut rng)).take(20));
+        for s in scalars {
+            assert_eq!(table.mul(&s), Point::generator() * s);
         }
     }
 
     #[test]
-    fn scalar_one_be<E: Curve>() {
-        let one = Scalar::<E>::one();
-        let one_be = one.to_be_bytes();
-        let len = one_be.len();
-        assert_eq!(one_be[len - 1], 1);
-        assert!(one_be[..len - 1].iter().all(|x| *x == 0));
-        assert_eq!(Scalar::<E>::from_be_bytes(one_be).unwrap(), one);
+    fn precomputed_base<E: Curve>() {
+        let mut rng = DevRng::new();
+        let base = Point::generator() * NonZero::<Scalar<E>>::random(&mut rng);
+        let table = generic_ec::PrecomputedBase::new(base);
+        assert_eq!(table.base(), base);
+
+        let scalars = [Scalar::<E>::zero(), Scalar::one(), -Scalar::one()]
+            .into_iter()
+            .chain(iter::repeat_with(|| Scalar::random(&mut rng)).take(20));
+        for s in scalars {
+            assert_eq!(table.mul(&s), base * s);
+        }
     }
 
-    fn _is_copy<T: Copy>() {}
-    fn _test_point_and_scalar_are_copy<E: Curve>() {
-        _is_copy::<Scalar<E>>();
-        _is_copy::<Point<E>>();
+    #[test]
+    fn neg_generator<E: Curve>() {
+        let g = Point::<E>::generator();
+        assert_eq!(-g, -g.to_nonzero_point());
+        assert!((g + *(-g)).is_zero());
     }
 
     #[test]
-    fn scalar_radix16<E: Curve>() {
+    fn secret_scalar_arithmetic<E: Curve>() {
         let mut rng = DevRng::new();
 
-        let random_scalar = Scalar::<E>::random(&mut rng);
-        for scalar in [Scalar::zero(), Scalar::one(), -Scalar::one(), random_scalar] {
-            let radix16_be = scalar.as_radix16_be().collect::<Vec<_>>();
+        let secret = SecretScalar::<E>::random(&mut rng);
+        let public = Scalar::<E>::random(&mut rng);
+        let other_secret = SecretScalar::<E>::random(&mut rng);
+
+        assert_eq!(secret.add_secret(&public).as_ref(), &(&secret + public));
+        assert_eq!(secret.sub_secret(&public).as_ref(), &(&secret - public));
+        assert_eq!(secret.mul_secret(&public).as_ref(), &(&secret * public));
+
+        assert_eq!(
+            secret.add_secret(&other_secret).as_ref(),
+            &(secret.as_ref() + other_secret.as_ref())
+        );
+        assert_eq!(
+            secret.mul_secret(&other_secret).as_ref(),
+            &(secret.as_ref() * other_secret.as_ref())
+        );
+        assert_eq!(secret.sub_secret(&secret).as_ref(), &Scalar::zero());
+    }
+
+    #[test]
+    fn secret_scalar_from_non_crypto_rng<E: Curve>() {
+        let mut rng1 = rand::rngs::mock::StepRng::new(1, 1);
+        let mut rng2 = rng1.clone();
+
+        let s1 = SecretScalar::<E>::random_from_non_crypto_rng(&mut rng1);
+        let s2 = SecretScalar::<E>::random_from_non_crypto_rng(&mut rng2);
+        assert_eq!(s1.as_ref(), s2.as_ref());
+        assert!(!s1.as_ref().is_zero());
+    }
+
+    #[test]
+    fn conditional_negate<E: Curve>() {
+        let mut rng = DevRng::new();
+
+        let s = Scalar::<E>::random(&mut rng);
+        assert_eq!(s.conditional_negate(Choice::from(0)), s);
+        assert_eq!(s.conditional_negate(Choice::from(1)), -s);
+
+        let p = Point::<E>::generator() * s;
+        assert_eq!(p.conditional_negate(Choice::from(0)), p);
+        assert_eq!(p.conditional_negate(Choice::from(1)), -p);
+    }
+
+    #[test]
+    fn conditional_select_and_assign<E: Curve>() {
+        let mut rng = DevRng::new();
+
+        let (a, b) = (Scalar::<E>::random(&mut rng), Scalar::<E>::random(&mut rng));
+        assert_eq!(Scalar::conditional_select(&a, &b, Choice::from(0)), a);
+        assert_eq!(Scalar::conditional_select(&a, &b, Choice::from(1)), b);
+
+        let mut s = a;
+        s.conditional_assign(&b, Choice::from(0));
+        assert_eq!(s, a);
+        s.conditional_assign(&b, Choice::from(1));
+        assert_eq!(s, b);
+
+        let (p, q) = (Point::<E>::generator() * a, Point::<E>::generator() * b);
+        assert_eq!(Point::conditional_select(&p, &q, Choice::from(0)), p);
+        assert_eq!(Point::conditional_select(&p, &q, Choice::from(1)), q);
+
+        let mut r = p;
+        r.conditional_assign(&q, Choice::from(0));
+        assert_eq!(r, p);
+        r.conditional_assign(&q, Choice::from(1));
+        assert_eq!(r, q);
+    }
+
+    #[test]
+    fn point_batch_eq<E: Curve>() {
+        let mut rng = DevRng::new();
+
+        let a = iter::repeat_with(|| Point::<E>::generator() * Scalar::random(&mut rng))
+            .take(20)
+            .collect::<Vec<_>>();
+        let mut b = a.clone();
+        assert!(Point::batch_eq(&a, &b));
+        assert!(Point::batch_diff(&a, &b).is_empty());
+
+        b[3] = Point::zero();
+        b[17] += Point::generator();
+        assert!(!Point::batch_eq(&a, &b));
+        assert_eq!(Point::batch_diff(&a, &b), [3, 17]);
+
+        assert!(!Point::batch_eq(&a, &a[..19]));
+        assert_eq!(Point::batch_diff(&a[..18], &b), [3, 17, 18, 19]);
+        assert_eq!(Point::batch_diff(&a, &b[..18]), [3, 17, 18, 19]);
+    }
+
+    #[test]
+    fn point_zero<E: Curve>() {
+        let mut rng = DevRng::new();
+
+        let s = Scalar::<E>::random(&mut rng);
+        assert_eq!(
+            Point::generator() * s + Point::zero(),
+            Point::generator() * s
+        );
+        assert_eq!(s * Point::zero(), Point::zero());
+
+        assert!(Point::<E>::zero().is_zero());
+        assert!(bool::from(Point::<E>::zero().ct_is_zero()))
+    }
+
+    #[test]
+    fn identity_bytes_round_trip<E: Curve>() {
+        for compressed in [true, false] {
+            let bytes = Point::<E>::zero().to_bytes(compressed);
+            assert_eq!(Point::<E>::from_bytes(&bytes).unwrap(), Point::zero());
+        }
+
+        // All-zero bytes of unexpected length must not be decoded as identity
+        for len in [
+            0,
+            2,
+            Point::<E>::serialized_len(true) - 1,
+            Point::<E>::serialized_len(false) + 1,
+        ] {
+            assert!(Point::<E>::from_bytes(vec![0u8; len]).is_err());
+        }
+    }
+
+    #[test]
+    fn point_hex_round_trip<E: Curve>() {
+        use generic_ec::errors::{InvalidPointReason, ParsePointErrorReason};
+
+        let mut rng = DevRng::new();
+        let random_point = Point::<E>::generator() * Scalar::random(&mut rng);
+        for point in [Point::zero(), Point::generator().to_point(), random_point] {
+            let hex = point.to_string();
+            assert_eq!(hex, hex::encode(point.to_bytes(true)));
+            assert!(format!("{point:?}").contains(&hex));
+            assert_eq!(hex.parse::<Point<E>>().unwrap(), point);
+            assert_eq!(hex.to_uppercase().parse::<Point<E>>().unwrap(), point);
+
+            assert_eq!(format!("{point:x}"), hex);
+            assert_eq!(format!("{point:#x}"), format!("0x{hex}"));
+            assert_eq!(format!("{point:X}"), hex.to_uppercase());
+            assert_eq!(format!("{point:#X}"), format!("0x{}", hex.to_uppercase()));
+
+            let uncompressed = hex::encode(point.to_bytes(false));
+            assert_eq!(uncompressed.parse::<Point<E>>().unwrap(), point);
+        }
+
+        let reason = |s: &str| s.parse::<Point<E>>().unwrap_err().reason();
+        assert_eq!(reason("0"), ParsePointErrorReason::OddLength);
+        assert_eq!(reason("0g"), ParsePointErrorReason::InvalidCharacter);
+        let too_long = "00".repeat(Point::<E>::serialized_len(false) + 1);
+        assert_eq!(
+            reason(&too_long),
+            ParsePointErrorReason::InvalidPoint(InvalidPointReason::WrongLength)
+        );
+    }
+
+    #[test]
+    fn point_bytes<E: Curve>() {
+        let mut rng = DevRng::new();
+
+        let random_point = Point::generator() * Scalar::<E>::random(&mut rng);
+
+        for point in [Point::zero(), Point::generator().into(), random_point] {
+            let bytes_compressed = point.to_bytes(true);
+            let bytes_uncompressed = point.to_bytes(false);
+            assert!(bytes_compressed.len() <= bytes_uncompressed.len());
+            assert_eq!(bytes_compressed.len(), Point::<E>::serialized_len(true));
+            assert_eq!(bytes_uncompressed.len(), Point::<E>::serialized_len(false));
+
+            let p1 = Point::<E>::from_bytes(&bytes_compressed).unwrap();
+            let p2 = Point::<E>::from_bytes(&bytes_uncompressed).unwrap();
+
+            assert_eq!(point, p1);
+            assert_eq!(point, p2);
+        }
+    }
+
+    #[test]
+    fn point_from_bytes_with_sec1_format<E: Curve>() {
+        use generic_ec::{errors::InvalidPointReason, PointInputFormat};
+
+        let mut rng = DevRng::new();
+        let random_point = Point::generator() * Scalar::<E>::random(&mut rng);
+
+        for compressed in [true, false] {
+            let bytes = random_point.to_bytes(compressed);
+            let decoded =
+                Point::<E>::from_bytes_with_format(&bytes, PointInputFormat::Sec1).unwrap();
+            assert_eq!(random_point, decoded);
+
+            let longer = [bytes.as_bytes(), &[0]].concat();
+            let shorter = &bytes[..bytes.len() - 1];
+            for wrong_len in [&longer[..], shorter] {
+                let err = Point::<E>::from_bytes_with_format(wrong_len, PointInputFormat::Sec1)
+                    .unwrap_err();
+                assert_eq!(err.reason(), InvalidPointReason::WrongLength);
+            }
+        }
+    }
+
+    #[test]
+    fn point_encode_into_buffer<E: Curve>() {
+        let mut rng = DevRng::new();
+
+        let random_point = Point::generator() * Scalar::<E>::random(&mut rng);
+
+        for point in [Point::zero(), Point::generator().into(), random_point] {
+            for compressed in [true, false] {
+                let expected = point.to_bytes(compressed);
+                let encode_into = |out: &mut [u8]| {
+                    if compressed {
+                        point.encode_compressed_into(out)
+                    } else {
+                        point.encode_uncompressed_into(out)
+                    }
+                };
+
+                let mut buffer = [0xaa_u8; 300];
+                let len = encode_into(&mut buffer).unwrap();
+                assert_eq!(len, expected.len());
+                assert_eq!(&buffer[..len], expected.as_bytes());
+                assert!(buffer[len..].iter().all(|b| *b == 0xaa));
+
+                let mut exact = vec![0u8; expected.len()];
+                assert_eq!(encode_into(&mut exact).unwrap(), expected.len());
+                assert_eq!(exact, expected.as_bytes());
+
+                let mut too_small = vec![0u8; expected.len() - 1];
+                let err = encode_into(&mut too_small).unwrap_err();
+                assert_eq!(err.required_len(), expected.len());
+                assert_eq!(err.provided_len(), expected.len() - 1);
+                assert!(encode_into(&mut []).is_err());
+            }
+        }
+    }
+
+    #[test]
+    fn point_at_scalar<E: Curve>() {
+        let mut rng = DevRng::new();
+
+        let s1 = Scalar::<E>::random(&mut rng);
+        let s2 = Scalar::<E>::random(&mut rng);
+
+        let p = Point::generator() * s1;
+        assert_ne!(p, Point::zero());
+        assert_ne!(p * s2, Point::zero());
+
+        assert_ne!(p * Scalar::from(-1), Point::zero());
+        assert_eq!(p + p * Scalar::from(-1), Point::zero());
+    }
+
+    #[test]
+    fn scalar_0xFF_not_valid<E: Curve>() {
+        let mut encoded_scalar = EncodedScalar::<E>::default();
+        encoded_scalar.as_mut().fill(0xFF);
+        Scalar::<E>::from_be_bytes(&encoded_scalar).unwrap_err();
+        Scalar::<E>::from_le_bytes(&encoded_scalar).unwrap_err();
+    }
+
+    #[test]
+    fn scalar_0xFF_valid_mod_order<E: Curve>() {
+        let mut encoded_0xFF_scalar = EncodedScalar::<E>::default();
+        encoded_0xFF_scalar.as_mut().fill(0xFF);
+        let decoded_scalar = Scalar::<E>::from_be_bytes_mod_order(&encoded_0xFF_scalar);
+
+        let scalar_0xFF = Scalar::from(0xFF_u8);
+        let scalar_0x100 = scalar_0xFF + Scalar::one();
+        let scalar_should_be = (0..encoded_0xFF_scalar.len())
+            .fold(Scalar::<E>::zero(), |scalar, _| {
+                scalar * scalar_0x100 + scalar_0xFF
+            });
+
+        assert_eq!(decoded_scalar, scalar_should_be);
+    }
+
+    #[test]
+    fn scalar_from_bytes_mod_order<E: Curve>() {
+        let mut rng = DevRng::new();
+
+        let fixed_len = [8, 16, 32, 48, 64, 80, 96, 128, 256, 512, 513];
+        let random_len = core::iter::repeat_with(|| rng.gen_range(1..=200)).take(20);
+        let lenghts = fixed_len.into_iter().chain(random_len).collect::<Vec<_>>();
+
+        for len in lenghts {
+            let mut bytes = vec![0u8; len];
+            rng.fill_bytes(&mut bytes);
+
+            eprintln!("len = {len}");
+            eprintln!("bytes = {}", hex::encode(&bytes));
+
+            let expected = super::naive_scalar_from_be_bytes_mod_order::<E>(&bytes);
+            let actual = Scalar::from_be_bytes_mod_order(&bytes);
+            assert_eq!(expected, actual);
+
+            let expected = super::naive_scalar_from_le_bytes_mod_order::<E>(&bytes);
+            let actual = Scalar::from_le_bytes_mod_order(bytes);
+            assert_eq!(expected, actual);
+        }
+    }
+
+    #[test]
+    fn scalar_from_digits<E: Curve>() {
+        let mut rng = DevRng::new();
+
+        assert_eq!(Scalar::<E>::from_be_digits(&[]), Scalar::zero());
+        assert_eq!(Scalar::<E>::from_le_digits(&[]), Scalar::zero());
+
+        for len in [1, 2, 4, 6, 8, 9, 17] {
+            let digits = iter::repeat_with(|| rng.next_u64())
+                .take(len)
+                .collect::<Vec<_>>();
+            let be_bytes = digits
+                .iter()
+                .flat_map(|d| d.to_be_bytes())
+                .collect::<Vec<_>>();
+            let le_bytes = digits
+                .iter()
+                .flat_map(|d| d.to_le_bytes())
+                .collect::<Vec<_>>();
+
+            assert_eq!(
+                Scalar::<E>::from_be_digits(&digits),
+                Scalar::from_be_bytes_mod_order(&be_bytes)
+            );
+            assert_eq!(
+                Scalar::<E>::from_le_digits(&digits),
+                Scalar::from_le_bytes_mod_order(&le_bytes)
+            );
+        }
+    }
+
+    #[test]
+    fn scalar_one_be<E: Curve>() {
+        let one = Scalar::<E>::one();
+        let one_be = one.to_be_bytes();
+        let len = one_be.len();
+        assert_eq!(one_be[len - 1], 1);
+        assert!(one_be[..len - 1].iter().all(|x| *x == 0));
+        assert_eq!(Scalar::<E>::from_be_bytes(one_be).unwrap(), one);
+    }
+
+    #[test]
+    fn try_from_bytes<E: Curve>() {
+        let mut rng = DevRng::new();
+
+        let scalar = NonZero::<Scalar<E>>::random(&mut rng);
+        let be = scalar.to_be_bytes();
+        assert_eq!(Scalar::<E>::try_from(be.as_bytes()).unwrap(), scalar);
+        assert_eq!(Scalar::<E>::try_from(be.clone()).unwrap(), scalar);
+        assert_eq!(Scalar::<E>::try_from([1u8; 1]).unwrap(), Scalar::one());
+        Scalar::<E>::try_from(&[0xffu8; 100][..]).unwrap_err();
+
+        let point = Point::<E>::generator() * scalar;
+        for compressed in [true, false] {
+            let bytes = point.to_bytes(compressed);
+            assert_eq!(Point::<E>::try_from(bytes.as_bytes()).unwrap(), point);
+        }
+        Point::<E>::try_from(&[0xffu8; 3][..]).unwrap_err();
+        Point::<E>::try_from([0xffu8; 3]).unwrap_err();
+
+        // Conversions from `NonZero` still work
+        let _: Scalar<E> = scalar.into();
+        let _: Point<E> = point.into();
+    }
+
+    #[test]
+    fn scalar_is_high_and_normalize_s<E: Curve>() {
+        let mut rng = DevRng::new();
+
+        // $(q + 1) / 2 = 2^{-1}$ is the smallest high scalar, $(q - 1) / 2 = -2^{-1}$ is the
+        // largest low scalar
+        let half = Scalar::<E>::from(2).invert().unwrap();
+        assert!(bool::from(half.is_high()));
+        assert!(!bool::from((-half).is_high()));
+        assert!(!bool::from(Scalar::<E>::zero().is_high()));
+        assert!(!bool::from(Scalar::<E>::one().is_high()));
+        assert!(bool::from((-Scalar::<E>::one()).is_high()));
+
+        let scalars = [
+            Scalar::<E>::zero(),
+            Scalar::one(),
+            -Scalar::one(),
+            half,
+            -half,
+        ]
+        .into_iter()
+        .chain(iter::repeat_with(|| Scalar::random(&mut rng)).take(50));
+        for s in scalars {
+            let normalized = s.normalize_s();
+            assert!(!bool::from(normalized.is_high()));
+            assert!(normalized == s || normalized == -s);
+            assert_eq!(normalized.normalize_s(), normalized);
+        }
+    }
+
+    #[test]
+    fn scalar_from_bytes_with_extra_zeroes<E: Curve>() {
+        let mut rng = DevRng::new();
+        let scalar = Scalar::<E>::random(&mut rng);
+        let be = scalar.to_be_bytes();
+        let le = scalar.to_le_bytes();
+
+        for extra in [1, 2, 10] {
+            let zeroes = vec![0u8; extra];
+
+            let padded_be = [&zeroes[..], &be].concat();
+            assert_eq!(Scalar::<E>::from_be_bytes(&padded_be).unwrap(), scalar);
+            let padded_le = [&le[..], &zeroes].concat();
+            assert_eq!(Scalar::<E>::from_le_bytes(&padded_le).unwrap(), scalar);
+
+            let mut non_zero_be = padded_be.clone();
+            non_zero_be[0] = 1;
+            Scalar::<E>::from_be_bytes(&non_zero_be).unwrap_err();
+            let mut non_zero_le = padded_le.clone();
+            *non_zero_le.last_mut().unwrap() = 1;
+            Scalar::<E>::from_le_bytes(&non_zero_le).unwrap_err();
+        }
+
+        // Significant bytes must still be less than group order
+        let mut max = vec![0u8; Scalar::<E>::serialized_len() + 1];
+        max[1..].fill(0xff);
+        Scalar::<E>::from_be_bytes(&max).unwrap_err();
+        max.reverse();
+        Scalar::<E>::from_le_bytes(&max).unwrap_err();
+    }
+
+    fn _is_copy<T: Copy>() {}
+    fn _test_point_and_scalar_are_copy<E: Curve>() {
+        _is_copy::<Scalar<E>>();
+        _is_copy::<Point<E>>();
+    }
+
+    #[test]
+    fn scalar_radix16<E: Curve>() {
+        let mut rng = DevRng::new();
+
+        let random_scalar = Scalar::<E>::random(&mut rng);
+        for scalar in [Scalar::zero(), Scalar::one(), -Scalar::one(), random_scalar] {
+            let radix16_be = scalar.as_radix16_be().collect::<Vec<_>>();
             assert_eq!(radix16_be.len(), Scalar::<E>::serialized_len() * 2);
 
             let reconstructed_scalar = radix16_be.iter().fold(Scalar::<E>::zero(), |acc, x| {
@@ -242,17 +1228,40 @@ mod tests {
         }
     }
 
+    #[test]
+    fn cofactor<E: Curve>() {
+        let h = Point::<E>::cofactor();
+        assert_eq!(h, E::cofactor());
+        assert_eq!(h, E::COFACTOR);
+        assert_ne!(h.first(), Some(&0));
+        assert!(!h.is_empty());
+        assert_eq!(E::is_prime_order(), h == [1]);
+    }
+
     #[instantiate_tests(<Secp256k1>)]
     mod secp256k1 {}
 
     #[instantiate_tests(<Secp256r1>)]
     mod secp256r1 {}
 
+    #[instantiate_tests(<Secp384r1>)]
+    mod secp384r1 {}
+
+    #[instantiate_tests(<Sm2>)]
+    mod sm2 {}
+
     #[instantiate_tests(<Stark>)]
     mod stark {}
 
     #[instantiate_tests(<Ed25519>)]
     mod ed25519 {}
+
+    #[instantiate_tests(<Ristretto255>)]
+    mod ristretto255 {}
+    #[instantiate_tests(<Bls12_381G1>)]
+    mod bls12_381_g1 {}
+    #[instantiate_tests(<Bls12_381G2>)]
+    mod bls12_381_g2 {}
 }
 
 #[generic_tests::define]
@@ -287,6 +1296,12 @@ mod scalar_reduce {
     #[instantiate_tests(<generic_ec::curves::Secp256r1, 32>)]
     mod secp256r1_32 {}
 
+    #[instantiate_tests(<generic_ec::curves::Secp384r1, 48>)]
+    mod secp384r1_48 {}
+
+    #[instantiate_tests(<generic_ec::curves::Sm2, 32>)]
+    mod sm2_32 {}
+
     #[instantiate_tests(<generic_ec::curves::Stark, 32>)]
     mod stark_32 {}
 
@@ -294,13 +1309,23 @@ mod scalar_reduce {
     mod ed25519_32 {}
     #[instantiate_tests(<generic_ec::curves::Ed25519, 64>)]
     mod ed25519_64 {}
+
+    #[instantiate_tests(<generic_ec::curves::Ristretto255, 32>)]
+    mod ristretto255_32 {}
+    #[instantiate_tests(<generic_ec::curves::Ristretto255, 64>)]
+    mod ristretto255_64 {}
+
+    #[instantiate_tests(<generic_ec::curves::Bls12_381G1, 32>)]
+    mod bls12_381_g1_32 {}
+    #[instantiate_tests(<generic_ec::curves::Bls12_381G1, 64>)]
+    mod bls12_381_g1_64 {}
 }
 
 #[generic_tests::define]
 mod coordinates {
-    use generic_ec::coords::{HasAffineX, HasAffineXAndParity, HasAffineXY, HasAffineY};
-    use generic_ec::curves::{Secp256k1, Secp256r1, Stark};
-    use generic_ec::{Curve, Point, Scalar};
+    use generic_ec::coords::{HasAffineX, HasAffineXAndParity, HasAffineXY, HasAffineY, Parity};
+    use generic_ec::curves::{Secp256k1, Secp256r1, Secp384r1, Sm2, Stark};
+    use generic_ec::{Curve, Point, PointInputFormat, Scalar};
 
     use rand_dev::DevRng;
 
@@ -331,6 +1356,39 @@ mod coordinates {
         assert_eq!(random_point, reassembled_point);
     }
 
+    #[test]
+    fn point_x_has_even_y<E>()
+    where
+        E: Curve + generic_ec::core::coords::HasAffineXAndParity,
+    {
+        let mut rng = DevRng::new();
+        let sk = Scalar::<E>::random(&mut rng);
+        let pk = Point::<E>::generator() * sk;
+
+        let (_, parity) = pk.x_and_parity().unwrap();
+        assert_eq!(bool::from(pk.x_has_even_y()), parity.is_even());
+        assert!(!bool::from(Point::<E>::zero().x_has_even_y()));
+
+        let sk = sk.conditional_negate(!pk.x_has_even_y());
+        let pk = Point::<E>::generator() * sk;
+        assert!(bool::from(pk.x_has_even_y()));
+    }
+
+    #[test]
+    fn point_y_is_odd<E>()
+    where
+        E: Curve + generic_ec::core::coords::HasAffineXAndParity,
+    {
+        let mut rng = DevRng::new();
+        for _ in 0..20 {
+            let point = Point::<E>::generator() * Scalar::random(&mut rng);
+            let (_, parity) = point.x_and_parity().unwrap();
+            assert_eq!(point.y_is_odd().map(bool::from), Some(parity.is_odd()));
+            assert_eq!((-point).y_is_odd().map(bool::from), Some(!parity.is_odd()));
+        }
+        assert!(Point::<E>::zero().y_is_odd().is_none());
+    }
+
     #[test]
     fn point_exposes_x_and_y<E: Curve>()
     where
@@ -347,20 +1405,1082 @@ mod coordinates {
         assert_eq!(random_point, reassembled_point);
     }
 
-    #[instantiate_tests(<Secp256k1>)]
-    mod secp256k1 {}
+    #[test]
+    fn point_exposes_y<E: Curve>()
+    where
+        Point<E>: HasAffineY<E> + HasAffineXY<E>,
+    {
+        use generic_ec::{coords::AlwaysHasAffineY, NonZero};
 
-    #[instantiate_tests(<Secp256r1>)]
-    mod secp256r1 {}
+        let mut rng = DevRng::new();
+        for _ in 0..10 {
+            let point = Point::<E>::generator() * NonZero::<Scalar<E>>::random(&mut rng);
+            let coords = point.coords().unwrap();
+            assert_eq!(HasAffineY::y(&*point), Some(coords.y.clone()));
+            assert_eq!(AlwaysHasAffineY::y(&point), coords.y);
+        }
+        assert_eq!(HasAffineY::y(&Point::<E>::zero()), None);
+    }
 
-    #[instantiate_tests(<Stark>)]
-    mod stark {}
-}
+    #[test]
+    fn coords_to_uncompressed_point_bytes<E>()
+    where
+        E: Curve + generic_ec::core::coords::HasAffineXY,
+        Point<E>: HasAffineXY<E>,
+    {
+        use generic_ec::{coords::Coordinates, errors::InvalidPointReason};
 
-fn naive_scalar_from_be_bytes_mod_order<E: generic_ec::Curve>(
-    bytes: &[u8],
-) -> generic_ec::Scalar<E> {
-    let scalar_0x100 = generic_ec::Scalar::from(0x100);
+        let mut rng = DevRng::new();
+        let random_point = Point::<E>::generator() * Scalar::random(&mut rng);
+        let coords = random_point.coords().unwrap();
+
+        let bytes = coords.to_uncompressed_point_bytes();
+        assert_eq!(bytes, random_point.to_bytes(false));
+
+        let parsed = Coordinates::<E>::from_uncompressed_point_bytes(&bytes).unwrap();
+        assert_eq!(parsed, coords);
+        assert_eq!(Point::from_coords(&parsed).unwrap(), random_point);
+
+        let compressed = random_point.to_bytes(true);
+        let err = Coordinates::<E>::from_uncompressed_point_bytes(&compressed).unwrap_err();
+        assert_eq!(err.reason(), InvalidPointReason::WrongLength);
+
+        let identity = Point::<E>::zero().to_bytes(false);
+        let err = Coordinates::<E>::from_uncompressed_point_bytes(&identity).unwrap_err();
+        assert_eq!(err.reason(), InvalidPointReason::MalformedEncoding);
+    }
+
+    #[test]
+    fn point_from_bytes_with_format<E>()
+    where
+        E: Curve
+            + generic_ec::core::coords::HasAffineXAndParity
+            + generic_ec::core::coords::HasAffineXY,
+    {
+        let mut rng = DevRng::new();
+        let random_point = Point::<E>::generator() * Scalar::random(&mut rng);
+
+        for compressed in [true, false] {
+            let bytes = random_point.to_bytes(compressed);
+            let decoded =
+                Point::<E>::from_bytes_with_format(&bytes, PointInputFormat::Sec1).unwrap();
+            assert_eq!(random_point, decoded);
+
+            assert!(Point::<E>::from_bytes_with_format(&bytes, PointInputFormat::XOnly).is_err());
+            assert!(Point::<E>::from_bytes_with_format(&bytes, PointInputFormat::Raw64).is_err());
+        }
+
+        let coords = random_point.coords().unwrap();
+        let raw = [coords.x.as_be_bytes(), coords.y.as_be_bytes()].concat();
+        let decoded = Point::<E>::from_bytes_with_format(&raw, PointInputFormat::Raw64).unwrap();
+        assert_eq!(random_point, decoded);
+        assert!(Point::<E>::from_bytes_with_format(&raw, PointInputFormat::Sec1).is_err());
+
+        let (x, parity) = random_point.x_and_parity().unwrap();
+        let decoded =
+            Point::<E>::from_bytes_with_format(x.as_be_bytes(), PointInputFormat::XOnly).unwrap();
+        match parity {
+            Parity::Even => assert_eq!(random_point, decoded),
+            Parity::Odd => assert_eq!(-random_point, decoded),
+        }
+        assert!(
+            Point::<E>::from_bytes_with_format(x.as_be_bytes(), PointInputFormat::Sec1).is_err()
+        );
+
+        // Coordinates with extra or missing byte are rejected
+        let coord_len = x.as_be_bytes().len();
+        for (format, expected_len) in [
+            (PointInputFormat::XOnly, coord_len),
+            (PointInputFormat::Raw64, 2 * coord_len),
+        ] {
+            for wrong_len in [expected_len - 1, expected_len + 1] {
+                let err =
+                    Point::<E>::from_bytes_with_format(vec![1u8; wrong_len], format).unwrap_err();
+                assert_eq!(
+                    err.reason(),
+                    generic_ec::errors::InvalidPointReason::WrongLength
+                );
+            }
+        }
+
+        for identity_len in [1, 1 + coord_len, 1 + 2 * coord_len] {
+            let identity = vec![0u8; identity_len];
+            let decoded =
+                Point::<E>::from_bytes_with_format(&identity, PointInputFormat::Sec1).unwrap();
+            assert!(decoded.is_zero());
+        }
+    }
+
+    #[test]
+    fn coordinate_field_modulus<E>()
+    where
+        E: Curve
+            + generic_ec::core::coords::HasFieldModulus
+            + generic_ec::core::coords::HasAffineXAndParity
+            + generic_ec::core::coords::HasAffineXY,
+    {
+        use generic_ec::coords::Coordinate;
+
+        let mut rng = DevRng::new();
+        let p = Coordinate::<E>::field_modulus_be_bytes();
+        assert_eq!(
+            p.as_ref().len(),
+            Coordinate::<E>::default().as_be_bytes().len()
+        );
+
+        for _ in 0..20 {
+            let coords = (Point::<E>::generator() * Scalar::random(&mut rng))
+                .coords()
+                .unwrap();
+            assert!(coords.x.as_be_bytes() < p.as_ref());
+            assert!(coords.y.as_be_bytes() < p.as_ref());
+            if coords.x.fits_into_scalar() {
+                assert_eq!(
+                    Coordinate::from_scalar(&coords.x.to_scalar()).unwrap(),
+                    coords.x
+                );
+            }
+
+            let scalar = Scalar::<E>::random(&mut rng);
+            let coord = Coordinate::from_scalar(&scalar).unwrap();
+            assert!(coord.fits_into_scalar());
+            assert_eq!(coord.to_scalar(), scalar);
+        }
+
+        // Field modulus is not a valid field element
+        let p = Coordinate::<E>::new(p);
+        assert!(Point::<E>::from_x_and_parity(&p, Parity::Even).is_none());
+        assert!(Point::<E>::from_x_and_parity(&p, Parity::Odd).is_none());
+    }
+
+    #[instantiate_tests(<Secp256k1>)]
+    mod secp256k1 {}
+
+    #[instantiate_tests(<Secp256r1>)]
+    mod secp256r1 {}
+
+    #[instantiate_tests(<Secp384r1>)]
+    mod secp384r1 {}
+
+    #[instantiate_tests(<Sm2>)]
+    mod sm2 {}
+
+    #[instantiate_tests(<Stark>)]
+    mod stark {}
+}
+
+mod hash_stability {
+    use core::hash::{Hash, Hasher};
+
+    use generic_ec::{
+        curves::{Ed25519, Secp256k1},
+        Point, Scalar,
+    };
+
+    /// FNV-1a hasher: unlike `DefaultHasher`, its output is guaranteed to never change
+    struct Fnv1a(u64);
+
+    impl Hasher for Fnv1a {
+        fn finish(&self) -> u64 {
+            self.0
+        }
+
+        fn write(&mut self, bytes: &[u8]) {
+            for byte in bytes {
+                self.0 ^= u64::from(*byte);
+                self.0 = self.0.wrapping_mul(0x100000001b3);
+            }
+        }
+    }
+
+    fn hash(value: &impl Hash) -> u64 {
+        let mut hasher = Fnv1a(0xcbf29ce484222325);
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn point_hash_is_stable() {
+        assert_eq!(
+            hash(&Point::<Secp256k1>::generator().to_point()),
+            0x9bbb75f84d91c3d5
+        );
+        assert_eq!(
+            hash(&Point::<Ed25519>::generator().to_point()),
+            0x7dda3b9ca7072a9b
+        );
+    }
+
+    #[test]
+    fn scalar_hash_is_stable() {
+        assert_eq!(hash(&Scalar::<Secp256k1>::one()), 0x0c820f784d8af3f2);
+        assert_eq!(hash(&Scalar::<Ed25519>::one()), 0x0c820f784d8af3f2);
+    }
+}
+
+mod invalid_point_reason {
+    use generic_ec::{
+        curves::{Ed25519, Secp256k1},
+        errors::InvalidPointReason,
+        Point, PointInputFormat,
+    };
+
+    #[test]
+    fn wrong_length() {
+        let err = Point::<Secp256k1>::from_bytes([2, 1, 2, 3]).unwrap_err();
+        assert_eq!(err.reason(), InvalidPointReason::WrongLength);
+
+        let err = Point::<Ed25519>::from_bytes([1, 2, 3]).unwrap_err();
+        assert_eq!(err.reason(), InvalidPointReason::WrongLength);
+    }
+
+    #[test]
+    fn malformed_encoding() {
+        let mut bytes = Point::<Secp256k1>::generator()
+            .to_point()
+            .to_bytes(true)
+            .to_vec();
+        bytes[0] = 0x08;
+        let err = Point::<Secp256k1>::from_bytes(&bytes).unwrap_err();
+        assert_eq!(err.reason(), InvalidPointReason::MalformedEncoding);
+
+        let err =
+            Point::<Secp256k1>::from_bytes_with_format(&bytes, PointInputFormat::Sec1).unwrap_err();
+        assert_eq!(err.reason(), InvalidPointReason::MalformedEncoding);
+    }
+
+    #[test]
+    fn not_on_curve() {
+        let mut bytes = [0u8; 64];
+        bytes[31] = 1;
+        bytes[63] = 1;
+        let err =
+            Point::<Secp256k1>::from_bytes_with_format(bytes, PointInputFormat::Raw64).unwrap_err();
+        assert_eq!(err.reason(), InvalidPointReason::NotOnCurve);
+    }
+
+    #[test]
+    fn has_torsion() {
+        // Point of order 2 on ed25519 curve: (0, -1)
+        let bytes = hex::decode("ecffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f")
+            .unwrap();
+        let err = Point::<Ed25519>::from_bytes(bytes).unwrap_err();
+        assert_eq!(err.reason(), InvalidPointReason::HasTorsion);
+    }
+}
+
+// Only instantiated for curves implementing `Cofactor`. BLS12-381 groups don't implement it.
+#[generic_tests::define]
+mod clear_cofactor {
+    use generic_ec::{curves::*, Curve, Point, Scalar};
+
+    #[test]
+    fn clear_cofactor<E: Curve>()
+    where
+        E::Point: generic_ec::core::Cofactor,
+    {
+        use generic_ec::as_raw::AsRaw;
+
+        let mut rng = rand_dev::DevRng::new();
+        let point = Point::<E>::generator() * Scalar::random(&mut rng);
+        let h = Scalar::from_be_bytes_mod_order(Point::<E>::cofactor());
+
+        let cleared = Point::<E>::clear_cofactor(*point.as_raw()).unwrap();
+        assert_eq!(cleared, point * h);
+
+        let (decoded, has_torsion) =
+            Point::<E>::from_bytes_allowing_torsion(point.to_bytes(true)).unwrap();
+        assert_eq!(decoded, point);
+        assert!(!bool::from(has_torsion));
+        assert!(Point::<E>::clear_cofactor(*Point::<E>::zero().as_raw())
+            .unwrap()
+            .is_zero());
+    }
+
+    #[instantiate_tests(<Secp256k1>)]
+    mod secp256k1 {}
+    #[instantiate_tests(<Secp256r1>)]
+    mod secp256r1 {}
+    #[instantiate_tests(<Secp384r1>)]
+    mod secp384r1 {}
+    #[instantiate_tests(<Sm2>)]
+    mod sm2 {}
+    #[instantiate_tests(<Stark>)]
+    mod stark {}
+    #[instantiate_tests(<Ed25519>)]
+    mod ed25519 {}
+    #[instantiate_tests(<Ristretto255>)]
+    mod ristretto255 {}
+}
+
+#[generic_tests::define]
+mod hash_to_generator {
+    use generic_ec::{core::HashToCurve, curves::*, Curve, Point};
+
+    #[test]
+    fn hash_to_generator<E: Curve + HashToCurve>()
+    where
+        E::Point: generic_ec::core::Cofactor,
+    {
+        let h = Point::<E>::hash_to_generator(b"generic-ec tests");
+        assert_eq!(h, Point::<E>::hash_to_generator(b"generic-ec tests"));
+        assert_ne!(h, Point::<E>::hash_to_generator(b"generic-ec tests 2"));
+        assert_ne!(*h, Point::<E>::generator().to_point());
+        assert!(!h.is_zero());
+
+        let (decoded, has_torsion) =
+            Point::<E>::from_bytes_allowing_torsion(h.to_bytes(true)).unwrap();
+        assert_eq!(decoded, *h);
+        assert!(!bool::from(has_torsion));
+    }
+
+    #[instantiate_tests(<Secp256k1>)]
+    mod secp256k1 {}
+    #[instantiate_tests(<Secp256r1>)]
+    mod secp256r1 {}
+    #[instantiate_tests(<Secp384r1>)]
+    mod secp384r1 {}
+    #[instantiate_tests(<Ed25519>)]
+    mod ed25519 {}
+    #[instantiate_tests(<Ristretto255>)]
+    mod ristretto255 {}
+}
+
+mod cofactor {
+    use generic_ec::{
+        as_raw::{AsRaw, TryFromRaw},
+        core::{Additive, CompressedEncoding, Decode},
+        curves::Ed25519,
+        Curve, Point, Scalar,
+    };
+
+    /// Point of order 2 on ed25519 curve: (0, -1)
+    fn torsion_point() -> <Ed25519 as Curve>::Point {
+        let bytes = hex::decode("ecffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f")
+            .unwrap();
+        <Ed25519 as Curve>::Point::decode(&bytes).unwrap()
+    }
+
+    #[test]
+    fn low_order_points_are_detected() {
+        // Points of order 1, 2, 4, and 4 correspondingly
+        let low_order_points = [
+            "0100000000000000000000000000000000000000000000000000000000000000",
+            "ecffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f",
+            "0000000000000000000000000000000000000000000000000000000000000000",
+            "0000000000000000000000000000000000000000000000000000000000000080",
+        ];
+        for (i, point) in low_order_points.iter().enumerate() {
+            let bytes = hex::decode(point).unwrap();
+            let raw = <Ed25519 as Curve>::Point::decode(&bytes).unwrap();
+            assert!(bool::from(Point::<Ed25519>::raw_is_small_order(&raw)));
+
+            let (point, has_torsion) =
+                Point::<Ed25519>::from_bytes_allowing_torsion(&bytes).unwrap();
+            assert!(point.is_zero());
+            // Identity point doesn't have torsion component
+            assert_eq!(bool::from(has_torsion), i != 0);
+        }
+    }
+
+    #[test]
+    fn prime_order_component_is_extracted() {
+        let g = Point::<Ed25519>::generator().to_point();
+        let g_plus_t = Additive::add(g.as_raw(), &torsion_point());
+        assert!(!bool::from(Point::<Ed25519>::raw_is_small_order(&g_plus_t)));
+        assert!(!bool::from(Point::<Ed25519>::raw_is_small_order(
+            g.as_raw()
+        )));
+
+        let bytes = <Ed25519 as Curve>::Point::to_bytes_compressed(&g_plus_t);
+        let (point, has_torsion) = Point::<Ed25519>::from_bytes_allowing_torsion(bytes).unwrap();
+        assert_eq!(point, g);
+        assert!(bool::from(has_torsion));
+
+        let (point, has_torsion) =
+            Point::<Ed25519>::from_bytes_allowing_torsion(g.to_bytes(true)).unwrap();
+        assert_eq!(point, g);
+        assert!(!bool::from(has_torsion));
+    }
+
+    #[test]
+    fn ed25519_cofactor_is_8() {
+        assert_eq!(Point::<Ed25519>::cofactor(), [8]);
+    }
+
+    #[test]
+    fn curves_cofactors() {
+        use generic_ec::curves::*;
+
+        assert!(Secp256k1::is_prime_order());
+        assert!(Secp256r1::is_prime_order());
+        assert!(Secp384r1::is_prime_order());
+        assert!(Sm2::is_prime_order());
+        assert!(Stark::is_prime_order());
+        assert!(Ristretto255::is_prime_order());
+
+        assert!(!Ed25519::is_prime_order());
+        assert_eq!(Ed25519::COFACTOR, [8]);
+
+        assert!(!Bls12_381G1::is_prime_order());
+        assert_eq!(
+            hex::encode(Bls12_381G1::COFACTOR),
+            "396c8c005555e1568c00aaab0000aaab"
+        );
+        assert!(!Bls12_381G2::is_prime_order());
+        assert_eq!(Bls12_381G2::COFACTOR.len(), 64);
+
+        assert_eq!(Point::<Bls12_381G1>::cofactor(), Bls12_381G1::COFACTOR);
+        assert_eq!(Point::<Bls12_381G2>::cofactor(), Bls12_381G2::COFACTOR);
+    }
+
+    #[test]
+    fn torsion_point_is_cleared_to_identity() {
+        let t = torsion_point();
+        assert!(Point::<Ed25519>::try_from_raw(t).is_none());
+
+        let cleared = Point::<Ed25519>::clear_cofactor(t).unwrap();
+        assert!(cleared.is_zero());
+    }
+
+    #[test]
+    fn torsion_component_is_cleared() {
+        let g = Point::<Ed25519>::generator().to_point();
+        let g_plus_t = Additive::add(g.as_raw(), &torsion_point());
+        assert!(Point::<Ed25519>::try_from_raw(g_plus_t).is_none());
+
+        let cleared = Point::<Ed25519>::clear_cofactor(g_plus_t).unwrap();
+        assert_eq!(cleared, g * Scalar::from(8));
+    }
+}
+
+mod rust_crypto_interop {
+    use generic_ec::curves::{k256, p256, Secp256k1, Secp256r1};
+    use generic_ec::{Point, Scalar};
+    use rand_dev::DevRng;
+
+    #[test]
+    fn secp256k1() {
+        let mut rng = DevRng::new();
+        let scalar = Scalar::<Secp256k1>::random(&mut rng);
+        let point = Point::generator() * scalar;
+
+        let k256_scalar = k256::Scalar::from(scalar);
+        let k256_point = k256::ProjectivePoint::from(point);
+        assert_eq!(k256_point, k256::ProjectivePoint::GENERATOR * k256_scalar);
+
+        assert_eq!(Scalar::<Secp256k1>::from(k256_scalar), scalar);
+        assert_eq!(Point::<Secp256k1>::try_from(k256_point).unwrap(), point);
+        assert!(
+            Point::<Secp256k1>::try_from(k256::ProjectivePoint::IDENTITY)
+                .unwrap()
+                .is_zero()
+        );
+    }
+
+    #[test]
+    fn secp256r1() {
+        let mut rng = DevRng::new();
+        let scalar = Scalar::<Secp256r1>::random(&mut rng);
+        let point = Point::generator() * scalar;
+
+        let p256_scalar = p256::Scalar::from(scalar);
+        let p256_point = p256::ProjectivePoint::from(point);
+        assert_eq!(p256_point, p256::ProjectivePoint::GENERATOR * p256_scalar);
+
+        assert_eq!(Scalar::<Secp256r1>::from(p256_scalar), scalar);
+        assert_eq!(Point::<Secp256r1>::try_from(p256_point).unwrap(), point);
+        assert!(
+            Point::<Secp256r1>::try_from(p256::ProjectivePoint::IDENTITY)
+                .unwrap()
+                .is_zero()
+        );
+    }
+}
+
+mod batch_encoding {
+    use generic_ec::{core::BatchEncoding, curves::*, Curve, Point, Scalar};
+    use rand_dev::DevRng;
+
+    fn batch_to_bytes<E: Curve>()
+    where
+        E::Point: BatchEncoding,
+    {
+        let mut rng = DevRng::new();
+
+        // Cover empty batch, a batch with identity points, and batches that don't fit
+        // into a single chunk of the backend
+        for n in [0, 1, 31, 32, 33, 100] {
+            let points = (0..n)
+                .map(|i| {
+                    if i % 7 == 3 {
+                        Point::zero()
+                    } else {
+                        Point::generator() * Scalar::<E>::random(&mut rng)
+                    }
+                })
+                .collect::<Vec<_>>();
+
+            for compressed in [true, false] {
+                let encoded = Point::batch_to_bytes(&points, compressed);
+                assert_eq!(encoded.len(), points.len());
+                for (point, bytes) in points.iter().zip(&encoded) {
+                    assert_eq!(bytes.as_bytes(), point.to_bytes(compressed).as_bytes());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn secp256k1() {
+        batch_to_bytes::<Secp256k1>()
+    }
+    #[test]
+    fn secp256r1() {
+        batch_to_bytes::<Secp256r1>()
+    }
+    #[test]
+    fn secp384r1() {
+        batch_to_bytes::<Secp384r1>()
+    }
+    #[test]
+    fn sm2() {
+        batch_to_bytes::<Sm2>()
+    }
+    #[test]
+    fn ed25519() {
+        batch_to_bytes::<Ed25519>()
+    }
+    #[test]
+    fn ristretto255() {
+        batch_to_bytes::<Ristretto255>()
+    }
+    #[test]
+    fn bls12_381_g1() {
+        batch_to_bytes::<Bls12_381G1>()
+    }
+    #[test]
+    fn bls12_381_g2() {
+        batch_to_bytes::<Bls12_381G2>()
+    }
+}
+
+mod hash_to_curve_suite {
+    use generic_ec::{curves::*, Curve};
+
+    #[test]
+    fn suite_ids_match_rfc9380() {
+        assert_eq!(
+            Secp256k1::HASH_TO_CURVE_SUITE,
+            Some("secp256k1_XMD:SHA-256_SSWU_RO_")
+        );
+        assert_eq!(
+            Secp256r1::HASH_TO_CURVE_SUITE,
+            Some("P256_XMD:SHA-256_SSWU_RO_")
+        );
+        assert_eq!(
+            Secp384r1::HASH_TO_CURVE_SUITE,
+            Some("P384_XMD:SHA-384_SSWU_RO_")
+        );
+        assert_eq!(
+            Ed25519::HASH_TO_CURVE_SUITE,
+            Some("edwards25519_XMD:SHA-512_ELL2_RO_")
+        );
+        assert_eq!(
+            Ristretto255::HASH_TO_CURVE_SUITE,
+            Some("ristretto255_XMD:SHA-512_R255MAP_RO_")
+        );
+        assert_eq!(Sm2::HASH_TO_CURVE_SUITE, None);
+        assert_eq!(Stark::HASH_TO_CURVE_SUITE, None);
+        assert_eq!(
+            Bls12_381G1::HASH_TO_CURVE_SUITE,
+            Some("BLS12381G1_XMD:SHA-256_SSWU_RO_")
+        );
+        assert_eq!(
+            Bls12_381G2::HASH_TO_CURVE_SUITE,
+            Some("BLS12381G2_XMD:SHA-256_SSWU_RO_")
+        );
+    }
+}
+
+mod ed25519 {
+    use generic_ec::{as_raw::TryFromRaw, curves::Ed25519, Point, PointInputFormat, Scalar};
+
+    #[test]
+    fn coordinate_input_formats_are_rejected() {
+        let g = Point::<Ed25519>::generator().to_point().to_bytes(true);
+        for format in [PointInputFormat::XOnly, PointInputFormat::Raw64] {
+            let err = Point::<Ed25519>::from_bytes_with_format(&g, format).unwrap_err();
+            assert_eq!(
+                err.reason(),
+                generic_ec::errors::InvalidPointReason::MalformedEncoding
+            );
+        }
+    }
+
+    /// Test vectors from RFC 9380, Appendix J.5.1
+    #[test]
+    fn hash_to_curve() {
+        let dst = b"QUUX-V01-CS02-with-edwards25519_XMD:SHA-512_ELL2_RO_";
+        let q128 = [b"q128_".as_slice(), &[b'q'; 128]].concat();
+        let a512 = [b"a512_".as_slice(), &[b'a'; 512]].concat();
+        let test_vectors: &[(&[u8], &str)] = &[
+            (
+                b"",
+                "21dc15e10253796df23a7699c8a383ea624cce88c52431f6be220b1a56c8a609",
+            ),
+            (
+                b"abc",
+                "31558a26887f23fb8218f143e69d5f0af2e7831130bd5b432ef23883b895839a",
+            ),
+            (
+                b"abcdef0123456789",
+                "a661c58eea707f2171dd1a8a641e41758ac842cfd31e64dabc7f0e143d0a0653",
+            ),
+            (
+                &q128,
+                "f7d2895eea2ef7b737ed56594f99e238a1eeb0dd672f98d239fafc55e315ca2e",
+            ),
+            (
+                &a512,
+                "95f9d827f3c0f8076af227f01fef51d0cc924fb1806a237fc2c566f204fcc26d",
+            ),
+        ];
+
+        for (msg, expected) in test_vectors {
+            let raw = Ed25519::hash_to_curve(&[msg], &[dst]).unwrap();
+            // Output is torsion-free, so it's accepted as `Point<Ed25519>`
+            let point = Point::<Ed25519>::try_from_raw(raw).unwrap();
+            assert_eq!(hex::encode(point.to_bytes(true)), *expected);
+        }
+
+        // Message and DST may be split into several chunks
+        let point = Ed25519::hash_to_curve(&[b"a", b"bc"], &[&dst[..4], &dst[4..]]).unwrap();
+        assert!(point == Ed25519::hash_to_curve(&[b"abc"], &[dst]).unwrap());
+    }
+
+    #[test]
+    fn to_montgomery() {
+        // X25519 base point (RFC 7748, Section 4.1)
+        let g = Point::<Ed25519>::generator().to_point();
+        assert_eq!(
+            hex::encode(g.to_montgomery()),
+            "0900000000000000000000000000000000000000000000000000000000000000"
+        );
+        assert_eq!(Point::<Ed25519>::zero().to_montgomery(), [0; 32]);
+
+        // Alice's key pair (RFC 7748, Section 6.1)
+        let mut sk =
+            hex::decode("77076d0a7318a57d3c16c17251b26645df4c2f87ebc0992ab177fba51db92c2a")
+                .unwrap();
+        sk[0] &= 248;
+        sk[31] &= 127;
+        sk[31] |= 64;
+        let pk = g * Scalar::<Ed25519>::from_le_bytes_mod_order(&sk);
+        let expected_pk = "8520f0098930a754748b7ddcb43ef75a0dbf3a0d26381af4eba4a98eaa9b4e6a";
+        assert_eq!(hex::encode(pk.to_montgomery()), expected_pk);
+        assert_eq!(hex::encode((-pk).to_montgomery()), expected_pk);
+    }
+}
+
+mod ristretto255 {
+    use generic_ec::{curves::Ristretto255, Point, Scalar};
+
+    #[test]
+    fn decode_rejects_non_canonical_encodings() {
+        let non_canonical = [
+            // non-canonical field encodings
+            "00ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+            "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f",
+            "f3ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f",
+            "edffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f",
+            // negative field elements
+            "0100000000000000000000000000000000000000000000000000000000000000",
+            "01ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f",
+        ];
+        for bytes in non_canonical {
+            let bytes = hex::decode(bytes).unwrap();
+            assert!(Point::<Ristretto255>::from_bytes(&bytes).is_err());
+        }
+    }
+
+    #[test]
+    fn generator_has_prime_order() {
+        // l = 2^252 + 27742317777372353535851937790883648493
+        let l = "1000000000000000000000000000000014def9dea2f79cd65812631a5cf5d3ed";
+        assert_eq!(
+            hex::encode(Scalar::<Ristretto255>::group_order_be_bytes()),
+            l
+        );
+
+        let g = Point::<Ristretto255>::generator().to_point();
+        assert!(!g.is_zero());
+        assert_eq!(g * -Scalar::one() + g, Point::zero());
+    }
+
+    #[test]
+    fn hash_to_curve() {
+        let dst = b"QUUX-V01-CS02-with-ristretto255_XMD:SHA-512_R255MAP_RO_";
+        let point = Ristretto255::hash_to_curve(&[b"abc"], &[dst]).unwrap();
+        assert!(point != Ristretto255::hash_to_curve(&[b"abd"], &[dst]).unwrap());
+        assert!(point != Ristretto255::hash_to_curve(&[b"abc"], &[&dst[1..]]).unwrap());
+
+        // Message and DST may be split into several chunks
+        let chunked = Ristretto255::hash_to_curve(&[b"a", b"bc"], &[&dst[..4], &dst[4..]]).unwrap();
+        assert!(point == chunked);
+    }
+}
+
+mod bls12_381 {
+    use generic_ec::{
+        as_raw::TryFromRaw,
+        core::HashToCurve,
+        curves::{Bls12_381G1, Bls12_381G2},
+        Curve, Point, Scalar,
+    };
+
+    #[test]
+    fn generator_encoding() {
+        // Generators as defined in ZCash serialization format
+        let g1 = "97f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb";
+        let g2 = "93e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8";
+
+        let g = Point::<Bls12_381G1>::generator().to_point();
+        assert_eq!(hex::encode(g.to_bytes(true)), g1);
+        assert_eq!(Point::from_bytes(hex::decode(g1).unwrap()).unwrap(), g);
+
+        let g = Point::<Bls12_381G2>::generator().to_point();
+        assert_eq!(hex::encode(g.to_bytes(true)), g2);
+        assert_eq!(Point::from_bytes(hex::decode(g2).unwrap()).unwrap(), g);
+    }
+
+    #[test]
+    fn groups_have_the_same_order() {
+        let q = "73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001";
+        assert_eq!(
+            hex::encode(Scalar::<Bls12_381G1>::group_order_be_bytes()),
+            q
+        );
+        assert_eq!(
+            hex::encode(Scalar::<Bls12_381G2>::group_order_be_bytes()),
+            q
+        );
+    }
+
+    /// Test vectors from RFC 9380, Appendices J.9.1 and J.10.1. Expected points are given in
+    /// uncompressed form, i.e. as affine $x$ and $y$ coordinates.
+    #[test]
+    fn hash_to_curve() {
+        fn check<E: Curve + HashToCurve>(dst: &[u8], test_vectors: &[(&[u8], &str)]) {
+            for (msg, expected) in test_vectors {
+                let raw = E::hash_to_curve(&[msg], &[dst]).unwrap();
+                // Output is torsion-free, so it's accepted as `Point<E>`
+                let point = Point::<E>::try_from_raw(raw).unwrap();
+                assert_eq!(hex::encode(point.to_bytes(false)), *expected);
+            }
+
+            // Message and DST may be split into several chunks
+            let point = E::hash_to_curve(&[b"a", b"bc"], &[&dst[..4], &dst[4..]]).unwrap();
+            assert!(point == E::hash_to_curve(&[b"abc"], &[dst]).unwrap());
+        }
+
+        let q128 = [b"q128_".as_slice(), &[b'q'; 128]].concat();
+        let a512 = [b"a512_".as_slice(), &[b'a'; 512]].concat();
+
+        check::<Bls12_381G1>(
+            b"QUUX-V01-CS02-with-BLS12381G1_XMD:SHA-256_SSWU_RO_",
+            &[
+                (
+                    b"",
+                    "052926add2207b76ca4fa57a8734416c8dc95e24501772c814278700eed6d1e4e8cf62d9c09db0fac349612b759e79a108ba738453bfed09cb546dbb0783dbb3a5f1f566ed67bb6be0e8c67e2e81a4cc68ee29813bb7994998f3eae0c9c6a265",
+                ),
+                (
+                    b"abc",
+                    "03567bc5ef9c690c2ab2ecdf6a96ef1c139cc0b2f284dca0a9a7943388a49a3aee664ba5379a7655d3c68900be2f69030b9c15f3fe6e5cf4211f346271d7b01c8f3b28be689c8429c85b67af215533311f0b8dfaaa154fa6b88176c229f2885d",
+                ),
+                (
+                    b"abcdef0123456789",
+                    "11e0b079dea29a68f0383ee94fed1b940995272407e3bb916bbf268c263ddd57a6a27200a784cbc248e84f357ce82d9803a87ae2caf14e8ee52e51fa2ed8eefe80f02457004ba4d486d6aa1f517c0889501dc7413753f9599b099ebcbbd2d709",
+                ),
+                (
+                    &q128,
+                    "15f68eaa693b95ccb85215dc65fa81038d69629f70aeee0d0f677cf22285e7bf58d7cb86eefe8f2e9bc3f8cb84fac4881807a1d50c29f430b8cafc4f8638dfeeadf51211e1602a5f184443076715f91bb90a48ba1e370edce6ae1062f5e6dd38",
+                ),
+                (
+                    &a512,
+                    "082aabae8b7dedb0e78aeb619ad3bfd9277a2f77ba7fad20ef6aabdc6c31d19ba5a6d12283553294c1825c4b3ca2dcfe05b84ae5a942248eea39e1d91030458c40153f3b654ab7872d779ad1e942856a20c438e8d99bc8abfbf74729ce1f7ac8",
+                ),
+            ],
+        );
+        check::<Bls12_381G2>(
+            b"QUUX-V01-CS02-with-BLS12381G2_XMD:SHA-256_SSWU_RO_",
+            &[
+                (
+                    b"",
+                    "05cb8437535e20ecffaef7752baddf98034139c38452458baeefab379ba13dff5bf5dd71b72418717047f5b0f37da03d0141ebfbdca40eb85b87142e130ab689c673cf60f1a3e98d69335266f30d9b8d4ac44c1038e9dcdd5393faf5c41fb78a12424ac32561493f3fe3c260708a12b7c620e7be00099a974e259ddc7d1f6395c3c811cdd19f1e8dbf3e9ecfdcbab8d60503921d7f6a12805e72940b963c0cf3471c7b2a524950ca195d11062ee75ec076daf2d4bc358c4b190c0c98064fdd92",
+                ),
+                (
+                    b"abc",
+                    "139cddbccdc5e91b9623efd38c49f81a6f83f175e80b06fc374de9eb4b41dfe4ca3a230ed250fbe3a2acf73a41177fd802c2d18e033b960562aae3cab37a27ce00d80ccd5ba4b7fe0e7a210245129dbec7780ccc7954725f4168aff2787776e600aa65dae3c8d732d10ecd2c50f8a1baf3001578f71c694e03866e9f3d49ac1e1ce70dd94a733534f106d4cec0eddd161787327b68159716a37440985269cf584bcb1e621d3a7202be6ea05c4cfe244aeb197642555a0645fb87bf7466b2ba48",
+                ),
+                (
+                    b"abcdef0123456789",
+                    "190d119345b94fbd15497bcba94ecf7db2cbfd1e1fe7da034d26cbba169fb3968288b3fafb265f9ebd380512a71c3f2c121982811d2491fde9ba7ed31ef9ca474f0e1501297f68c298e9f4c0028add35aea8bb83d53c08cfc007c1e005723cd00bb5e7572275c567462d91807de765611490205a941a5a6af3b1691bfe596c31225d3aabdf15faff860cb4ef17c7c3be05571a0f8d3c08d094576981f4a3b8eda0a8e771fcdcc8ecceaf1356a6acf17574518acb506e435b639353c2e14827c8",
+                ),
+                (
+                    &q128,
+                    "0934aba516a52d8ae479939a91998299c76d39cc0c035cd18813bec433f587e2d7a4fef038260eef0cef4d02aae3eb9119a84dd7248a1066f737cc34502ee5555bd3c19f2ecdb3c7d9e24dc65d4e25e50d83f0f77105e955d78f4762d33c17da09bcccfa036b4847c9950780733633f13619994394c23ff0b32fa6b795844f4a0673e20282d07bc69641cee04f5e566214f81cd421617428bc3b9fe25afbb751d934a00493524bc4e065635b0555084dd54679df1536101b2c979c0152d09192",
+                ),
+                (
+                    &a512,
+                    "11fca2ff525572795a801eed17eb12785887c7b63fb77a42be46ce4a34131d71f7a73e95fee3f812aea3de78b4d0156901a6ba2f9a11fa5598b2d8ace0fbe0a0eacb65deceb476fbbcb64fd24557c2f4b18ecfc5663e54ae16a84f5ab7f6253403a47f8e6d1763ba0cad63d6114c0accbef65707825a511b251a660a9b3994249ae4e63fac38b23da0c398689ee2ab520b6798718c8aed24bc19cb27f866f1c9effcdbf92397ad6448b5c9db90d2b9da6cbabf48adc1adf59a1a28344e79d57e",
+                ),
+            ],
+        );
+    }
+}
+
+mod sm2 {
+    use generic_ec::{curves::Sm2, Point, Scalar};
+
+    #[test]
+    fn generator_encoding() {
+        // Generator as defined in GB/T 32918.5
+        let g = "0232c4ae2c1f1981195f9904466a39c9948fe30bbff2660be1715a4589334c74c7";
+        let g_uncompressed = "0432c4ae2c1f1981195f9904466a39c9948fe30bbff2660be1715a4589334c74c7\
+            bc3736a2f4f6779c59bdcee36b692153d0a9877cc62a474002df32e52139f0a0";
+
+        let generator = Point::<Sm2>::generator().to_point();
+        assert_eq!(hex::encode(generator.to_bytes(true)), g);
+        assert_eq!(hex::encode(generator.to_bytes(false)), g_uncompressed);
+        assert_eq!(
+            Point::from_bytes(hex::decode(g).unwrap()).unwrap(),
+            generator
+        );
+    }
+
+    #[test]
+    fn group_order() {
+        assert_eq!(
+            hex::encode(Scalar::<Sm2>::group_order_be_bytes()),
+            "fffffffeffffffffffffffffffffffff7203df6b21c6052b53bbf40939d54123"
+        );
+    }
+}
+
+mod keccak {
+    use generic_ec::{curves::Secp256k1, Point, Scalar};
+    use sha3::{Digest, Keccak256};
+
+    /// Ethereum address of the public key: last 20 bytes of keccak256 of uncompressed
+    /// point coordinates
+    fn eth_address(point: &Point<Secp256k1>) -> [u8; 20] {
+        let encoded = point.to_bytes(false);
+        let hash = Keccak256::digest(&encoded[1..]);
+        hash[12..].try_into().unwrap()
+    }
+
+    #[test]
+    fn known_answers() {
+        let test_vectors: &[(&[u8], &str)] = &[
+            (
+                b"",
+                "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470",
+            ),
+            (
+                b"abc",
+                "4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45",
+            ),
+        ];
+        for (msg, expected) in test_vectors {
+            let expected =
+                Scalar::<Secp256k1>::from_be_bytes(hex::decode(expected).unwrap()).unwrap();
+            assert_eq!(Scalar::<Secp256k1>::keccak256(msg), expected);
+            assert_eq!(
+                Scalar::<Secp256k1>::from_keccak256(Keccak256::new_with_prefix(msg)),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn ecrecover_style_challenge() {
+        // Challenge as computed by Schnorr verifiers on Ethereum that use `ecrecover`:
+        // `uint256(keccak256(abi.encodePacked(pk.x, pk_parity, msg_hash, nonce_address))) % n`
+        let pk = Point::<Secp256k1>::generator().to_point();
+        let nonce = Point::<Secp256k1>::generator() * Scalar::from(2);
+
+        assert_eq!(
+            hex::encode(eth_address(&pk)),
+            "7e5f4552091a69125d5dfcb7b8c2659029395bdf"
+        );
+        assert_eq!(
+            hex::encode(eth_address(&nonce)),
+            "2b5ad5c4795c026514f8317c7a215e218dccd6cf"
+        );
+
+        let pk_compressed = pk.to_bytes(true);
+        let pk_parity = 27 + pk_compressed[0] - 2;
+        let msg_hash = Keccak256::digest(b"hello");
+
+        let challenge = Scalar::<Secp256k1>::from_keccak256(
+            Keccak256::new()
+                .chain_update(&pk_compressed[1..])
+                .chain_update([pk_parity])
+                .chain_update(msg_hash)
+                .chain_update(eth_address(&nonce)),
+        );
+        assert_eq!(
+            hex::encode(challenge.to_be_bytes()),
+            "3af372204cb240396ed88ad90adbd11bfeb49206c6ee9e910f975c1920f6ae01"
+        );
+    }
+
+    #[test]
+    fn digest_is_reduced_like_uint256_mod_n() {
+        // Keccak outputs above the order can't be found in practice, so check reduction of
+        // 32 bytes integers that are used as input
+        let n = Scalar::<Secp256k1>::group_order_be_bytes();
+        let mut n_plus_5 = [0u8; 32];
+        n_plus_5.copy_from_slice(n.as_ref());
+        n_plus_5[31] += 5;
+        assert_eq!(
+            Scalar::<Secp256k1>::from_be_bytes_mod_order(n_plus_5),
+            Scalar::from(5)
+        );
+
+        // 2^256 - 1 = n + 0x14551231950b75fc4402da1732fc9bebe
+        assert_eq!(
+            hex::encode(Scalar::<Secp256k1>::from_be_bytes_mod_order([0xff; 32]).to_be_bytes()),
+            "000000000000000000000000000000014551231950b75fc4402da1732fc9bebe"
+        );
+    }
+}
+
+mod rfc6979 {
+    use generic_ec::{curves::Secp256r1, Scalar};
+    use sha2::{Digest, Sha256, Sha384, Sha512};
+
+    /// Test vectors from RFC 6979, Appendix A.2.5
+    #[test]
+    fn p256_test_vectors() {
+        let secret = Scalar::<Secp256r1>::from_be_bytes(
+            hex::decode("c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721")
+                .unwrap(),
+        )
+        .unwrap();
+
+        fn nonce<D: Digest + sha2::digest::core_api::BlockSizeUser>(
+            secret: &Scalar<Secp256r1>,
+            msg: &[u8],
+        ) -> String {
+            let nonce = Scalar::derive_nonce_rfc6979::<D>(secret, &D::digest(msg));
+            hex::encode(nonce.to_be_bytes())
+        }
+
+        assert_eq!(
+            nonce::<Sha256>(&secret, b"sample"),
+            "a6e3c57dd01abe90086538398355dd4c3b17aa873382b0f24d6129493d8aad60"
+        );
+        assert_eq!(
+            nonce::<Sha384>(&secret, b"sample"),
+            "09f634b188cefd98e7ec88b1aa9852d734d0bc272f7d2a47decc6ebeb375aad4"
+        );
+        assert_eq!(
+            nonce::<Sha512>(&secret, b"sample"),
+            "5fa81c63109badb88c1f367b47da606da28cad69aa22c4fe6ad7df73a7173aa5"
+        );
+        assert_eq!(
+            nonce::<Sha256>(&secret, b"test"),
+            "d16b6ae827f17175e040871a1c7ec3500192c4c92677336ec2537acaee0008e0"
+        );
+        assert_eq!(
+            nonce::<Sha512>(&secret, b"test"),
+            "6915d11632aca3c40d5d51c08daf9c555933819548784480e93499000d9f0b7f"
+        );
+    }
+}
+
+mod uint_conversion {
+    use generic_ec::Scalar;
+    use rand_dev::DevRng;
+
+    macro_rules! uint_tests {
+        ($mod:ident, $curve:ty $(, $extra:ident)*) => {
+            mod $mod {
+                use super::*;
+
+                #[test]
+                fn scalar_to_uint_and_back() {
+                    let mut rng = DevRng::new();
+
+                    let scalar = Scalar::<$curve>::random(&mut rng);
+                    let uint = scalar.to_uint();
+                    assert_eq!(Scalar::<$curve>::from_uint(&uint).unwrap(), scalar);
+
+                    let zero = Scalar::<$curve>::zero().to_uint();
+                    assert_eq!(Scalar::<$curve>::from_uint(&zero).unwrap(), Scalar::zero());
+                    let minus_one = (-Scalar::<$curve>::one()).to_uint();
+                    assert_eq!(
+                        Scalar::<$curve>::from_uint(&minus_one).unwrap(),
+                        -Scalar::one()
+                    );
+                }
+
+                #[test]
+                fn uint_out_of_range_is_rejected() {
+                    let one = Scalar::<$curve>::one().to_uint();
+                    let order = (-Scalar::<$curve>::one()).to_uint().wrapping_add(&one);
+                    assert!(bool::from(Scalar::<$curve>::from_uint(&order).is_none()));
+
+                    let order_plus_one = order.wrapping_add(&one);
+                    assert!(bool::from(
+                        Scalar::<$curve>::from_uint(&order_plus_one).is_none()
+                    ));
+
+                    let zero = Scalar::<$curve>::zero().to_uint();
+                    let max = zero.wrapping_sub(&one);
+                    assert!(bool::from(Scalar::<$curve>::from_uint(&max).is_none()));
+                }
+
+                $(uint_tests!(@$extra, $curve);)*
+            }
+        };
+        (@from_uint_reduced, $curve:ty) => {
+            #[test]
+            fn uint_is_reduced_mod_order() {
+                let mut rng = DevRng::new();
+
+                let scalar = Scalar::<$curve>::random(&mut rng);
+                assert_eq!(Scalar::<$curve>::from_uint_reduced(&scalar.to_uint()), scalar);
+
+                let one = Scalar::<$curve>::one().to_uint();
+                let order = (-Scalar::<$curve>::one()).to_uint().wrapping_add(&one);
+                assert_eq!(Scalar::<$curve>::from_uint_reduced(&order), Scalar::zero());
+
+                let order_plus_one = order.wrapping_add(&one);
+                assert_eq!(
+                    Scalar::<$curve>::from_uint_reduced(&order_plus_one),
+                    Scalar::one()
+                );
+            }
+        };
+    }
+
+    uint_tests!(secp256k1, generic_ec::curves::Secp256k1, from_uint_reduced);
+    uint_tests!(secp256r1, generic_ec::curves::Secp256r1, from_uint_reduced);
+    uint_tests!(secp384r1, generic_ec::curves::Secp384r1, from_uint_reduced);
+    uint_tests!(sm2, generic_ec::curves::Sm2, from_uint_reduced);
+    uint_tests!(stark, generic_ec::curves::Stark, from_uint_reduced);
+    uint_tests!(ed25519, generic_ec::curves::Ed25519);
+    uint_tests!(ristretto255, generic_ec::curves::Ristretto255);
+    uint_tests!(bls12_381_g1, generic_ec::curves::Bls12_381G1);
+}
+
+fn naive_scalar_from_be_bytes_mod_order<E: generic_ec::Curve>(
+    bytes: &[u8],
+) -> generic_ec::Scalar<E> {
+    let scalar_0x100 = generic_ec::Scalar::from(0x100);
 
     bytes
         .iter()
@@ -380,3 +2500,18 @@ fn naive_scalar_from_le_bytes_mod_order<E: generic_ec::Curve>(
             acc * scalar_0x100 + generic_ec::Scalar::from(*s_i)
         })
 }
+fn naive_pow<E: generic_ec::Curve>(
+    base: &generic_ec::Scalar<E>,
+    exp: &generic_ec::Scalar<E>,
+) -> generic_ec::Scalar<E> {
+    let mut result = generic_ec::Scalar::one();
+    for byte in exp.to_be_bytes().as_bytes() {
+        for bit in (0..8).rev() {
+            result *= result;
+            if (byte >> bit) & 1 == 1 {
+                result *= base;
+            }
+        }
+    }
+    result
+}
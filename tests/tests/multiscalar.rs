@@ -3,8 +3,11 @@ mod tests {
     use core::iter;
 
     use generic_ec::{
-        curves::{Ed25519, Secp256k1, Secp256r1, Stark},
-        multiscalar::{Dalek, MultiscalarMul, Naive, Straus},
+        curves::{
+            Bls12_381G1, Bls12_381G2, Ed25519, Ristretto255, Secp256k1, Secp256r1, Secp384r1, Sm2,
+            Stark,
+        },
+        multiscalar::{Dalek, MultiscalarMul, Naive, Pippenger, Straus},
         Curve, Point, Scalar,
     };
     use rand::Rng;
@@ -12,7 +15,13 @@ mod tests {
     #[test]
     fn multiscalar_mul<E: Curve, M: MultiscalarMul<E>>() {
         let mut rng = rand_dev::DevRng::new();
-        let lengths = [1, 2, rng.gen_range(3..=20), rng.gen_range(50..=150)];
+        let lengths = [
+            1,
+            2,
+            rng.gen_range(3..=20),
+            rng.gen_range(50..=150),
+            rng.gen_range(200..=400),
+        ];
 
         for len in lengths {
             let scalar_points = iter::repeat_with(|| {
@@ -31,14 +40,297 @@ mod tests {
         }
     }
 
+    #[test]
+    fn multiscalar_mul_short_scalars<E: Curve, M: MultiscalarMul<E>>() {
+        let mut rng = rand_dev::DevRng::new();
+
+        for scalar_bits in [0, 1, 8, 128] {
+            let len = rng.gen_range(1..=50);
+            let scalar_points = iter::repeat_with(|| {
+                (
+                    Scalar::<E>::random_bits(&mut rng, scalar_bits),
+                    Scalar::<E>::random(&mut rng) * Point::generator(),
+                )
+            })
+            .take(len)
+            .collect::<Vec<_>>();
+
+            let actual = M::multiscalar_mul(scalar_points.iter().copied());
+            let expected = Naive::multiscalar_mul(scalar_points.iter().copied());
+
+            assert_eq!(actual, expected);
+        }
+    }
+
     #[instantiate_tests(<Secp256k1, Straus>)]
     mod secp256k1_straus {}
     #[instantiate_tests(<Secp256r1, Straus>)]
     mod secp256r1_straus {}
+    #[instantiate_tests(<Secp384r1, Straus>)]
+    mod secp384r1_straus {}
+    #[instantiate_tests(<Sm2, Straus>)]
+    mod sm2_straus {}
     #[instantiate_tests(<Stark, Straus>)]
     mod stark_straus {}
     #[instantiate_tests(<Ed25519, Straus>)]
     mod ed25519_straus {}
     #[instantiate_tests(<Ed25519, Dalek>)]
     mod ed25519_dalek {}
+    #[instantiate_tests(<Ristretto255, Straus>)]
+    mod ristretto255_straus {}
+    #[instantiate_tests(<Bls12_381G1, Straus>)]
+    mod bls12_381_g1_straus {}
+    #[instantiate_tests(<Bls12_381G2, Straus>)]
+    mod bls12_381_g2_straus {}
+
+    #[instantiate_tests(<Secp256k1, Pippenger>)]
+    mod secp256k1_pippenger {}
+    #[instantiate_tests(<Secp256r1, Pippenger>)]
+    mod secp256r1_pippenger {}
+    #[instantiate_tests(<Secp384r1, Pippenger>)]
+    mod secp384r1_pippenger {}
+    #[instantiate_tests(<Sm2, Pippenger>)]
+    mod sm2_pippenger {}
+    #[instantiate_tests(<Stark, Pippenger>)]
+    mod stark_pippenger {}
+    #[instantiate_tests(<Ed25519, Pippenger>)]
+    mod ed25519_pippenger {}
+    #[instantiate_tests(<Ristretto255, Pippenger>)]
+    mod ristretto255_pippenger {}
+    #[instantiate_tests(<Bls12_381G1, Pippenger>)]
+    mod bls12_381_g1_pippenger {}
+    #[instantiate_tests(<Bls12_381G2, Pippenger>)]
+    mod bls12_381_g2_pippenger {}
+}
+
+#[generic_tests::define]
+mod straus_width {
+    use core::iter;
+
+    use generic_ec::{
+        curves::{
+            Bls12_381G1, Bls12_381G2, Ed25519, Ristretto255, Secp256k1, Secp256r1, Secp384r1, Sm2,
+            Stark,
+        },
+        multiscalar::{MultiscalarMul, Naive, Straus},
+        Curve, Point, Scalar,
+    };
+    use rand::Rng;
+
+    #[test]
+    fn multiscalar_mul_with_width<E: Curve>() {
+        let mut rng = rand_dev::DevRng::new();
+
+        for len in [0, 1, rng.gen_range(2..=30)] {
+            let scalar_points = iter::repeat_with(|| {
+                (
+                    Scalar::<E>::random(&mut rng),
+                    Scalar::<E>::random(&mut rng) * Point::generator(),
+                )
+            })
+            .take(len)
+            .collect::<Vec<_>>();
+
+            let expected = Naive::multiscalar_mul(scalar_points.iter().copied());
+            for w in 2..=8 {
+                let actual = Straus::multiscalar_mul_with_width(w, scalar_points.iter().copied());
+                assert_eq!(actual, expected, "w = {w}");
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn width_out_of_range<E: Curve>() {
+        let scalar_points = [(Scalar::<E>::one(), Point::<E>::generator().to_point())];
+        Straus::multiscalar_mul_with_width(9, scalar_points.iter().copied());
+    }
+
+    #[instantiate_tests(<Secp256k1>)]
+    mod secp256k1 {}
+    #[instantiate_tests(<Secp256r1>)]
+    mod secp256r1 {}
+    #[instantiate_tests(<Secp384r1>)]
+    mod secp384r1 {}
+    #[instantiate_tests(<Sm2>)]
+    mod sm2 {}
+    #[instantiate_tests(<Stark>)]
+    mod stark {}
+    #[instantiate_tests(<Ed25519>)]
+    mod ed25519 {}
+    #[instantiate_tests(<Ristretto255>)]
+    mod ristretto255 {}
+    #[instantiate_tests(<Bls12_381G1>)]
+    mod bls12_381_g1 {}
+    #[instantiate_tests(<Bls12_381G2>)]
+    mod bls12_381_g2 {}
+}
+
+#[generic_tests::define]
+mod parallel {
+    use core::iter;
+
+    use generic_ec::{
+        curves::{
+            Bls12_381G1, Bls12_381G2, Ed25519, Ristretto255, Secp256k1, Secp256r1, Secp384r1, Sm2,
+            Stark,
+        },
+        multiscalar::{self, MultiscalarMul, Parallel, Straus},
+        Curve, Point, Scalar,
+    };
+    use rand::Rng;
+    use rayon::prelude::*;
+
+    #[test]
+    fn parallel_multiscalar_mul<E: Curve>() {
+        let mut rng = rand_dev::DevRng::new();
+        let lengths = [1, rng.gen_range(2..=300), rng.gen_range(1000..=3000)];
+
+        for len in lengths {
+            let scalar_points = iter::repeat_with(|| {
+                (
+                    Scalar::<E>::random(&mut rng),
+                    Scalar::<E>::random(&mut rng) * Point::generator(),
+                )
+            })
+            .take(len)
+            .collect::<Vec<_>>();
+
+            let actual = Parallel::multiscalar_mul(scalar_points.iter().copied());
+            let expected = Straus::multiscalar_mul(scalar_points.iter().copied());
+            assert_eq!(actual, expected);
+
+            let default = Scalar::multiscalar_mul(scalar_points.iter().copied());
+            assert_eq!(default, expected);
+
+            let par_iter = multiscalar::parallel_multiscalar_mul(
+                scalar_points.par_iter().map(|(s, p)| (s, p)),
+            );
+            assert_eq!(par_iter, expected);
+        }
+    }
+
+    #[instantiate_tests(<Secp256k1>)]
+    mod secp256k1 {}
+    #[instantiate_tests(<Secp256r1>)]
+    mod secp256r1 {}
+    #[instantiate_tests(<Secp384r1>)]
+    mod secp384r1 {}
+    #[instantiate_tests(<Sm2>)]
+    mod sm2 {}
+    #[instantiate_tests(<Stark>)]
+    mod stark {}
+    #[instantiate_tests(<Ed25519>)]
+    mod ed25519 {}
+    #[instantiate_tests(<Ristretto255>)]
+    mod ristretto255 {}
+    #[instantiate_tests(<Bls12_381G1>)]
+    mod bls12_381_g1 {}
+    #[instantiate_tests(<Bls12_381G2>)]
+    mod bls12_381_g2 {}
+}
+
+#[generic_tests::define]
+mod mixed {
+    use core::iter;
+
+    use generic_ec::{
+        curves::{
+            Bls12_381G1, Bls12_381G2, Ed25519, Ristretto255, Secp256k1, Secp256r1, Secp384r1, Sm2,
+            Stark,
+        },
+        multiscalar::{MultiscalarMul, Naive},
+        Curve, Point, Scalar,
+    };
+    use rand::Rng;
+
+    #[test]
+    fn multiscalar_mul_mixed<E: Curve>() {
+        let mut rng = rand_dev::DevRng::new();
+        let table = Point::<E>::generator_table();
+
+        for (generator_len, points_len) in
+            [(0, 0), (1, 0), (0, 1), (1, 5), (3, rng.gen_range(10..=50))]
+        {
+            let generator_terms = iter::repeat_with(|| Scalar::<E>::random(&mut rng))
+                .take(generator_len)
+                .collect::<Vec<_>>();
+            let point_terms = iter::repeat_with(|| {
+                (
+                    Scalar::<E>::random(&mut rng),
+                    Scalar::<E>::random(&mut rng) * Point::generator(),
+                )
+            })
+            .take(points_len)
+            .collect::<Vec<_>>();
+
+            let actual = Scalar::multiscalar_mul_mixed(
+                &table,
+                &generator_terms,
+                point_terms.iter().copied(),
+            );
+
+            let g = Point::generator().to_point();
+            let all_terms = generator_terms
+                .iter()
+                .map(|s| (*s, g))
+                .chain(point_terms.iter().copied())
+                .collect::<Vec<_>>();
+            let expected = Naive::multiscalar_mul(all_terms.into_iter());
+            assert_eq!(actual, expected);
+        }
+    }
+
+    #[instantiate_tests(<Secp256k1>)]
+    mod secp256k1 {}
+    #[instantiate_tests(<Secp256r1>)]
+    mod secp256r1 {}
+    #[instantiate_tests(<Secp384r1>)]
+    mod secp384r1 {}
+    #[instantiate_tests(<Sm2>)]
+    mod sm2 {}
+    #[instantiate_tests(<Stark>)]
+    mod stark {}
+    #[instantiate_tests(<Ed25519>)]
+    mod ed25519 {}
+    #[instantiate_tests(<Ristretto255>)]
+    mod ristretto255 {}
+    #[instantiate_tests(<Bls12_381G1>)]
+    mod bls12_381_g1 {}
+    #[instantiate_tests(<Bls12_381G2>)]
+    mod bls12_381_g2 {}
+}
+
+mod ed25519 {
+    use core::iter;
+
+    use generic_ec::{
+        curves::Ed25519,
+        multiscalar::{Dalek, MultiscalarMul, Straus},
+        Point, Scalar,
+    };
+    use rand::Rng;
+
+    #[test]
+    fn default_agrees_with_straus_and_dalek() {
+        let mut rng = rand_dev::DevRng::new();
+
+        for len in [0, 1, rng.gen_range(2..=50), rng.gen_range(300..=500)] {
+            let scalar_points = iter::repeat_with(|| {
+                (
+                    Scalar::<Ed25519>::random(&mut rng),
+                    Scalar::<Ed25519>::random(&mut rng) * Point::generator(),
+                )
+            })
+            .take(len)
+            .collect::<Vec<_>>();
+
+            let straus = Straus::multiscalar_mul(scalar_points.iter().copied());
+            let dalek = Dalek::multiscalar_mul(scalar_points.iter().copied());
+            let default = Scalar::multiscalar_mul(scalar_points.iter().copied());
+
+            assert_eq!(straus, dalek);
+            assert_eq!(default, dalek);
+        }
+    }
 }
@@ -0,0 +1,69 @@
+#[generic_tests::define]
+mod tests {
+    use generic_ec::{
+        curves::{
+            Bls12_381G1, Bls12_381G2, Ed25519, Ristretto255, Secp256k1, Secp256r1, Secp384r1, Sm2,
+            Stark,
+        },
+        proptest::{non_zero_scalar, point, scalar},
+        Curve, Point, Scalar,
+    };
+    use proptest::prelude::*;
+
+    fn config() -> ProptestConfig {
+        ProptestConfig::with_cases(32)
+    }
+
+    #[test]
+    fn scalar_arithmetic<E: Curve>() {
+        proptest!(config(), |(a in scalar::<E>(), b in scalar::<E>(), c in non_zero_scalar::<E>())| {
+            prop_assert_eq!(a + b, b + a);
+            prop_assert_eq!(a * (b + c), a * b + a * c);
+            prop_assert_eq!(a * c * c.invert(), a);
+        });
+    }
+
+    #[test]
+    fn point_arithmetic<E: Curve>() {
+        proptest!(config(), |(a in scalar::<E>(), p in point::<E>(), q in point::<E>())| {
+            prop_assert_eq!(p + q, q + p);
+            prop_assert_eq!((p + q) * a, p * a + q * a);
+            prop_assert_eq!(p + (-p), Point::zero());
+        });
+    }
+
+    #[test]
+    fn scalar_encoding<E: Curve>() {
+        proptest!(config(), |(a in scalar::<E>())| {
+            prop_assert_eq!(Scalar::<E>::from_be_bytes(a.to_be_bytes()).unwrap(), a);
+            prop_assert_eq!(Scalar::<E>::from_le_bytes(a.to_le_bytes()).unwrap(), a);
+        });
+    }
+
+    #[instantiate_tests(<Secp256k1>)]
+    mod secp256k1 {}
+
+    #[instantiate_tests(<Secp256r1>)]
+    mod secp256r1 {}
+
+    #[instantiate_tests(<Secp384r1>)]
+    mod secp384r1 {}
+
+    #[instantiate_tests(<Sm2>)]
+    mod sm2 {}
+
+    #[instantiate_tests(<Stark>)]
+    mod stark {}
+
+    #[instantiate_tests(<Ed25519>)]
+    mod ed25519 {}
+
+    #[instantiate_tests(<Ristretto255>)]
+    mod ristretto255 {}
+
+    #[instantiate_tests(<Bls12_381G1>)]
+    mod bls12_381_g1 {}
+
+    #[instantiate_tests(<Bls12_381G2>)]
+    mod bls12_381_g2 {}
+}
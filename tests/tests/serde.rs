@@ -1,6 +1,6 @@
 #[generic_tests::define]
 mod tests {
-    use generic_ec::{Curve, Point, Scalar};
+    use generic_ec::{core::ByteArray, Curve, EncodedPoint, EncodedScalar, NonZero, Point, Scalar};
     use serde_test::{Configure, Token};
 
     #[test]
@@ -202,6 +202,79 @@ mod tests {
         }
     }
 
+    #[test]
+    fn deserialize_non_zero_point_rejects_identity<E: Curve>() {
+        let identity = Point::<E>::zero().to_bytes(false).to_vec().leak();
+        let identity_hex = hex::encode(identity).leak();
+
+        serde_test::assert_de_tokens_error::<serde_test::Readable<NonZero<Point<E>>>>(
+            &[
+                Token::Struct {
+                    name: "PointUncompressed",
+                    len: 2,
+                },
+                Token::Str("curve"),
+                Token::Str(E::CURVE_NAME),
+                Token::Str("point"),
+                Token::Str(identity_hex),
+                Token::StructEnd,
+            ],
+            "expected non-zero point, got identity",
+        );
+    }
+
+    #[test]
+    fn serde_encoded_point<E: Curve>() {
+        let mut rng = rand_dev::DevRng::new();
+
+        let random_point = Point::<E>::generator() * Scalar::random(&mut rng);
+        for point in [Point::zero(), Point::generator().into(), random_point] {
+            for compressed in [true, false] {
+                let encoded = point.to_bytes(compressed);
+                let bytes = encoded.to_vec().leak();
+                let bytes_hex = hex::encode(&bytes).leak();
+
+                serde_test::assert_tokens(&encoded.clone().readable(), &[Token::Str(bytes_hex)]);
+                serde_test::assert_tokens(&encoded.clone().compact(), &[Token::Bytes(bytes)]);
+            }
+        }
+
+        let wrong_len = vec![0u8; E::CompressedPointArray::LEN + 1].leak();
+        serde_test::assert_de_tokens_error::<serde_test::Compact<EncodedPoint<E>>>(
+            &[Token::Bytes(wrong_len)],
+            &format!(
+                "invalid length {}, expected {} or {} bytes",
+                wrong_len.len(),
+                E::CompressedPointArray::LEN,
+                E::UncompressedPointArray::LEN
+            ),
+        );
+    }
+
+    #[test]
+    fn serde_encoded_scalar<E: Curve>() {
+        let mut rng = rand_dev::DevRng::new();
+
+        let scalar = Scalar::<E>::random(&mut rng);
+        for encoded in [scalar.to_be_bytes(), scalar.to_le_bytes()] {
+            let bytes = encoded.to_vec().leak();
+            let bytes_hex = hex::encode(&bytes).leak();
+
+            serde_test::assert_tokens(&encoded.clone().readable(), &[Token::Str(bytes_hex)]);
+            serde_test::assert_tokens(&encoded.clone().compact(), &[Token::Bytes(bytes)]);
+        }
+
+        let too_short = vec![0u8; Scalar::<E>::SERIALIZED_LEN - 1].leak();
+        serde_test::assert_de_tokens_error::<serde_test::Compact<EncodedScalar<E>>>(
+            &[Token::Bytes(too_short)],
+            &format!(
+                "invalid length {}, expected {} bytes",
+                too_short.len(),
+                Scalar::<E>::SERIALIZED_LEN
+            ),
+        );
+    }
+
     #[test]
     fn serialize_scalar<E: Curve>() {
         let mut rng = rand_dev::DevRng::new();
@@ -358,6 +431,100 @@ mod tests {
         }
     }
 
+    #[test]
+    fn fixed_bincode<E: Curve>() {
+        let mut rng = rand_dev::DevRng::new();
+
+        let random_point = Point::<E>::generator() * Scalar::random(&mut rng);
+        for point in [Point::zero(), Point::generator().into(), random_point] {
+            let bytes = bincode::serialize(&Fixed(point)).unwrap();
+            assert_eq!(bytes.len(), E::CompressedPointArray::LEN);
+            assert_eq!(bytes, point.to_bytes(true).as_bytes());
+
+            let decoded: Fixed<Point<E>> = bincode::deserialize(&bytes).unwrap();
+            assert_eq!(decoded.0, point);
+            assert!(bincode::deserialize::<Fixed<Point<E>>>(&bytes[1..]).is_err());
+
+            // Human-readable formats serialize points in the same way as compact format
+            assert_eq!(
+                serde_json::to_string(&Fixed(point)).unwrap(),
+                serde_json::to_string(&Compact(point)).unwrap()
+            );
+        }
+
+        let scalar = Scalar::<E>::random(&mut rng);
+        let bytes = bincode::serialize(&Fixed(scalar)).unwrap();
+        assert_eq!(bytes.len(), Scalar::<E>::SERIALIZED_LEN);
+        assert_eq!(bytes, scalar.to_be_bytes().as_bytes());
+        let decoded: Fixed<Scalar<E>> = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(decoded.0, scalar);
+        assert!(bincode::deserialize::<Fixed<Scalar<E>>>(&bytes[1..]).is_err());
+        assert!(bincode::deserialize::<Fixed<NonZero<Scalar<E>>>>(
+            Scalar::<E>::zero().to_be_bytes().as_bytes()
+        )
+        .is_err());
+
+        let pair = (Fixed(random_point), Fixed(scalar));
+        let bytes = bincode::serialize(&pair).unwrap();
+        assert_eq!(
+            bytes.len(),
+            E::CompressedPointArray::LEN + Scalar::<E>::SERIALIZED_LEN
+        );
+        let decoded: (Fixed<Point<E>>, Fixed<Scalar<E>>) = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(decoded, pair);
+    }
+
+    #[test]
+    fn compressed_tagged<E: Curve>() {
+        let mut rng = rand_dev::DevRng::new();
+
+        let random_point = Point::<E>::generator() * Scalar::random(&mut rng);
+        for point in [Point::zero(), Point::generator().into(), random_point] {
+            let json = serde_json::to_value(CompressedTagged(point)).unwrap();
+            assert_eq!(
+                json,
+                serde_json::json!({
+                    "curve": E::CURVE_NAME,
+                    "point": hex::encode(point.to_bytes(true)),
+                })
+            );
+            let decoded: CompressedTagged<Point<E>> = serde_json::from_value(json.clone()).unwrap();
+            assert_eq!(decoded.0, point);
+
+            let bytes = bincode::serialize(&CompressedTagged(point)).unwrap();
+            let decoded: CompressedTagged<Point<E>> = bincode::deserialize(&bytes).unwrap();
+            assert_eq!(decoded.0, point);
+
+            // Curve name is still checked
+            let other_curve = if E::CURVE_NAME == "secp256k1" {
+                "secp256r1"
+            } else {
+                "secp256k1"
+            };
+            let mut mismatched = json;
+            mismatched["curve"] = other_curve.into();
+            assert!(serde_json::from_value::<CompressedTagged<Point<E>>>(mismatched).is_err());
+
+            // Point in default (uncompressed) format is not accepted
+            let uncompressed = serde_json::to_value(point).unwrap();
+            if point.to_bytes(true) != point.to_bytes(false) {
+                assert!(
+                    serde_json::from_value::<CompressedTagged<Point<E>>>(uncompressed).is_err()
+                );
+            }
+        }
+
+        // Scalars are serialized in default format
+        let scalar = Scalar::<E>::random(&mut rng);
+        assert_eq!(
+            serde_json::to_string(&CompressedTagged(scalar)).unwrap(),
+            serde_json::to_string(&scalar).unwrap()
+        );
+        let decoded: CompressedTagged<NonZero<Scalar<E>>> =
+            serde_json::from_str(&serde_json::to_string(&scalar).unwrap()).unwrap();
+        assert_eq!(decoded.0, scalar);
+    }
+
     #[derive(PartialEq, Eq, Debug)]
     struct Compact<T>(T);
     impl<T> serde::Serialize for Compact<T>
@@ -412,12 +579,180 @@ mod tests {
         }
     }
 
+    #[derive(PartialEq, Eq, Debug)]
+    struct Fixed<T>(T);
+    impl<T> serde::Serialize for Fixed<T>
+    where
+        generic_ec::serde::Fixed: serde_with::SerializeAs<T>,
+    {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+        {
+            use serde_with::SerializeAs;
+            generic_ec::serde::Fixed::serialize_as(&self.0, serializer)
+        }
+    }
+    impl<'de, T> serde::Deserialize<'de> for Fixed<T>
+    where
+        generic_ec::serde::Fixed: serde_with::DeserializeAs<'de, T>,
+    {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            use serde_with::DeserializeAs;
+            generic_ec::serde::Fixed::deserialize_as(deserializer).map(Self)
+        }
+    }
+
+    #[derive(PartialEq, Eq, Debug)]
+    struct CompressedTagged<T>(T);
+    impl<T> serde::Serialize for CompressedTagged<T>
+    where
+        generic_ec::serde::CompressedTagged: serde_with::SerializeAs<T>,
+    {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+        {
+            use serde_with::SerializeAs;
+            generic_ec::serde::CompressedTagged::serialize_as(&self.0, serializer)
+        }
+    }
+    impl<'de, T> serde::Deserialize<'de> for CompressedTagged<T>
+    where
+        generic_ec::serde::CompressedTagged: serde_with::DeserializeAs<'de, T>,
+    {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            use serde_with::DeserializeAs;
+            generic_ec::serde::CompressedTagged::deserialize_as(deserializer).map(Self)
+        }
+    }
+
     #[instantiate_tests(<generic_ec::curves::Secp256k1>)]
     mod secp256k1 {}
 
     #[instantiate_tests(<generic_ec::curves::Secp256r1>)]
     mod secp256r1 {}
 
+    #[instantiate_tests(<generic_ec::curves::Secp384r1>)]
+    mod secp384r1 {}
+
+    #[instantiate_tests(<generic_ec::curves::Sm2>)]
+    mod sm2 {}
+
     #[instantiate_tests(<generic_ec::curves::Stark>)]
     mod stark {}
+
+    #[instantiate_tests(<generic_ec::curves::Bls12_381G1>)]
+    mod bls12_381_g1 {}
+
+    #[instantiate_tests(<generic_ec::curves::Bls12_381G2>)]
+    mod bls12_381_g2 {}
+}
+
+mod any_curve {
+    use generic_ec::{
+        curves::{Secp256k1, Secp256r1, Secp384r1},
+        serde::AnyCurvePoint,
+        Curve, Point, Scalar,
+    };
+    use serde_test::{Configure, Token};
+
+    fn random_point<E: Curve>() -> Point<E> {
+        let mut rng = rand_dev::DevRng::new();
+        Point::generator() * Scalar::random(&mut rng)
+    }
+
+    #[test]
+    fn deserialize_envelopes() {
+        let p = random_point::<Secp256k1>();
+        let q = random_point::<Secp256r1>();
+
+        let json = serde_json::to_string(&p).unwrap();
+        let decoded: AnyCurvePoint = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded, AnyCurvePoint::Secp256k1(p));
+        assert_eq!(decoded.curve_name(), "secp256k1");
+        assert_eq!(serde_json::to_string(&decoded).unwrap(), json);
+
+        let json = serde_json::to_string(&q).unwrap();
+        let decoded: AnyCurvePoint = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded, AnyCurvePoint::Secp256r1(q));
+        assert_eq!(decoded.curve_name(), "secp256r1");
+        assert_eq!(serde_json::to_string(&decoded).unwrap(), json);
+
+        // Binary format
+        let bytes = bincode::serialize(&q).unwrap();
+        let decoded: AnyCurvePoint = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(decoded, AnyCurvePoint::Secp256r1(q));
+        assert_eq!(bincode::serialize(&decoded).unwrap(), bytes);
+    }
+
+    #[test]
+    fn curve_field_may_come_after_point() {
+        let p = random_point::<Secp256k1>();
+        let point_hex = hex::encode(p.to_bytes(false)).leak();
+
+        serde_test::assert_de_tokens(
+            &AnyCurvePoint::from(p).readable(),
+            &[
+                Token::Struct {
+                    name: "PointUncompressed",
+                    len: 2,
+                },
+                Token::Str("point"),
+                Token::Str(point_hex),
+                Token::Str("curve"),
+                Token::Str("secp256k1"),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn conversions() {
+        let p = random_point::<Secp256k1>();
+        let any = AnyCurvePoint::from(p);
+
+        assert_eq!(Point::<Secp256k1>::try_from(any).unwrap(), p);
+        let err = Point::<Secp256r1>::try_from(any).unwrap_err();
+        assert_eq!(err.expected(), "secp256r1");
+        assert_eq!(err.got(), "secp256k1");
+    }
+
+    #[test]
+    fn rejects_invalid_envelopes() {
+        // Unknown curve
+        let json = r#"{"curve":"unknown","point":"00"}"#;
+        assert!(serde_json::from_str::<AnyCurvePoint>(json).is_err());
+
+        // Point on secp384r1 tagged as secp256r1
+        let point = random_point::<Secp384r1>();
+        let json = format!(
+            r#"{{"curve":"secp256r1","point":"{}"}}"#,
+            hex::encode(point.to_bytes(false))
+        );
+        assert!(serde_json::from_str::<AnyCurvePoint>(&json).is_err());
+        let json = format!(
+            r#"{{"point":"{}","curve":"secp256r1"}}"#,
+            hex::encode(point.to_bytes(false))
+        );
+        assert!(serde_json::from_str::<AnyCurvePoint>(&json).is_err());
+
+        // Encoding is not a valid point
+        let json = format!(r#"{{"curve":"secp256k1","point":"04{}"}}"#, "ff".repeat(64));
+        assert!(serde_json::from_str::<AnyCurvePoint>(&json).is_err());
+
+        // Missing fields
+        assert!(serde_json::from_str::<AnyCurvePoint>(r#"{"curve":"secp256k1"}"#).is_err());
+        let json = format!(
+            r#"{{"point":"{}"}}"#,
+            hex::encode(random_point::<Secp256k1>().to_bytes(false))
+        );
+        assert!(serde_json::from_str::<AnyCurvePoint>(&json).is_err());
+    }
 }
@@ -0,0 +1,98 @@
+//! Checks that secret scalars are erased from the heap when dropped
+//!
+//! Test binary installs global allocator which inspects memory being deallocated, so it
+//! must not contain any other tests.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicU8, AtomicUsize, Ordering};
+
+use generic_ec::{curves, Curve, NonZero, Scalar, SecretScalar};
+use zeroize::ZeroizeOnDrop;
+
+/// Address of the scalar which deallocation we're waiting for
+static WATCHED_ADDR: AtomicUsize = AtomicUsize::new(0);
+/// Size of the scalar which deallocation we're waiting for
+static WATCHED_SIZE: AtomicUsize = AtomicUsize::new(0);
+/// Outcome of the watched deallocation
+static OUTCOME: AtomicU8 = AtomicU8::new(NOT_FREED);
+
+const NOT_FREED: u8 = 0;
+const FREED_ZEROIZED: u8 = 1;
+const FREED_NOT_ZEROIZED: u8 = 2;
+
+struct InspectingAllocator;
+
+unsafe impl GlobalAlloc for InspectingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        let watched_addr = WATCHED_ADDR.load(Ordering::SeqCst);
+        let watched_size = WATCHED_SIZE.load(Ordering::SeqCst);
+        let block = ptr as usize..ptr as usize + layout.size();
+        if watched_addr != 0 && block.contains(&watched_addr) {
+            let offset = watched_addr - ptr as usize;
+            let bytes = core::slice::from_raw_parts(ptr.add(offset), watched_size);
+            let outcome = if bytes.iter().all(|b| *b == 0) {
+                FREED_ZEROIZED
+            } else {
+                FREED_NOT_ZEROIZED
+            };
+            OUTCOME.store(outcome, Ordering::SeqCst);
+        }
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: InspectingAllocator = InspectingAllocator;
+
+/// Drops the value, returns whether memory at `addr` was zeroized before being deallocated
+fn drop_and_inspect<T>(value: T, addr: *const u8, size: usize) -> u8 {
+    OUTCOME.store(NOT_FREED, Ordering::SeqCst);
+    WATCHED_SIZE.store(size, Ordering::SeqCst);
+    WATCHED_ADDR.store(addr as usize, Ordering::SeqCst);
+    drop(value);
+    WATCHED_ADDR.store(0, Ordering::SeqCst);
+    OUTCOME.load(Ordering::SeqCst)
+}
+
+fn assert_zeroize_on_drop<T: ZeroizeOnDrop>() {}
+
+fn secret_scalar_is_zeroized_on_drop<E: Curve>() {
+    assert_zeroize_on_drop::<SecretScalar<E>>();
+    assert_zeroize_on_drop::<NonZero<SecretScalar<E>>>();
+
+    let mut rng = rand_dev::DevRng::new();
+    let size = core::mem::size_of::<Scalar<E>>();
+    let addr_of = |s: &Scalar<E>| s as *const Scalar<E> as *const u8;
+
+    // Make sure that inspection works: scalar on the heap is not zeroized on drop
+    let boxed = Box::new(Scalar::<E>::random(&mut rng));
+    let addr = addr_of(&boxed);
+    assert_eq!(drop_and_inspect(boxed, addr, size), FREED_NOT_ZEROIZED);
+
+    let secret = SecretScalar::<E>::random(&mut rng);
+    let addr = addr_of(secret.as_ref());
+    assert_eq!(drop_and_inspect(secret, addr, size), FREED_ZEROIZED);
+
+    let secret = NonZero::<SecretScalar<E>>::random(&mut rng);
+    let addr = addr_of((*secret).as_ref());
+    // Memory is not freed while there's another clone of the secret
+    let clone = secret.clone();
+    assert_eq!(drop_and_inspect(secret, addr, size), NOT_FREED);
+    assert_eq!(drop_and_inspect(clone, addr, size), FREED_ZEROIZED);
+}
+
+#[test]
+fn secret_scalars_are_zeroized_on_drop() {
+    secret_scalar_is_zeroized_on_drop::<curves::Secp256k1>();
+    secret_scalar_is_zeroized_on_drop::<curves::Secp256r1>();
+    secret_scalar_is_zeroized_on_drop::<curves::Secp384r1>();
+    secret_scalar_is_zeroized_on_drop::<curves::Sm2>();
+    secret_scalar_is_zeroized_on_drop::<curves::Stark>();
+    secret_scalar_is_zeroized_on_drop::<curves::Ed25519>();
+    secret_scalar_is_zeroized_on_drop::<curves::Ristretto255>();
+    secret_scalar_is_zeroized_on_drop::<curves::Bls12_381G1>();
+}